@@ -1,4 +1,3 @@
-use criterion::black_box;
 use criterion::criterion_group;
 use criterion::criterion_main;
 use criterion::Criterion;
@@ -37,7 +36,7 @@ fn date_time(c: &mut Criterion) {
     c.bench_function("date_time", |b| {
         b.iter(|| {
             for value in values {
-                let dtf = DateTimeFormat::new_from_static(value.0, value.1, value.2);
+                let dtf = DateTimeFormat::new_from_static(value.0, value.1, value.2).unwrap();
                 for date in dates {
                     let _ = dtf.format(date);
                 }
@@ -48,10 +47,11 @@ fn date_time(c: &mut Criterion) {
     #[cfg(feature = "serde")]
     c.bench_function("date_time_dynamic", |b| {
         let data =
-            unic_datetime::data::load_json::get_calendar_data("./data/cldr-dates-modern", "pl");
+            unic_datetime::data::load_json::get_calendar_data("./data/cldr-dates-modern", "pl")
+                .expect("Loading data failed");
         b.iter(|| {
             for value in values {
-                let dtf = DateTimeFormat::new(value.0, value.1, value.2, &data);
+                let dtf = DateTimeFormat::new(value.0, value.1, value.2, &data).unwrap();
                 for date in dates {
                     let _ = dtf.format(date);
                 }
@@ -61,10 +61,11 @@ fn date_time(c: &mut Criterion) {
 
     #[cfg(feature = "serde")]
     c.bench_function("date_time_binary", |b| {
-        let data = unic_datetime::data::load_bin::get_calendar_data("./res", "pl");
+        let data = unic_datetime::data::load_bin::get_calendar_data("./res", "pl")
+            .expect("Loading data failed");
         b.iter(|| {
             for value in values {
-                let dtf = DateTimeFormat::new(value.0, value.1, value.2, &data);
+                let dtf = DateTimeFormat::new(value.0, value.1, value.2, &data).unwrap();
                 for date in dates {
                     let _ = dtf.format(date);
                 }
@@ -32,11 +32,11 @@ fn main() {
 
     let now = Instant::now();
 
-    let data = get_calendar_data("./res", "pl");
+    let data = get_calendar_data("./res", "pl").expect("Loading data failed");
     // println!("{:#?}", data);
 
     for value in values {
-        let dtf = DateTimeFormat::new(value.0, value.1, value.2, &data);
+        let dtf = DateTimeFormat::new(value.0, value.1, value.2, &data).unwrap();
         for date in dates {
             let _ = dtf.format(date);
             // println!("{}", s);
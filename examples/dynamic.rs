@@ -32,12 +32,12 @@ fn main() {
 
     let now = Instant::now();
 
-    let data = get_calendar_data("./data/cldr-dates-modern", "pl");
+    let data = get_calendar_data("./data/cldr-dates-modern", "pl").expect("Loading data failed");
 
     for value in values {
-        let dtf = DateTimeFormat::new(value.0, value.1, value.2, &data);
+        let dtf = DateTimeFormat::new(value.0, value.1, value.2, &data).unwrap();
         for date in dates {
-            let s = dtf.format(date);
+            let _ = dtf.format(date);
             // println!("{}", s);
         }
     }
@@ -31,9 +31,9 @@ fn main() {
 
     let now = Instant::now();
     for value in values {
-        let dtf = DateTimeFormat::new_from_static(value.0, value.1, value.2);
+        let dtf = DateTimeFormat::new_from_static(value.0, value.1, value.2).unwrap();
         for date in dates {
-            let s = dtf.format(date);
+            let _ = dtf.format(date);
             // println!("{}", s);
         }
     }
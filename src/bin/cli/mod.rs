@@ -0,0 +1,324 @@
+//! Command-line handling shared by the data generators.
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+use unic_datetime::data::error::DataError;
+use unic_datetime::data::layout::{Calendar, Resource};
+use unic_datetime::data::subset::UsageManifest;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum OutputFormat {
+    Rust,
+    Bincode,
+    Bundle,
+    Json,
+    /// A human-readable report.
+    Text,
+}
+
+impl OutputFormat {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "rust" => Some(Self::Rust),
+            "bincode" => Some(Self::Bincode),
+            "bundle" => Some(Self::Bundle),
+            "json" => Some(Self::Json),
+            "text" => Some(Self::Text),
+            _ => None,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Rust => "rust",
+            Self::Bincode => "bincode",
+            Self::Bundle => "bundle",
+            Self::Json => "json",
+            Self::Text => "text",
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum CliError {
+    /// The arguments were invalid. Exits with code 2.
+    Usage(String),
+    /// Generation failed. Exits with code 1.
+    Failed(String),
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Usage(msg) | Self::Failed(msg) => f.write_str(msg),
+        }
+    }
+}
+
+impl From<DataError> for CliError {
+    fn from(err: DataError) -> Self {
+        Self::Failed(err.to_string())
+    }
+}
+
+impl From<std::io::Error> for CliError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Failed(err.to_string())
+    }
+}
+
+impl From<fmt::Error> for CliError {
+    fn from(err: fmt::Error) -> Self {
+        Self::Failed(err.to_string())
+    }
+}
+
+/// Per-tool defaults. The first of `formats` is the default one.
+pub struct Tool {
+    pub name: &'static str,
+    pub output: &'static str,
+    pub formats: &'static [OutputFormat],
+}
+
+pub struct Options {
+    pub input: String,
+    pub output: PathBuf,
+    pub include: Vec<String>,
+    pub exclude: Vec<String>,
+    pub format: OutputFormat,
+    /// CLDR identifiers of the calendars to load.
+    pub calendars: Vec<String>,
+    /// Restricts the generated data to what the manifest can reach.
+    pub manifest: Option<UsageManifest>,
+}
+
+impl Tool {
+    fn usage(&self) -> String {
+        let formats: Vec<_> = self.formats.iter().map(|f| f.name()).collect();
+        format!(
+            "Usage: {} [OPTIONS]
+
+Options:
+    -i, --input <PATH>       CLDR dates package [default: ./data/cldr-dates-modern]
+    -o, --output <PATH>      Output path [default: {}]
+        --include <LOCALES>  Comma-separated locales or globs to generate [default: *]
+        --exclude <LOCALES>  Comma-separated locales or globs to skip
+    -f, --format <FORMAT>    Output format: {} [default: {}]
+    -c, --calendars <NAMES>  Comma-separated calendars to load [default: gregorian]
+    -m, --manifest <PATH>    Usage manifest listing the styles and tokens to keep
+    -h, --help               Print this message",
+            self.name,
+            self.output,
+            formats.join(", "),
+            self.formats[0].name(),
+        )
+    }
+
+    /// Parses the process arguments, printing usage and exiting on `--help`.
+    pub fn parse_args(&self) -> Result<Options, CliError> {
+        let mut options = Options {
+            input: "./data/cldr-dates-modern".to_string(),
+            output: PathBuf::from(self.output),
+            include: vec![],
+            exclude: vec![],
+            format: self.formats[0],
+            calendars: vec!["gregorian".to_string()],
+            manifest: None,
+        };
+
+        let mut args = std::env::args().skip(1);
+        while let Some(arg) = args.next() {
+            let (name, inline_value) = match arg.find('=') {
+                Some(idx) if arg.starts_with("--") => {
+                    (&arg[..idx], Some(arg[idx + 1..].to_string()))
+                }
+                _ => (arg.as_str(), None),
+            };
+            if name == "-h" || name == "--help" {
+                println!("{}", self.usage());
+                process::exit(0);
+            }
+
+            let mut value = || {
+                inline_value
+                    .clone()
+                    .or_else(|| args.next())
+                    .ok_or_else(|| CliError::Usage(format!("Missing value for {}", name)))
+            };
+            match name {
+                "-i" | "--input" => options.input = value()?,
+                "-o" | "--output" => options.output = PathBuf::from(value()?),
+                "--include" => options.include.extend(split_list(&value()?)),
+                "--exclude" => options.exclude.extend(split_list(&value()?)),
+                "-m" | "--manifest" => options.manifest = Some(read_manifest(&value()?)?),
+                "-c" | "--calendars" => {
+                    options.calendars = split_list(&value()?);
+                    if let Some(name) = options
+                        .calendars
+                        .iter()
+                        .find(|c| !Calendar::NAMES.contains(&c.as_str()))
+                    {
+                        return Err(CliError::Usage(format!("Unsupported calendar {:?}", name)));
+                    }
+                }
+                "-f" | "--format" => {
+                    let value = value()?;
+                    options.format = OutputFormat::from_name(&value)
+                        .filter(|format| self.formats.contains(format))
+                        .ok_or_else(|| {
+                            CliError::Usage(format!(
+                                "Unsupported format {:?}\n\n{}",
+                                value,
+                                self.usage()
+                            ))
+                        })?;
+                }
+                _ => {
+                    return Err(CliError::Usage(format!(
+                        "Unknown argument {:?}\n\n{}",
+                        arg,
+                        self.usage()
+                    )))
+                }
+            }
+        }
+        Ok(options)
+    }
+}
+
+fn read_manifest(path: &str) -> Result<UsageManifest, CliError> {
+    let input = fs::read_to_string(path)
+        .map_err(|err| CliError::Usage(format!("Cannot read {}: {}", path, err)))?;
+    UsageManifest::from_json(&input)
+        .map_err(|err| CliError::Usage(format!("Invalid manifest {}: {}", path, err)))
+}
+
+fn split_list(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+impl Options {
+    pub fn should_skip(&self, locale: &str) -> bool {
+        let included =
+            self.include.is_empty() || self.include.iter().any(|p| matches_glob(p, locale));
+        !included || self.exclude.iter().any(|p| matches_glob(p, locale))
+    }
+
+    /// Strips `res` down to the manifest, if one was given.
+    #[allow(dead_code)] // validate_data checks the full data.
+    pub fn apply_manifest(&self, res: &mut Resource) {
+        if let Some(manifest) = &self.manifest {
+            for locale_res in res.main.values_mut() {
+                for (_, calendar) in locale_res.dates.calendars.iter_mut() {
+                    manifest.subset(calendar);
+                }
+            }
+        }
+    }
+
+    /// Lists the locales in the CLDR package that pass the filters, sorted.
+    pub fn locales(&self) -> Result<Vec<String>, CliError> {
+        let path = Path::new(&self.input).join("main");
+        let entries = fs::read_dir(&path)
+            .map_err(|err| CliError::Failed(format!("Cannot read {}: {}", path.display(), err)))?;
+
+        let mut locales = vec![];
+        for entry in entries {
+            let locale = entry?.file_name().to_string_lossy().into_owned();
+            if !self.should_skip(&locale) {
+                locales.push(locale);
+            }
+        }
+        if locales.is_empty() {
+            return Err(CliError::Failed(format!(
+                "No locales in {} match the filters",
+                path.display()
+            )));
+        }
+        locales.sort();
+        Ok(locales)
+    }
+}
+
+/// Matches `text` against a pattern where `*` stands for any run of
+/// characters and `?` for a single character.
+pub fn matches_glob(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+
+    let (mut p, mut t) = (0, 0);
+    // Position of the last `*` and of the text it currently ends at.
+    let mut backtrack = None;
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, t));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match backtrack {
+                Some((star, matched)) => {
+                    p = star + 1;
+                    t = matched + 1;
+                    backtrack = Some((star, matched + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// Runs `main`, reporting failures on stderr with a non-zero exit code.
+pub fn run(main: impl FnOnce() -> Result<(), CliError>) {
+    if let Err(err) = main() {
+        eprintln!("error: {}", err);
+        process::exit(match err {
+            CliError::Usage(_) => 2,
+            CliError::Failed(_) => 1,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_glob() {
+        assert!(matches_glob("pl", "pl"));
+        assert!(!matches_glob("pl", "pl-PL"));
+        assert!(matches_glob("pl*", "pl-PL"));
+        assert!(matches_glob("*", "en"));
+        assert!(matches_glob("en-??", "en-GB"));
+        assert!(!matches_glob("en-??", "en-150"));
+        assert!(matches_glob("*-*-*", "sr-Latn-BA"));
+        assert!(!matches_glob("*-*-*", "sr-Latn"));
+    }
+
+    #[test]
+    fn test_should_skip() {
+        let options = Options {
+            input: String::new(),
+            output: PathBuf::new(),
+            include: vec!["en*".to_string(), "pl".to_string()],
+            exclude: vec!["en-*".to_string()],
+            format: OutputFormat::Rust,
+            calendars: vec![],
+            manifest: None,
+        };
+        assert!(!options.should_skip("pl"));
+        assert!(!options.should_skip("en"));
+        assert!(options.should_skip("en-GB"));
+        assert!(options.should_skip("de"));
+    }
+}
@@ -1,49 +1,102 @@
-use bincode;
+mod cli;
+
+use cli::{CliError, Options, OutputFormat, Tool};
+use std::borrow::Cow;
+use std::fs;
 use std::fs::File;
-use std::io::prelude::*;
+use unic_datetime::data::bundle;
 use unic_datetime::data::layout;
+use unic_datetime::data::load_bin;
 use unic_datetime::data::load_json;
 use unic_datetime::data::patterns;
 
-fn parse_formats(formats: &mut layout::Formats) {
-    if let Some(layout::DateTimePattern::Raw(ref r)) = formats.full {
-        let parsed = patterns::parse_pattern(r.as_ref()).expect("Failed to parse");
-        formats.full = Some(layout::DateTimePattern::Parsed(parsed.into()));
-    }
-
-    if let Some(layout::DateTimePattern::Raw(ref r)) = formats.long {
-        let parsed = patterns::parse_pattern(r.as_ref()).expect("Failed to parse");
-        formats.long = Some(layout::DateTimePattern::Parsed(parsed.into()));
+fn parse_pattern(pattern: &mut Option<layout::DateTimePattern>) -> Result<(), CliError> {
+    if let Some(layout::DateTimePattern::Raw(ref r)) = pattern {
+        let parsed = patterns::parse_pattern(r.as_ref())
+            .map_err(|err| CliError::Failed(format!("Failed to parse {:?}: {:?}", r, err)))?;
+        *pattern = Some(layout::DateTimePattern::Parsed(parsed.into()));
     }
+    Ok(())
+}
 
-    if let Some(layout::DateTimePattern::Raw(ref r)) = formats.medium {
-        let parsed = patterns::parse_pattern(r.as_ref()).expect("Failed to parse");
-        formats.medium = Some(layout::DateTimePattern::Parsed(parsed.into()));
-    }
+fn parse_formats(formats: &mut layout::Formats) -> Result<(), CliError> {
+    parse_pattern(&mut formats.full)?;
+    parse_pattern(&mut formats.long)?;
+    parse_pattern(&mut formats.medium)?;
+    parse_pattern(&mut formats.short)
+}
 
-    if let Some(layout::DateTimePattern::Raw(ref r)) = formats.short {
-        let parsed = patterns::parse_pattern(r.as_ref()).expect("Failed to parse");
-        formats.short = Some(layout::DateTimePattern::Parsed(parsed.into()));
+fn parse_all_formats(res: &mut layout::Resource) -> Result<(), CliError> {
+    for locale_res in res.main.values_mut() {
+        for (_, calendar) in locale_res.dates.calendars.iter_mut() {
+            parse_formats(&mut calendar.date_formats)?;
+            parse_formats(&mut calendar.time_formats)?;
+            parse_formats(&mut calendar.date_time_formats)?;
+        }
     }
+    Ok(())
 }
 
-fn parse_all_formats(res: &mut layout::Resource) {
-    parse_formats(&mut res.main.pl.dates.calendars.gregorian.date_formats);
-    parse_formats(&mut res.main.pl.dates.calendars.gregorian.time_formats);
-    parse_formats(&mut res.main.pl.dates.calendars.gregorian.date_time_formats);
+fn get_cldr_version(path: &str) -> Result<String, CliError> {
+    let package = fs::read_to_string(format!("{}/package.json", path))
+        .map_err(|err| CliError::Failed(format!("Cannot read {}/package.json: {}", path, err)))?;
+    let package: serde_json::Value = serde_json::from_str(&package)
+        .map_err(|err| CliError::Failed(format!("Cannot parse package.json: {}", err)))?;
+    package["version"]
+        .as_str()
+        .map(str::to_string)
+        .ok_or_else(|| CliError::Failed("Missing CLDR version in package.json".to_string()))
 }
 
-fn main() {
-    let mut res = load_json::get_calendar_data("./data/cldr-dates-modern", "pl");
-    parse_all_formats(&mut res);
+fn generate(options: &Options) -> Result<(), CliError> {
+    let cldr_version = get_cldr_version(&options.input)?;
+    let dest_dir = options.output.as_path();
+    fs::create_dir_all(dest_dir)?;
+
+    let mut all = layout::Resource {
+        main: Default::default(),
+    };
+    for locale in options.locales()? {
+        let mut res = load_json::get_calendars_data(&options.input, &locale, &options.calendars)?;
+        parse_all_formats(&mut res)?;
+        options.apply_manifest(&mut res);
 
-    let encoded: Vec<u8> = bincode::serialize(&res).unwrap();
+        match options.format {
+            OutputFormat::Bincode => {
+                let buffer = File::create(dest_dir.join(format!("{}.dat", locale)))?;
+                load_bin::write_calendar_data(buffer, &cldr_version, &res)?;
+            }
+            OutputFormat::Json => {
+                let buffer = File::create(dest_dir.join(format!("{}.json", locale)))?;
+                serde_json::to_writer(buffer, &res)
+                    .map_err(|err| CliError::Failed(err.to_string()))?;
+            }
+            OutputFormat::Bundle => {
+                let data = res.main.remove(locale.as_str()).ok_or_else(|| {
+                    CliError::Failed(format!("{} has no data for itself", locale))
+                })?;
+                all.main.insert(Cow::Owned(locale), data);
+            }
+            format => unreachable!("{:?} is not offered by this tool", format),
+        }
+    }
 
-    let mut buffer = File::create("./res/pl.dat").expect("Opening file failed");
-    buffer.write_all(&encoded).expect("Writing failed");
-    // println!("{:?}", encoded);
+    if options.format == OutputFormat::Bundle {
+        let buffer = File::create(dest_dir.join("bundle.dat"))?;
+        bundle::write_bundle(buffer, &cldr_version, &all)?;
+    }
+    Ok(())
+}
 
-    // let decoded: layout::DayList = bincode::deserialize(&encoded[..]).unwrap();
-    //
-    // assert_eq!(list, decoded);
+fn main() {
+    let tool = Tool {
+        name: "generate_res",
+        output: "./res/",
+        formats: &[
+            OutputFormat::Bincode,
+            OutputFormat::Bundle,
+            OutputFormat::Json,
+        ],
+    };
+    cli::run(|| generate(&tool.parse_args()?));
 }
@@ -1,127 +1,357 @@
+mod cli;
+
+use cli::{CliError, Options, OutputFormat, Tool};
+use std::borrow::Cow;
 use std::fmt::Write;
 use std::fs;
+use std::mem;
 use std::path::Path;
 use unic_datetime::data::layout::*;
-use unic_datetime::data::load_json::get_calendar_data;
+use unic_datetime::data::load_json::get_calendars_data;
 
-fn serialize_day_list(list: &Option<DayList>) -> Result<String, std::fmt::Error> {
-    let mut result = String::new();
-    if let Some(list) = list {
-        let mut i = 0;
-        write!(result, "Some(DayList {{\n")?;
-        for key in &["sun", "mon", "tue", "wed", "thu", "fri", "sat"] {
-            writeln!(
-                result,
-                r#"                                   {}: Cow::Borrowed("{}"),"#,
-                key,
-                list.get(i),
-            )?;
-            i += 1;
+/// Name lists shared by every generated locale, written to `names.rs`.
+///
+/// Each distinct list is emitted once as a static array that every use
+/// borrows, so identical format and stand-alone lists, or lists repeated
+/// across locales, are stored a single time.
+#[derive(Default)]
+struct NameTable {
+    day_lists: Vec<Vec<String>>,
+    month_lists: Vec<Vec<String>>,
+    era_lists: Vec<Vec<String>>,
+    cyclic_lists: Vec<Vec<String>>,
+    /// Number of lists referencing the table, one per use.
+    uses: usize,
+    /// Estimated static data size if every use had its own copy.
+    inline_size: usize,
+}
+
+fn intern(lists: &mut Vec<Vec<String>>, list: &[Cow<str>]) -> usize {
+    lists
+        .iter()
+        .position(|l| l.iter().eq(list.iter()))
+        .unwrap_or_else(|| {
+            lists.push(list.iter().map(|name| name.to_string()).collect());
+            lists.len() - 1
+        })
+}
+
+/// Estimates the static data size of a list of names.
+fn list_size(list: &[impl AsRef<str>]) -> usize {
+    list.iter()
+        .map(|name| mem::size_of::<Cow<str>>() + name.as_ref().len())
+        .sum()
+}
+
+impl NameTable {
+    fn day_list(&mut self, list: &Option<DayList>) -> String {
+        match list {
+            Some(list) => {
+                self.uses += 1;
+                self.inline_size += list_size(&list.0);
+                let idx = intern(&mut self.day_lists, &list.0);
+                format!("Some(DayList(Cow::Borrowed(&DAYS_{}))),", idx)
+            }
+            None => "None,".to_string(),
         }
-        write!(result, "                                }}),")?;
-    } else {
-        write!(result, "None,")?;
     }
-    Ok(result)
-}
 
-fn serialize_month_list(list: &Option<MonthList>) -> Result<String, std::fmt::Error> {
-    let mut result = String::new();
-    if let Some(list) = list {
-        write!(result, "Some(MonthList {{\n")?;
-        for i in 1..=12 {
-            writeln!(
-                result,
-                r#"                                    m{}: Cow::Borrowed("{}"),"#,
-                i,
-                list.get(i - 1)
-            )?;
+    fn month_list(&mut self, list: &Option<MonthList>) -> String {
+        match list {
+            Some(list) => {
+                self.uses += 1;
+                self.inline_size += list_size(&list.names);
+                let idx = intern(&mut self.month_lists, &list.names);
+                if list.leap_names.is_empty() {
+                    return format!("Some(MonthList::new(Cow::Borrowed(&MONTHS_{}))),", idx);
+                }
+                // Leap year names are rare enough to be written in place.
+                let leap_names: Vec<_> = list
+                    .leap_names
+                    .iter()
+                    .map(|(idx, name)| format!("({}, Cow::Borrowed({:?}))", idx, name))
+                    .collect();
+                format!(
+                    "Some(MonthList {{ names: Cow::Borrowed(&MONTHS_{}), leap_names: Cow::Borrowed(&[{}]) }}),",
+                    idx,
+                    leap_names.join(", ")
+                )
+            }
+            None => "None,".to_string(),
         }
-        write!(result, "                                }}),")?;
-    } else {
-        write!(result, "None,")?;
     }
-    Ok(result)
+
+    fn era_list(&mut self, list: &Option<EraList>) -> String {
+        match list {
+            Some(list) => {
+                self.uses += 1;
+                self.inline_size += list_size(&list.0);
+                let idx = intern(&mut self.era_lists, &list.0);
+                format!("Some(EraList(Cow::Borrowed(&ERAS_{}))),", idx)
+            }
+            None => "None,".to_string(),
+        }
+    }
+
+    fn cyclic_list(&mut self, list: &Option<CyclicNameList>) -> String {
+        match list {
+            Some(list) => {
+                self.uses += 1;
+                self.inline_size += list_size(&list.0);
+                let idx = intern(&mut self.cyclic_lists, &list.0);
+                format!("Some(CyclicNameList(Cow::Borrowed(&CYCLIC_{}))),", idx)
+            }
+            None => "None,".to_string(),
+        }
+    }
+
+    fn serialize(&self) -> Result<String, std::fmt::Error> {
+        let mut result = String::new();
+        writeln!(result, "use std::borrow::Cow;")?;
+        for (prefix, lists) in &[
+            ("DAYS", &self.day_lists),
+            ("MONTHS", &self.month_lists),
+            ("ERAS", &self.era_lists),
+            ("CYCLIC", &self.cyclic_lists),
+        ] {
+            for (idx, list) in lists.iter().enumerate() {
+                writeln!(
+                    result,
+                    "\npub static {}_{}: [Cow<'static, str>; {}] = [",
+                    prefix,
+                    idx,
+                    list.len()
+                )?;
+                for name in list {
+                    writeln!(result, "    Cow::Borrowed({:?}),", name)?;
+                }
+                writeln!(result, "];")?;
+            }
+        }
+        Ok(result)
+    }
+
+    /// Estimates the static data size of the lists with the shared table.
+    fn size(&self) -> usize {
+        self.day_lists
+            .iter()
+            .chain(&self.month_lists)
+            .chain(&self.era_lists)
+            .chain(&self.cyclic_lists)
+            .map(|list| list_size(list))
+            .sum::<usize>()
+            + self.uses * mem::size_of::<Option<MonthList>>()
+    }
 }
 
-fn serialize_day_names(days: &DayTypes) -> Result<String, std::fmt::Error> {
+fn serialize_day_names(days: &DayTypes, names: &mut NameTable) -> Result<String, std::fmt::Error> {
     let mut result = String::new();
     writeln!(result, "DayTypes {{")?;
     writeln!(
         result,
-        "                                abbreviated: {}",
-        serialize_day_list(&days.abbreviated)?
+        "                        abbreviated: {}",
+        names.day_list(&days.abbreviated)
     )?;
     writeln!(
         result,
-        "                                narrow: {}",
-        serialize_day_list(&days.narrow)?
+        "                        narrow: {}",
+        names.day_list(&days.narrow)
     )?;
     writeln!(
         result,
-        "                                short: {}",
-        serialize_day_list(&days.short)?
+        "                        short: {}",
+        names.day_list(&days.short)
     )?;
     writeln!(
         result,
-        "                                wide: {}",
-        serialize_day_list(&days.wide)?
+        "                        wide: {}",
+        names.day_list(&days.wide)
     )?;
-    write!(result, "                           }}")?;
+    write!(result, "                   }}")?;
     Ok(result)
 }
 
-fn serialize_month_names(months: &MonthTypes) -> Result<String, std::fmt::Error> {
+fn serialize_month_names(
+    months: &MonthTypes,
+    names: &mut NameTable,
+) -> Result<String, std::fmt::Error> {
     let mut result = String::new();
     writeln!(result, "MonthTypes {{")?;
     writeln!(
         result,
-        "                                abbreviated: {}",
-        serialize_month_list(&months.abbreviated)?
+        "                        abbreviated: {}",
+        names.month_list(&months.abbreviated)
     )?;
     writeln!(
         result,
-        "                                narrow: {}",
-        serialize_month_list(&months.narrow)?
+        "                        narrow: {}",
+        names.month_list(&months.narrow)
     )?;
     writeln!(
         result,
-        "                                short: {}",
-        serialize_month_list(&months.short)?
+        "                        short: {}",
+        names.month_list(&months.short)
     )?;
     writeln!(
         result,
-        "                                wide: {}",
-        serialize_month_list(&months.wide)?
+        "                        wide: {}",
+        names.month_list(&months.wide)
     )?;
-    write!(result, "                           }}")?;
+    write!(result, "                   }}")?;
+    Ok(result)
+}
+
+fn serialize_era_names(
+    eras: &Option<Eras>,
+    names: &mut NameTable,
+) -> Result<String, std::fmt::Error> {
+    let eras = match eras {
+        Some(eras) => eras,
+        None => return Ok("None".to_string()),
+    };
+    let mut result = String::new();
+    writeln!(result, "Some(Eras {{")?;
+    writeln!(
+        result,
+        "                    abbreviated: {}",
+        names.era_list(&eras.abbreviated)
+    )?;
+    writeln!(
+        result,
+        "                    narrow: {}",
+        names.era_list(&eras.narrow)
+    )?;
+    writeln!(
+        result,
+        "                    wide: {}",
+        names.era_list(&eras.wide)
+    )?;
+    write!(result, "                }})")?;
+    Ok(result)
+}
+
+fn serialize_cyclic_names(
+    sets: &Option<CyclicNameSets>,
+    names: &mut NameTable,
+) -> Result<String, std::fmt::Error> {
+    let years = match sets {
+        Some(CyclicNameSets { years: Some(years) }) => &years.format,
+        Some(_) => return Ok("Some(CyclicNameSets { years: None })".to_string()),
+        None => return Ok("None".to_string()),
+    };
+    let mut result = String::new();
+    writeln!(result, "Some(CyclicNameSets {{")?;
+    writeln!(result, "                    years: Some(CyclicNames {{")?;
+    writeln!(result, "                        format: CyclicNameTypes {{")?;
+    writeln!(
+        result,
+        "                            abbreviated: {}",
+        names.cyclic_list(&years.abbreviated)
+    )?;
+    writeln!(
+        result,
+        "                            narrow: {}",
+        names.cyclic_list(&years.narrow)
+    )?;
+    writeln!(
+        result,
+        "                            wide: {}",
+        names.cyclic_list(&years.wide)
+    )?;
+    writeln!(result, "                        }},")?;
+    writeln!(result, "                    }}),")?;
+    write!(result, "                }})")?;
+    Ok(result)
+}
+
+fn serialize_month_pattern(pattern: &Option<MonthPattern>) -> String {
+    match pattern {
+        Some(MonthPattern { leap: Some(leap) }) => format!(
+            "Some(MonthPattern {{ leap: Some(Cow::Borrowed({:?})) }})",
+            leap
+        ),
+        Some(MonthPattern { leap: None }) => "Some(MonthPattern { leap: None })".to_string(),
+        None => "None".to_string(),
+    }
+}
+
+fn serialize_month_patterns(patterns: &Option<MonthPatterns>) -> Result<String, std::fmt::Error> {
+    let patterns = match patterns {
+        Some(patterns) => patterns,
+        None => return Ok("None".to_string()),
+    };
+    let mut result = String::new();
+    writeln!(result, "Some(MonthPatterns {{")?;
+    match &patterns.format {
+        Some(format) => {
+            writeln!(
+                result,
+                "                    format: Some(MonthPatternTypes {{"
+            )?;
+            for (name, pattern) in &[
+                ("abbreviated", &format.abbreviated),
+                ("narrow", &format.narrow),
+                ("wide", &format.wide),
+            ] {
+                writeln!(
+                    result,
+                    "                        {}: {},",
+                    name,
+                    serialize_month_pattern(pattern)
+                )?;
+            }
+            writeln!(result, "                    }}),")?;
+        }
+        None => writeln!(result, "                    format: None,")?,
+    }
+    match &patterns.numeric {
+        Some(numeric) => writeln!(
+            result,
+            "                    numeric: Some(NumericMonthPatterns {{ all: {} }}),",
+            serialize_month_pattern(&numeric.all)
+        )?,
+        None => writeln!(result, "                    numeric: None,")?,
+    }
+    write!(result, "                }})")?;
     Ok(result)
 }
 
 fn serialize_dt_format(pattern: &Option<DateTimePattern>) -> Result<String, std::fmt::Error> {
     let mut result = String::new();
     if let Some(pattern) = pattern {
-        let pattern = pattern.to_parsed();
+        let pattern = match (pattern, pattern.to_parsed()) {
+            (_, Ok(pattern)) => pattern,
+            // Kept as is, so that formatters report the error.
+            (DateTimePattern::Raw(raw), Err(_)) => {
+                write!(
+                    result,
+                    "Some(DateTimePattern::Raw(Cow::Borrowed({:?})))",
+                    raw
+                )?;
+                return Ok(result);
+            }
+            (DateTimePattern::Parsed(_), Err(_)) => unreachable!(),
+        };
         writeln!(result, r#"Some(DateTimePattern::Parsed(Cow::Borrowed(&["#)?;
         for elem in pattern {
             match elem {
                 PatternElement::Literal(s) => {
                     writeln!(
                         result,
-                        r#"                                PatternElement::Literal(Cow::Borrowed("{}")),"#,
+                        "                        PatternElement::Literal(Cow::Borrowed({:?})),",
                         s
                     )?;
                 }
                 PatternElement::Token(t) => {
                     writeln!(
                         result,
-                        r#"                                PatternElement::Token(DateTimeToken::{}),"#,
+                        r#"                        PatternElement::Token(DateTimeToken::{}),"#,
                         t.get_name()
                     )?;
                 }
             }
         }
-        write!(result, r#"                            ])))"#)?;
+        write!(result, r#"                    ])))"#)?;
     } else {
         writeln!(result, "None")?;
     }
@@ -132,154 +362,224 @@ fn serialize_dt_formats(formats: &Formats) -> Result<String, std::fmt::Error> {
     writeln!(result, "Formats {{")?;
     writeln!(
         result,
-        "                            full: {},",
+        "                    full: {},",
         serialize_dt_format(&formats.full)?
     )?;
     writeln!(
         result,
-        "                            long: {},",
+        "                    long: {},",
         serialize_dt_format(&formats.long)?
     )?;
     writeln!(
         result,
-        "                            medium: {},",
+        "                    medium: {},",
         serialize_dt_format(&formats.medium)?
     )?;
     writeln!(
         result,
-        "                            short: {},",
+        "                    short: {},",
         serialize_dt_format(&formats.short)?
     )?;
-    write!(result, "                        }},")?;
+    write!(result, "                }},")?;
     Ok(result)
 }
 
-fn serialize_calendar_data(data: &Resource, locale: &str) -> Result<String, std::fmt::Error> {
-    let calendar = &data.get(locale).unwrap().dates.calendars.gregorian;
+fn serialize_calendar(
+    calendar: &CalendarData,
+    names: &mut NameTable,
+) -> Result<String, std::fmt::Error> {
     let mut result = String::new();
-
-    writeln!(result, "use crate::data::layout::*;")?;
-    writeln!(result, "use std::borrow::Cow;\n")?;
-
+    writeln!(result, "Some(CalendarData {{")?;
+    writeln!(result, "                months: Months {{")?;
     writeln!(
         result,
-        "pub const RESOURCE: Resource<'static> = Resource {{"
+        "                   format: {},",
+        serialize_month_names(&calendar.months.format, names)?
     )?;
-    writeln!(result, "    main: MainResource {{")?;
-    writeln!(result, "        pl: LocaleResource {{")?;
-    writeln!(result, "            dates: CalendarDates {{")?;
-    writeln!(result, "                calendars: Calendar {{")?;
     writeln!(
         result,
-        "                    gregorian: GregorianCalendar {{"
+        "                   stand_alone: {},",
+        serialize_month_names(&calendar.months.stand_alone, names)?
     )?;
-
-    writeln!(result, "                        months: Months {{")?;
+    writeln!(result, "                }},")?;
+    writeln!(result, "                days: Days {{")?;
     writeln!(
         result,
-        "                           format: {},",
-        serialize_month_names(&calendar.months.format)?
+        "                   format: {},",
+        serialize_day_names(&calendar.days.format, names)?
     )?;
     writeln!(
         result,
-        "                           stand_alone: {},",
-        serialize_month_names(&calendar.months.stand_alone)?
+        "                   stand_alone: {},",
+        serialize_day_names(&calendar.days.stand_alone, names)?
     )?;
-    writeln!(result, "                        }},")?;
-    writeln!(result, "                        days: Days {{")?;
+    writeln!(result, "                }},")?;
     writeln!(
         result,
-        "                           format: {},",
-        serialize_day_names(&calendar.days.format)?
+        "                eras: {},",
+        serialize_era_names(&calendar.eras, names)?
     )?;
     writeln!(
         result,
-        "                           stand_alone: {},",
-        serialize_day_names(&calendar.days.stand_alone)?
+        "                cyclic_name_sets: {},",
+        serialize_cyclic_names(&calendar.cyclic_name_sets, names)?
+    )?;
+    writeln!(
+        result,
+        "                month_patterns: {},",
+        serialize_month_patterns(&calendar.month_patterns)?
     )?;
-    writeln!(result, "                        }},")?;
     writeln!(
         result,
-        "                        date_formats: {}",
+        "                date_formats: {}",
         serialize_dt_formats(&calendar.date_formats)?
     )?;
     writeln!(
         result,
-        "                        time_formats: {}",
+        "                time_formats: {}",
         serialize_dt_formats(&calendar.time_formats)?
     )?;
     writeln!(
         result,
-        "                        date_time_formats: {}",
+        "                date_time_formats: {}",
         serialize_dt_formats(&calendar.date_time_formats)?
     )?;
-    writeln!(result, "                    }},")?;
-    writeln!(result, "                }},")?;
-    writeln!(result, "            }},")?;
+    write!(result, "            }})")?;
+    Ok(result)
+}
+
+fn serialize_calendar_data(
+    data: &Resource,
+    locale: &str,
+    names: &mut NameTable,
+) -> Result<String, std::fmt::Error> {
+    let calendars = &data.get(locale).unwrap().dates.calendars;
+    let mut result = String::new();
+
+    writeln!(result, "use super::names::*;")?;
+    writeln!(result, "use crate::data::layout::*;")?;
+    writeln!(result, "use std::borrow::Cow;\n")?;
+
+    writeln!(
+        result,
+        "pub static RESOURCE: LocaleResource<'static> = LocaleResource {{"
+    )?;
+    writeln!(result, "    dates: CalendarDates {{")?;
+    writeln!(result, "        calendars: Calendar {{")?;
+    for name in &Calendar::NAMES {
+        let calendar = match calendars.get(name) {
+            Some(calendar) => serialize_calendar(calendar, names)?,
+            None => "None".to_string(),
+        };
+        writeln!(result, "            {}: {},", name, calendar)?;
+    }
     writeln!(result, "        }},")?;
     writeln!(result, "    }},")?;
     write!(result, "}};")?;
     Ok(result)
 }
 
-fn clean_dir(path: &std::path::Path) -> std::io::Result<()> {
-    let paths = fs::read_dir(path).unwrap();
-    for path in paths {
-        fs::remove_file(path.unwrap().path())?;
-    }
-    Ok(())
-}
+/// Rust keywords, which locales such as `as` (Assamese) collide with.
+const KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "do", "dyn",
+    "else", "enum", "extern", "false", "final", "fn", "for", "if", "impl", "in", "let", "loop",
+    "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return", "static",
+    "struct", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use", "virtual",
+    "where", "while", "yield",
+];
 
-fn should_skip(locale: &str, include_locales: Option<&'static [&str]>) -> bool {
-    if let Some(includes) = include_locales {
-        !includes.contains(&locale)
+/// The module of the file `rust_file`, as a raw identifier for keywords.
+fn module_name(rust_file: String) -> String {
+    if KEYWORDS.contains(&rust_file.as_str()) {
+        format!("r#{}", rust_file)
     } else {
-        true
+        rust_file
     }
 }
 
-fn main() {
-    // Future config!
-    let cldr_dates_modern_path = "./data/cldr-dates-modern";
-    let include_locales: Option<&'static [&str]> = Some(&["pl"]);
-    let exclude_locales: &'static [&str] = &[];
-    let dest_path = "./src/data/generated/";
+/// The start of the `mod.rs` written by `generate`, which marks a directory
+/// as generated.
+const MOD_HEADER: &str = "use crate::data::layout::LocaleResource;\nmod names;\n";
 
-    let path = Path::new(cldr_dates_modern_path).join("main");
-    let dest_dir = Path::new(dest_path);
+/// Removes the files of an earlier run from `path`, found through the
+/// modules its `mod.rs` declares. Directories holding other Rust sources are
+/// refused, so that `-o src` cannot wipe the crate.
+fn clean_dir(path: &Path) -> Result<(), CliError> {
+    let not_generated = || {
+        CliError::Failed(format!(
+            "{} holds Rust files not written by generate_tables",
+            path.display()
+        ))
+    };
+    let mod_rs = match fs::read_to_string(path.join("mod.rs")) {
+        Ok(mod_rs) => mod_rs,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+            for entry in fs::read_dir(path)? {
+                if entry?.path().extension().is_some_and(|ext| ext == "rs") {
+                    return Err(not_generated());
+                }
+            }
+            return Ok(());
+        }
+        Err(err) => return Err(err.into()),
+    };
+    if !mod_rs.starts_with(MOD_HEADER) {
+        return Err(not_generated());
+    }
+    let modules = mod_rs.lines().filter_map(|line| {
+        let module = line.strip_prefix("pub mod ")?.strip_suffix(';')?;
+        Some(module.trim_start_matches("r#"))
+    });
+    for module in modules.chain(Some("names")) {
+        let file = path.join(format!("{}.rs", module));
+        if file.exists() {
+            fs::remove_file(file)?;
+        }
+    }
+    fs::remove_file(path.join("mod.rs"))?;
+    Ok(())
+}
 
-    clean_dir(dest_dir);
+fn generate(options: &Options) -> Result<(), CliError> {
+    let locales = options.locales()?;
+    let dest_dir = options.output.as_path();
 
-    let paths = fs::read_dir(path).unwrap();
+    fs::create_dir_all(dest_dir)?;
+    clean_dir(dest_dir)?;
 
+    let mut names = NameTable::default();
     let mut generated_locales = vec![];
-    for path in paths {
-        let locale = path
-            .unwrap()
-            .file_name()
-            .to_os_string()
-            .into_string()
-            .unwrap();
-        if should_skip(&locale, include_locales) {
-            continue;
-        }
-
-        // println!("Name: {:#?}", locale);
-        let data = get_calendar_data("./data/cldr-dates-modern", &locale);
-        let result = serialize_calendar_data(&data, &locale).unwrap();
+    for locale in locales {
+        let mut data = get_calendars_data(&options.input, &locale, &options.calendars)?;
+        options.apply_manifest(&mut data);
+        let result = serialize_calendar_data(&data, &locale, &mut names)?;
 
-        let rust_file = locale.replace("-", "_");
-        fs::write(dest_dir.join(format!("{}.rs", rust_file)), result)
-            .expect("Unable to write file");
-        generated_locales.push(rust_file);
+        let rust_file = locale.replace('-', "_").to_lowercase();
+        fs::write(dest_dir.join(format!("{}.rs", rust_file)), result)?;
+        generated_locales.push((locale, module_name(rust_file)));
     }
+    fs::write(dest_dir.join("names.rs"), names.serialize()?)?;
 
-    let mut res = String::from("use crate::data::layout::Resource;\n");
+    let (before, after) = (names.inline_size, names.size());
+    println!(
+        "Name lists: {} uses of {} distinct day, {} distinct month, {} distinct era and {} \
+         distinct cyclic lists, ~{} bytes instead of ~{} ({:.1}% saved)",
+        names.uses,
+        names.day_lists.len(),
+        names.month_lists.len(),
+        names.era_lists.len(),
+        names.cyclic_lists.len(),
+        after,
+        before,
+        100.0 - after as f64 * 100.0 / before.max(1) as f64,
+    );
+
+    let mut res = String::from(MOD_HEADER);
 
     res.push_str(
         &generated_locales
             .iter()
-            .map(|l| format!("pub mod {};", l))
+            .map(|(_, module)| format!("pub mod {};", module))
             .collect::<Vec<_>>()
             .join("\n"),
     );
@@ -287,13 +587,39 @@ fn main() {
     res.push_str(
         r#"
 
-pub fn get(locale: &str) -> &'static Resource<'static> {
+pub fn get(locale: &str) -> Option<&'static LocaleResource<'static>> {
     match locale {
-        "pl" => &pl::RESOURCE,
-        _ => panic!()
+"#,
+    );
+
+    for (locale, module) in &generated_locales {
+        res.push_str(&format!(
+            "        {:?} => Some(&{}::RESOURCE),\n",
+            locale, module
+        ));
+    }
+
+    res.push_str(
+        r#"        _ => None,
     }
 }
-    "#,
+"#,
     );
-    fs::write(dest_dir.join("mod.rs"), res).expect("Unable to write file");
+    fs::write(dest_dir.join("mod.rs"), res)?;
+    Ok(())
+}
+
+fn main() {
+    let tool = Tool {
+        name: "generate_tables",
+        output: "./src/data/generated/",
+        formats: &[OutputFormat::Rust],
+    };
+    cli::run(|| {
+        let options = tool.parse_args()?;
+        match options.format {
+            OutputFormat::Rust => generate(&options),
+            format => unreachable!("{:?} is not offered by this tool", format),
+        }
+    });
 }
@@ -0,0 +1,102 @@
+mod cli;
+
+use cli::{CliError, Options, OutputFormat, Tool};
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::fs;
+use unic_datetime::calendar;
+use unic_datetime::data::load_json;
+use unic_datetime::data::validate::validate_calendar;
+
+/// Validates every selected locale, returning the report and the number
+/// of locales with issues.
+fn validate(options: &Options) -> Result<(String, usize), CliError> {
+    let locales = options.locales()?;
+    let mut report = String::new();
+    let mut failed = 0;
+    // Unsupported field symbols, with the locales using them.
+    let mut unsupported: BTreeMap<char, Vec<String>> = BTreeMap::new();
+
+    for locale in &locales {
+        let issues = match load_json::get_calendars_data(&options.input, locale, &options.calendars)
+        {
+            Ok(res) => {
+                let mut issues = vec![];
+                for name in &options.calendars {
+                    // Variants share their data, so one of them is enough.
+                    let system = calendar::ALL
+                        .iter()
+                        .find(|system| system.data_name() == name)
+                        .expect("every calendar in the layout has a system");
+                    match res.get(locale).and_then(|l| l.get_calendar(name)) {
+                        Some(calendar) => {
+                            for issue in validate_calendar(calendar, *system) {
+                                if let Some(symbol) = issue.unsupported_symbol() {
+                                    let locales = unsupported.entry(symbol).or_default();
+                                    if !locales.contains(locale) {
+                                        locales.push(locale.clone());
+                                    }
+                                }
+                                issues.push(issue.to_string());
+                            }
+                        }
+                        None => issues.push(format!("no {} calendar data for {}", name, locale)),
+                    }
+                }
+                issues
+            }
+            Err(err) => vec![format!("failed to load: {}", err)],
+        };
+
+        if issues.is_empty() {
+            writeln!(report, "{}: ok", locale)?;
+        } else {
+            failed += 1;
+            writeln!(report, "{}: {} issues", locale, issues.len())?;
+            for issue in issues {
+                writeln!(report, "    {}", issue)?;
+            }
+        }
+    }
+
+    if !unsupported.is_empty() {
+        writeln!(report, "\nUnsupported symbols:")?;
+        for (symbol, locales) in &unsupported {
+            writeln!(
+                report,
+                "    {} in {} locales: {}",
+                symbol,
+                locales.len(),
+                locales.join(", ")
+            )?;
+        }
+    }
+    writeln!(
+        report,
+        "\nValidated {} locales, {} with issues",
+        locales.len(),
+        failed
+    )?;
+    Ok((report, failed))
+}
+
+fn main() {
+    let tool = Tool {
+        name: "validate_data",
+        output: "-",
+        formats: &[OutputFormat::Text],
+    };
+    cli::run(|| {
+        let options = tool.parse_args()?;
+        let (report, failed) = validate(&options)?;
+        if options.output.as_os_str() == "-" {
+            print!("{}", report);
+        } else {
+            fs::write(&options.output, report)?;
+        }
+        if failed > 0 {
+            return Err(CliError::Failed(format!("{} locales have issues", failed)));
+        }
+        Ok(())
+    });
+}
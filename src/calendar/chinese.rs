@@ -0,0 +1,454 @@
+use super::gregorian::{fixed_from_gregorian, gregorian_from_fixed};
+use super::{CalendarDate, CalendarSystem};
+use std::convert::TryFrom;
+
+/// The Chinese lunisolar calendar.
+///
+/// Years are numbered within 60-year cycles: the era is the number of the
+/// cycle and the year runs from 1 to 60. Months are numbered 1 to 12, and a
+/// leap month repeats the number of the month before it.
+///
+/// Month lengths and leap months come from a table covering 1900 to 2100,
+/// which matches ICU. Other years are computed from the mean motions of the
+/// sun and moon, which may put a month start a day or two off.
+pub struct Chinese;
+
+/// The Korean lunisolar calendar, reckoned like the Chinese one but for the
+/// meridian of Korea.
+pub struct Dangi;
+
+const FIRST_YEAR: i64 = 1900;
+
+/// Fixed day number of the new year of 1900.
+const FIRST_NEW_YEAR: i64 = 693_626;
+
+/// Added to a Gregorian year to count years from 2637 BC, the first year of
+/// the first 60-year cycle.
+const CYCLE_EPOCH_OFFSET: i64 = 2637;
+
+const MEAN_SYNODIC_MONTH: f64 = 29.530_588_853;
+const MEAN_TROPICAL_YEAR: f64 = 365.242_189;
+
+/// The mean new moon of January 6, 2000, as a fixed moment in UT.
+const MEAN_NEW_MOON: f64 = 730_125.597_66;
+
+/// The mean winter solstice of December 21, 2000, as a fixed moment in UT.
+const MEAN_WINTER_SOLSTICE: f64 = 730_475.567;
+
+/// A bit per 30-day month of each year from 1900, starting with the first
+/// month in the lowest bit. Other months have 29 days. Bits 13 and up hold
+/// the position of the leap month, or 0 in years without one. The lengths
+/// match ICU's Chinese calendar.
+const CHINESE_MONTHS: [u32; 201] = [
+    0x136d2, 0x00752, 0x00ea5, 0x0d64a, 0x0064b, 0x00a9b, 0x0b556, 0x0056a, 0x00b59, 0x07752,
+    0x00752, 0x0fb25, 0x00b25, 0x00a4b, 0x0d4ab, 0x002ad, 0x0056b, 0x08b69, 0x00da9, 0x11d92,
+    0x00e92, 0x00d25, 0x0fa4d, 0x00a56, 0x002b6, 0x0b5b5, 0x006d4, 0x00ea9, 0x07e92, 0x00e92,
+    0x0ed26, 0x0052b, 0x00a57, 0x0d2b6, 0x00b5a, 0x006d4, 0x08ec9, 0x00749, 0x11693, 0x00a93,
+    0x0052b, 0x0ea5b, 0x00aad, 0x0056a, 0x0bb55, 0x00ba4, 0x00b49, 0x07a93, 0x00a95, 0x1152d,
+    0x00536, 0x00aad, 0x0d5aa, 0x00db2, 0x00da4, 0x09d49, 0x00d4a, 0x12a95, 0x00a97, 0x00556,
+    0x0eab5, 0x00ad5, 0x006d2, 0x0aea5, 0x00ea5, 0x0064a, 0x08c97, 0x00a9b, 0x1155a, 0x0056a,
+    0x00b69, 0x0d752, 0x00b52, 0x00b25, 0x0b64b, 0x00a4b, 0x134ab, 0x002ad, 0x0056d, 0x0eb69,
+    0x00da9, 0x00d92, 0x0bd25, 0x00d25, 0x17a4d, 0x00a56, 0x002b6, 0x105b5, 0x006d5, 0x00ea9,
+    0x0de92, 0x00e92, 0x00d26, 0x08a56, 0x00a57, 0x134d6, 0x0035a, 0x006d5, 0x0cec9, 0x00749,
+    0x00693, 0x0b52b, 0x0052b, 0x00a5b, 0x0755a, 0x0056a, 0x11b55, 0x00ba4, 0x00b49, 0x0da93,
+    0x00a95, 0x0052d, 0x0aa6d, 0x00ab5, 0x155aa, 0x005d2, 0x00da5, 0x0fd4a, 0x00e4a, 0x00c95,
+    0x0b52e, 0x00556, 0x00ab5, 0x075b2, 0x006d2, 0x0eea5, 0x00f25, 0x0064a, 0x0cc97, 0x004ab,
+    0x0055b, 0x08ad6, 0x00b69, 0x19752, 0x00b52, 0x00b25, 0x0fa4b, 0x00a4b, 0x004ab, 0x0c55b,
+    0x005ad, 0x00b6a, 0x07b52, 0x00d92, 0x11d25, 0x00d25, 0x00a55, 0x0d4ad, 0x004b6, 0x005b5,
+    0x08daa, 0x00ec9, 0x13e92, 0x00e92, 0x00d26, 0x0ea56, 0x00a57, 0x004d6, 0x0a6d5, 0x00755,
+    0x00749, 0x08e93, 0x00693, 0x1152b, 0x0052b, 0x00a5b, 0x0d55a, 0x0056a, 0x00b65, 0x0b74a,
+    0x00b49, 0x13a95, 0x00a95, 0x0052d, 0x0eaad, 0x00ab5, 0x005aa, 0x0aba5, 0x00da5, 0x00d4a,
+    0x09c95, 0x00c96, 0x1194e, 0x00556, 0x00ab5, 0x0d5b2, 0x006d2, 0x00ea5, 0x0ae4a, 0x0068b,
+    0x12c97, 0x004ab, 0x0055b, 0x0ead6, 0x00b6a, 0x00752, 0x0b725, 0x00b45, 0x00a8b, 0x0749b,
+    0x004ab,
+];
+
+/// The same as `CHINESE_MONTHS`, for ICU's Dangi calendar.
+const DANGI_MONTHS: [u32; 201] = [
+    0x136d2, 0x00752, 0x00ea5, 0x0d64a, 0x0064b, 0x00a9b, 0x0b556, 0x0056a, 0x00b59, 0x07752,
+    0x00752, 0x0fb25, 0x00b25, 0x00a4b, 0x0d29b, 0x00aad, 0x0056a, 0x06b69, 0x00ba9, 0x11b52,
+    0x00d92, 0x00d25, 0x0da4d, 0x00956, 0x002b5, 0x0b5ad, 0x006d4, 0x00da9, 0x07d92, 0x00e92,
+    0x0ed26, 0x00527, 0x00a57, 0x0d2b6, 0x00ada, 0x006d4, 0x08ea9, 0x00749, 0x11693, 0x00a93,
+    0x0052b, 0x0ea5b, 0x0096d, 0x00b6a, 0x0bb54, 0x00ba4, 0x00b49, 0x07a93, 0x00a95, 0x1152b,
+    0x0052d, 0x00aad, 0x0d56a, 0x00db2, 0x00da4, 0x09d49, 0x00d4a, 0x13a95, 0x00a96, 0x00556,
+    0x0eab5, 0x00ad5, 0x006d2, 0x0aea5, 0x00ea5, 0x00e4a, 0x08c96, 0x00a9b, 0x11556, 0x0056a,
+    0x00b59, 0x0d752, 0x00752, 0x00725, 0x0b64b, 0x00a4b, 0x132ab, 0x002ad, 0x0056b, 0x0eb69,
+    0x00da9, 0x00d92, 0x0bb25, 0x00d25, 0x17a4d, 0x00a56, 0x002b6, 0x0f5ad, 0x006d4, 0x00da9,
+    0x0dd92, 0x00e92, 0x00d26, 0x08a56, 0x00a57, 0x132b6, 0x00b5a, 0x006d4, 0x0cec9, 0x00749,
+    0x00693, 0x0b527, 0x0052b, 0x00a5b, 0x0755a, 0x0036a, 0x11b55, 0x00ba4, 0x00b49, 0x0da93,
+    0x00a95, 0x0052d, 0x08a5d, 0x00aad, 0x155aa, 0x005d2, 0x00da5, 0x0dd49, 0x00d4a, 0x00a95,
+    0x0b52d, 0x00556, 0x00ab5, 0x075aa, 0x006d2, 0x0eea5, 0x00ea5, 0x00e4a, 0x0cc96, 0x00c9b,
+    0x0055a, 0x08ad5, 0x00b69, 0x19752, 0x00752, 0x00b25, 0x0f64b, 0x00a4b, 0x004ab, 0x0c55b,
+    0x0056d, 0x00b69, 0x07b52, 0x00d92, 0x11d25, 0x00d25, 0x00a4d, 0x0d4ad, 0x002b6, 0x005b5,
+    0x08da9, 0x00dc9, 0x13d92, 0x00e92, 0x00d26, 0x0ea56, 0x00a57, 0x004d6, 0x0a6b5, 0x006d5,
+    0x00ec9, 0x08e92, 0x00693, 0x1152b, 0x0052b, 0x00a5b, 0x0d55a, 0x0056a, 0x00b55, 0x0b749,
+    0x00b49, 0x13a93, 0x00a95, 0x0052d, 0x0eaad, 0x00ab5, 0x005aa, 0x0aba5, 0x00da5, 0x00d4a,
+    0x09a95, 0x00c95, 0x1152e, 0x00556, 0x00ab5, 0x0d5b2, 0x006d2, 0x00ea5, 0x0be4a, 0x0064a,
+    0x12c97, 0x00cab, 0x0055a, 0x0ead5, 0x00b69, 0x00752, 0x0aea5, 0x00b25, 0x0064b, 0x09497,
+    0x004ab,
+];
+
+/// The months of a year, packed as in `CHINESE_MONTHS`.
+#[derive(Clone, Copy)]
+struct YearMonths(u32);
+
+impl YearMonths {
+    /// The position of the leap month, if the year has one.
+    fn leap_month(self) -> Option<usize> {
+        match self.0 >> 13 {
+            0 => None,
+            leap_month => Some(leap_month as usize),
+        }
+    }
+
+    fn len(self) -> usize {
+        if self.leap_month().is_some() {
+            13
+        } else {
+            12
+        }
+    }
+
+    fn days_in_month(self, ordinal_month: usize) -> usize {
+        if self.0 & (1 << (ordinal_month - 1)) != 0 {
+            30
+        } else {
+            29
+        }
+    }
+
+    fn days(self) -> i64 {
+        29 * self.len() as i64 + (self.0 & 0x1fff).count_ones() as i64
+    }
+
+    /// The number of the `ordinal_month`-th month, and whether it is the
+    /// leap month.
+    fn month(self, ordinal_month: usize) -> (usize, bool) {
+        match self.leap_month() {
+            Some(leap_month) if ordinal_month >= leap_month => {
+                (ordinal_month - 1, ordinal_month == leap_month)
+            }
+            _ => (ordinal_month, false),
+        }
+    }
+
+    /// The position of the month numbered `month`, or of the leap month
+    /// following it.
+    fn ordinal_month(self, month: usize, leap_month: bool) -> usize {
+        match self.leap_month() {
+            Some(leap) if month >= leap || (leap_month && month + 1 == leap) => month + 1,
+            _ => month,
+        }
+    }
+}
+
+/// A calendar of the Chinese family, reckoned at `offset`, the fraction of
+/// a day by which local time is ahead of UT.
+struct Lunisolar {
+    months: &'static [u32; 201],
+    offset: f64,
+}
+
+const CHINA: Lunisolar = Lunisolar {
+    months: &CHINESE_MONTHS,
+    offset: 8.0 / 24.0,
+};
+
+const KOREA: Lunisolar = Lunisolar {
+    months: &DANGI_MONTHS,
+    offset: 9.0 / 24.0,
+};
+
+impl Lunisolar {
+    fn table_index(&self, related_year: i64) -> Option<usize> {
+        let idx = usize::try_from(related_year - FIRST_YEAR).ok()?;
+        Some(idx).filter(|idx| *idx < self.months.len())
+    }
+
+    /// The fixed day number of the first day after the table.
+    fn table_end(&self) -> i64 {
+        FIRST_NEW_YEAR
+            + self
+                .months
+                .iter()
+                .map(|&months| YearMonths(months).days())
+                .sum::<i64>()
+    }
+
+    /// The new year and months of the year starting in the Gregorian year
+    /// `related_year`.
+    fn year(&self, related_year: i64) -> (i64, YearMonths) {
+        match self.table_index(related_year) {
+            Some(idx) => {
+                let new_year = FIRST_NEW_YEAR
+                    + self.months[..idx]
+                        .iter()
+                        .map(|&months| YearMonths(months).days())
+                        .sum::<i64>();
+                (new_year, YearMonths(self.months[idx]))
+            }
+            None => self.mean_year(related_year),
+        }
+    }
+
+    fn related_year(era: usize, year: i32) -> i64 {
+        (era as i64 - 1) * 60 + year as i64 - CYCLE_EPOCH_OFFSET
+    }
+
+    fn date_from_fixed(&self, fixed: i64) -> CalendarDate {
+        let mut related_year = gregorian_from_fixed(fixed).0;
+        let (mut new_year, mut months) = self.year(related_year);
+        if fixed < new_year {
+            related_year -= 1;
+            let year = self.year(related_year);
+            new_year = year.0;
+            months = year.1;
+        }
+
+        let mut day = (fixed - new_year) as usize;
+        let mut ordinal_month = 1;
+        while day >= months.days_in_month(ordinal_month) {
+            day -= months.days_in_month(ordinal_month);
+            ordinal_month += 1;
+        }
+        let (month, leap_month) = months.month(ordinal_month);
+        let elapsed_years = related_year + CYCLE_EPOCH_OFFSET - 1;
+        CalendarDate {
+            era: (elapsed_years.div_euclid(60) + 1) as usize,
+            year: (elapsed_years.rem_euclid(60) + 1) as i32,
+            month,
+            ordinal_month,
+            leap_month,
+            day: day + 1,
+        }
+    }
+
+    fn fixed_from_date(&self, date: &CalendarDate) -> i64 {
+        let (new_year, months) = self.year(Self::related_year(date.era, date.year));
+        let prior_days: usize = (1..months.ordinal_month(date.month, date.leap_month))
+            .map(|ordinal_month| months.days_in_month(ordinal_month))
+            .sum();
+        new_year + prior_days as i64 + date.day as i64 - 1
+    }
+
+    fn year_months(&self, era: usize, year: i32) -> YearMonths {
+        self.year(Self::related_year(era, year)).1
+    }
+
+    /// The first day on or after `day` with a mean new moon.
+    fn new_moon_on_or_after(&self, day: i64) -> i64 {
+        let n = ((day as f64 - self.offset - MEAN_NEW_MOON) / MEAN_SYNODIC_MONTH).ceil();
+        (MEAN_NEW_MOON + n * MEAN_SYNODIC_MONTH + self.offset).floor() as i64
+    }
+
+    /// The last day before `day` with a mean new moon.
+    fn new_moon_before(&self, day: i64) -> i64 {
+        let n = ((day as f64 - self.offset - MEAN_NEW_MOON) / MEAN_SYNODIC_MONTH).ceil() - 1.0;
+        (MEAN_NEW_MOON + n * MEAN_SYNODIC_MONTH + self.offset).floor() as i64
+    }
+
+    /// The last day on or before `day` with a mean winter solstice.
+    fn winter_solstice_on_or_before(&self, day: i64) -> i64 {
+        let n = ((day as f64 + 1.0 - self.offset - MEAN_WINTER_SOLSTICE) / MEAN_TROPICAL_YEAR)
+            .ceil()
+            - 1.0;
+        (MEAN_WINTER_SOLSTICE + n * MEAN_TROPICAL_YEAR + self.offset).floor() as i64
+    }
+
+    /// The number of mean major solar terms, which divide the year in
+    /// twelve from the winter solstice, before `day`.
+    fn major_terms_before(&self, day: i64) -> i64 {
+        let term = MEAN_TROPICAL_YEAR / 12.0;
+        ((day as f64 - self.offset - MEAN_WINTER_SOLSTICE) / term).ceil() as i64
+    }
+
+    /// Whether the month starting on `new_moon` has no major solar term.
+    fn no_major_term(&self, new_moon: i64) -> bool {
+        let next_new_moon = self.new_moon_on_or_after(new_moon + 1);
+        self.major_terms_before(next_new_moon) == self.major_terms_before(new_moon)
+    }
+
+    /// The new year of the period from the winter solstice on or before
+    /// `day` to the next one. It is the second new moon after the solstice,
+    /// or the third if a leap month comes first.
+    fn new_year_in_sui(&self, day: i64) -> i64 {
+        let solstice = self.winter_solstice_on_or_before(day);
+        let next_solstice = self.winter_solstice_on_or_before(solstice + 370);
+        let month_12 = self.new_moon_on_or_after(solstice + 1);
+        let month_13 = self.new_moon_on_or_after(month_12 + 1);
+        let next_month_11 = self.new_moon_before(next_solstice + 1);
+        let leap_sui = ((next_month_11 - month_12) as f64 / MEAN_SYNODIC_MONTH).round() == 12.0;
+        if leap_sui && (self.no_major_term(month_12) || self.no_major_term(month_13)) {
+            self.new_moon_on_or_after(month_13 + 1)
+        } else {
+            month_13
+        }
+    }
+
+    fn mean_new_year(&self, related_year: i64) -> i64 {
+        // Keep the mean years contiguous with the table.
+        if related_year == FIRST_YEAR + self.months.len() as i64 {
+            return self.table_end();
+        }
+        if related_year == FIRST_YEAR {
+            return FIRST_NEW_YEAR;
+        }
+        let midyear = fixed_from_gregorian(related_year, 7, 1);
+        let new_year = self.new_year_in_sui(midyear);
+        if new_year <= midyear {
+            new_year
+        } else {
+            self.new_year_in_sui(midyear - 180)
+        }
+    }
+
+    /// Computes a year outside the table. The 30-day months are spread
+    /// evenly over the year, and the leap month is the first month without
+    /// a major solar term.
+    fn mean_year(&self, related_year: i64) -> (i64, YearMonths) {
+        let new_year = self.mean_new_year(related_year);
+        let days = self.mean_new_year(related_year + 1) - new_year;
+        let len = (days as f64 / MEAN_SYNODIC_MONTH).round() as i64;
+        let month_start = |idx: i64| new_year + (idx * days + len / 2) / len;
+
+        let mut months = 0;
+        let mut leap_month = None;
+        for idx in 0..len {
+            let (start, end) = (month_start(idx), month_start(idx + 1));
+            if end - start == 30 {
+                months |= 1 << idx;
+            }
+            if len == 13
+                && leap_month.is_none()
+                && self.major_terms_before(end) == self.major_terms_before(start)
+            {
+                leap_month = Some(idx as u32 + 1);
+            }
+        }
+        if len == 13 {
+            months |= leap_month.unwrap_or(13) << 13;
+        }
+        (new_year, YearMonths(months))
+    }
+}
+
+macro_rules! lunisolar_calendar {
+    ($calendar:ident, $name:expr, $reckoning:expr) => {
+        impl CalendarSystem for $calendar {
+            fn name(&self) -> &'static str {
+                $name
+            }
+
+            fn date_from_fixed(&self, fixed: i64) -> CalendarDate {
+                $reckoning.date_from_fixed(fixed)
+            }
+
+            fn fixed_from_date(&self, date: &CalendarDate) -> i64 {
+                $reckoning.fixed_from_date(date)
+            }
+
+            fn months_in_year(&self, era: usize, year: i32) -> usize {
+                $reckoning.year_months(era, year).len()
+            }
+
+            fn days_in_month(&self, era: usize, year: i32, ordinal_month: usize) -> usize {
+                $reckoning
+                    .year_months(era, year)
+                    .days_in_month(ordinal_month)
+            }
+
+            fn is_leap_year(&self, era: usize, year: i32) -> bool {
+                $reckoning.year_months(era, year).leap_month().is_some()
+            }
+
+            fn is_leap_month(&self, era: usize, year: i32, ordinal_month: usize) -> bool {
+                $reckoning.year_months(era, year).leap_month() == Some(ordinal_month)
+            }
+
+            fn cyclic_year(&self, date: &CalendarDate) -> Option<usize> {
+                Some(date.year as usize)
+            }
+        }
+    };
+}
+
+lunisolar_calendar!(Chinese, "chinese", CHINA);
+lunisolar_calendar!(Dangi, "dangi", KOREA);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(system: &dyn CalendarSystem, year: i64, month: usize, day: usize) -> CalendarDate {
+        system.date_from_fixed(fixed_from_gregorian(year, month, day))
+    }
+
+    /// The cycle, year, month, leap flag and day of a date.
+    fn fields(date: CalendarDate) -> (usize, i32, usize, bool, usize) {
+        (date.era, date.year, date.month, date.leap_month, date.day)
+    }
+
+    #[test]
+    fn test_chinese() {
+        // Reference values from ICU.
+        let chinese = |year, month, day| fields(date(&Chinese, year, month, day));
+        assert_eq!(chinese(1900, 1, 31), (76, 37, 1, false, 1));
+        assert_eq!(chinese(2021, 2, 11), (78, 37, 12, false, 30));
+        assert_eq!(chinese(2021, 2, 12), (78, 38, 1, false, 1));
+        assert_eq!(chinese(2021, 10, 18), (78, 38, 9, false, 13));
+        assert_eq!(chinese(2020, 5, 23), (78, 37, 4, true, 1));
+        assert_eq!(chinese(2020, 6, 21), (78, 37, 5, false, 1));
+        assert_eq!(chinese(2017, 7, 23), (78, 34, 6, true, 1));
+
+        assert_eq!(date(&Chinese, 2020, 5, 23).ordinal_month, 5);
+        assert_eq!(date(&Chinese, 2020, 6, 21).ordinal_month, 6);
+        assert!(Chinese.is_leap_year(78, 40));
+        assert_eq!(Chinese.months_in_year(78, 40), 13);
+        assert!(Chinese.is_leap_month(78, 40, 3));
+    }
+
+    #[test]
+    fn test_dangi() {
+        let dangi = |year, month, day| fields(date(&Dangi, year, month, day));
+        assert_eq!(dangi(2021, 10, 18), (78, 38, 9, false, 13));
+        assert_eq!(dangi(2020, 5, 23), (78, 37, 4, true, 1));
+        // Korea had the leap month after the fifth month in 2017.
+        assert_eq!(dangi(2017, 7, 23), (78, 34, 6, false, 1));
+        assert!(Dangi.is_leap_month(78, 34, 6));
+    }
+
+    #[test]
+    fn test_mean_years() {
+        // ICU has the fourth month, day 21, and the fifth month, day 6.
+        let date_1850 = date(&Chinese, 1850, 6, 1);
+        assert_eq!(
+            (date_1850.era, date_1850.year, date_1850.month),
+            (75, 47, 4)
+        );
+        assert!((20..=22).contains(&date_1850.day));
+        let date_2150 = date(&Chinese, 2150, 6, 1);
+        assert_eq!(
+            (date_2150.era, date_2150.year, date_2150.month),
+            (80, 47, 5)
+        );
+        assert!((5..=7).contains(&date_2150.day));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for system in &[&Chinese as &dyn CalendarSystem, &Dangi] {
+            let mut previous = system.date_from_fixed(640_000);
+            for fixed in 640_001..780_000 {
+                let date = system.date_from_fixed(fixed);
+                assert_eq!(system.fixed_from_date(&date), fixed, "{:?}", date);
+                if date.day == 1 {
+                    assert!((29..=30).contains(&previous.day), "{:?}", previous);
+                    assert!(date.ordinal_month <= system.months_in_year(date.era, date.year));
+                } else {
+                    assert_eq!(date.day, previous.day + 1);
+                }
+                previous = date;
+            }
+        }
+    }
+}
@@ -0,0 +1,224 @@
+use super::{CalendarDate, CalendarSystem};
+
+/// The Coptic calendar, with the eras before (0) and after (1) Diocletian.
+///
+/// Years have twelve months of 30 days followed by Nasie, a 13th month of
+/// five days, or six in leap years.
+pub struct Coptic;
+
+/// The Ethiopic calendar, with the eras Amete Alem (0) and Amete Mihret (1).
+///
+/// It has the months of the Coptic calendar with a later epoch. Years
+/// before Amete Mihret count from the creation, 5500 years earlier, as in
+/// ICU.
+pub struct Ethiopic;
+
+/// The Ethiopic calendar counting every year in Amete Alem (0), CLDR's
+/// `ethiopic-amete-alem`. It formats with the data of `ethiopic`.
+pub struct EthiopicAmeteAlem;
+
+/// Fixed day number of 1 Tout 1 in the Coptic calendar.
+const COPTIC_EPOCH: i64 = 103_605;
+
+/// Fixed day number of 1 Meskerem 1 Amete Mihret.
+const ETHIOPIC_EPOCH: i64 = 2_796;
+
+const AMETE_ALEM_OFFSET: i32 = 5_500;
+
+fn is_coptic_leap_year(year: i64) -> bool {
+    year.rem_euclid(4) == 3
+}
+
+fn days_in_coptic_month(year: i64, month: usize) -> usize {
+    match month {
+        13 if is_coptic_leap_year(year) => 6,
+        13 => 5,
+        _ => 30,
+    }
+}
+
+fn fixed_from_coptic(epoch: i64, year: i64, month: usize, day: usize) -> i64 {
+    epoch - 1 + 365 * (year - 1) + year.div_euclid(4) + 30 * (month as i64 - 1) + day as i64
+}
+
+fn coptic_from_fixed(epoch: i64, fixed: i64) -> (i64, usize, usize) {
+    let year = (4 * (fixed - epoch) + 1463).div_euclid(1461);
+    let month = ((fixed - fixed_from_coptic(epoch, year, 1, 1)) / 30 + 1) as usize;
+    let day = (fixed - fixed_from_coptic(epoch, year, month, 1) + 1) as usize;
+    (year, month, day)
+}
+
+impl Coptic {
+    fn year(era: usize, year: i32) -> i64 {
+        if era == 0 {
+            1 - year as i64
+        } else {
+            year as i64
+        }
+    }
+}
+
+impl Ethiopic {
+    fn year(era: usize, year: i32) -> i64 {
+        if era == 0 {
+            (year - AMETE_ALEM_OFFSET) as i64
+        } else {
+            year as i64
+        }
+    }
+}
+
+impl CalendarSystem for Coptic {
+    fn name(&self) -> &'static str {
+        "coptic"
+    }
+
+    fn date_from_fixed(&self, fixed: i64) -> CalendarDate {
+        let (year, month, day) = coptic_from_fixed(COPTIC_EPOCH, fixed);
+        if year > 0 {
+            CalendarDate::new(1, year as i32, month, day)
+        } else {
+            CalendarDate::new(0, (1 - year) as i32, month, day)
+        }
+    }
+
+    fn fixed_from_date(&self, date: &CalendarDate) -> i64 {
+        let year = Self::year(date.era, date.year);
+        fixed_from_coptic(COPTIC_EPOCH, year, date.month, date.day)
+    }
+
+    fn months_in_year(&self, _era: usize, _year: i32) -> usize {
+        13
+    }
+
+    fn days_in_month(&self, era: usize, year: i32, ordinal_month: usize) -> usize {
+        days_in_coptic_month(Self::year(era, year), ordinal_month)
+    }
+
+    fn is_leap_year(&self, era: usize, year: i32) -> bool {
+        is_coptic_leap_year(Self::year(era, year))
+    }
+}
+
+impl CalendarSystem for Ethiopic {
+    fn name(&self) -> &'static str {
+        "ethiopic"
+    }
+
+    fn date_from_fixed(&self, fixed: i64) -> CalendarDate {
+        let (year, month, day) = coptic_from_fixed(ETHIOPIC_EPOCH, fixed);
+        if year > 0 {
+            CalendarDate::new(1, year as i32, month, day)
+        } else {
+            CalendarDate::new(0, year as i32 + AMETE_ALEM_OFFSET, month, day)
+        }
+    }
+
+    fn fixed_from_date(&self, date: &CalendarDate) -> i64 {
+        let year = Self::year(date.era, date.year);
+        fixed_from_coptic(ETHIOPIC_EPOCH, year, date.month, date.day)
+    }
+
+    fn months_in_year(&self, _era: usize, _year: i32) -> usize {
+        13
+    }
+
+    fn days_in_month(&self, era: usize, year: i32, ordinal_month: usize) -> usize {
+        days_in_coptic_month(Self::year(era, year), ordinal_month)
+    }
+
+    fn is_leap_year(&self, era: usize, year: i32) -> bool {
+        is_coptic_leap_year(Self::year(era, year))
+    }
+}
+
+impl CalendarSystem for EthiopicAmeteAlem {
+    fn name(&self) -> &'static str {
+        "ethiopic-amete-alem"
+    }
+
+    fn data_name(&self) -> &'static str {
+        "ethiopic"
+    }
+
+    fn date_from_fixed(&self, fixed: i64) -> CalendarDate {
+        let (year, month, day) = coptic_from_fixed(ETHIOPIC_EPOCH, fixed);
+        CalendarDate::new(0, year as i32 + AMETE_ALEM_OFFSET, month, day)
+    }
+
+    fn fixed_from_date(&self, date: &CalendarDate) -> i64 {
+        Ethiopic.fixed_from_date(date)
+    }
+
+    fn months_in_year(&self, _era: usize, _year: i32) -> usize {
+        13
+    }
+
+    fn days_in_month(&self, era: usize, year: i32, ordinal_month: usize) -> usize {
+        Ethiopic.days_in_month(era, year, ordinal_month)
+    }
+
+    fn is_leap_year(&self, era: usize, year: i32) -> bool {
+        Ethiopic.is_leap_year(era, year)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendar::fixed_from_gregorian;
+
+    fn coptic(year: i64, month: usize, day: usize) -> CalendarDate {
+        Coptic.date_from_fixed(fixed_from_gregorian(year, month, day))
+    }
+
+    fn ethiopic(year: i64, month: usize, day: usize) -> CalendarDate {
+        Ethiopic.date_from_fixed(fixed_from_gregorian(year, month, day))
+    }
+
+    #[test]
+    fn test_coptic_from_gregorian() {
+        // Reference values from ICU.
+        assert_eq!(coptic(2021, 10, 18), CalendarDate::new(1, 1738, 2, 8));
+        assert_eq!(coptic(2023, 9, 6), CalendarDate::new(1, 1739, 13, 1));
+        assert_eq!(coptic(2023, 9, 11), CalendarDate::new(1, 1739, 13, 6));
+        assert_eq!(coptic(2023, 9, 12), CalendarDate::new(1, 1740, 1, 1));
+        assert_eq!(coptic(2024, 9, 10), CalendarDate::new(1, 1740, 13, 5));
+        assert_eq!(coptic(300, 1, 1), CalendarDate::new(1, 16, 5, 5));
+        assert_eq!(coptic(100, 1, 1), CalendarDate::new(0, 185, 5, 7));
+        assert!(Coptic.is_leap_year(1, 1739));
+        assert_eq!(Coptic.days_in_month(1, 1740, 13), 5);
+    }
+
+    #[test]
+    fn test_ethiopic_from_gregorian() {
+        // Reference values from ICU.
+        assert_eq!(ethiopic(2021, 10, 18), CalendarDate::new(1, 2014, 2, 8));
+        assert_eq!(ethiopic(2023, 9, 11), CalendarDate::new(1, 2015, 13, 6));
+        assert_eq!(ethiopic(2023, 9, 12), CalendarDate::new(1, 2016, 1, 1));
+        assert_eq!(ethiopic(1900, 1, 1), CalendarDate::new(1, 1892, 4, 23));
+        assert_eq!(ethiopic(-5000, 1, 1), CalendarDate::new(0, 492, 6, 15));
+
+        let amete_alem = |year, month, day| {
+            EthiopicAmeteAlem.date_from_fixed(fixed_from_gregorian(year, month, day))
+        };
+        assert_eq!(amete_alem(2021, 10, 18), CalendarDate::new(0, 7514, 2, 8));
+        assert_eq!(amete_alem(-5000, 1, 1), CalendarDate::new(0, 492, 6, 15));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for fixed in -1_000..1_000 {
+            let fixed = 730_000 + fixed * 997;
+            for system in &[
+                &Coptic as &dyn CalendarSystem,
+                &Ethiopic,
+                &EthiopicAmeteAlem,
+            ] {
+                let date = system.date_from_fixed(fixed);
+                assert_eq!(system.fixed_from_date(&date), fixed);
+                assert!(date.day <= system.days_in_month(date.era, date.year, date.month));
+            }
+        }
+    }
+}
@@ -0,0 +1,272 @@
+use super::{CalendarDate, CalendarSystem};
+
+/// The proleptic Gregorian calendar, with the eras BC (0) and AD (1).
+pub struct Gregorian;
+
+/// The Thai Buddhist calendar, with the single era BE (0).
+///
+/// It is the Gregorian calendar with years counted from 543 BC. Month and
+/// day names are those of the Gregorian calendar.
+pub struct Buddhist;
+
+/// The Republic of China (Minguo) calendar, with the eras before (0) and
+/// after (1) 1912.
+///
+/// It is the Gregorian calendar with years counted from 1912. Month and
+/// day names are those of the Gregorian calendar.
+pub struct Roc;
+
+const BUDDHIST_OFFSET: i64 = 543;
+
+const ROC_OFFSET: i64 = 1911;
+
+pub fn is_gregorian_leap_year(year: i64) -> bool {
+    year.rem_euclid(4) == 0 && (year.rem_euclid(100) != 0 || year.rem_euclid(400) == 0)
+}
+
+pub fn days_in_gregorian_month(year: i64, month: usize) -> usize {
+    match month {
+        2 if is_gregorian_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// The fixed day number of a proleptic Gregorian date, where year 0 is
+/// 1 BC.
+pub fn fixed_from_gregorian(year: i64, month: usize, day: usize) -> i64 {
+    let prior_years = year - 1;
+    let month = month as i64;
+    let correction = if month <= 2 {
+        0
+    } else if is_gregorian_leap_year(year) {
+        -1
+    } else {
+        -2
+    };
+    365 * prior_years + prior_years.div_euclid(4) - prior_years.div_euclid(100)
+        + prior_years.div_euclid(400)
+        + (367 * month - 362).div_euclid(12)
+        + correction
+        + day as i64
+}
+
+fn gregorian_year_from_fixed(fixed: i64) -> i64 {
+    let d0 = fixed - 1;
+    let n400 = d0.div_euclid(146_097);
+    let d1 = d0.rem_euclid(146_097);
+    let n100 = d1 / 36_524;
+    let d2 = d1 % 36_524;
+    let n4 = d2 / 1_461;
+    let d3 = d2 % 1_461;
+    let n1 = d3 / 365;
+    let year = 400 * n400 + 100 * n100 + 4 * n4 + n1;
+    if n100 == 4 || n1 == 4 {
+        year
+    } else {
+        year + 1
+    }
+}
+
+/// The proleptic Gregorian year, month and day of a fixed day number.
+pub fn gregorian_from_fixed(fixed: i64) -> (i64, usize, usize) {
+    let year = gregorian_year_from_fixed(fixed);
+    let prior_days = fixed - fixed_from_gregorian(year, 1, 1);
+    let correction = if fixed < fixed_from_gregorian(year, 3, 1) {
+        0
+    } else if is_gregorian_leap_year(year) {
+        1
+    } else {
+        2
+    };
+    let month = (12 * (prior_days + correction) + 373).div_euclid(367) as usize;
+    let day = (fixed - fixed_from_gregorian(year, month, 1) + 1) as usize;
+    (year, month, day)
+}
+
+impl CalendarSystem for Gregorian {
+    fn name(&self) -> &'static str {
+        "gregorian"
+    }
+
+    fn date_from_fixed(&self, fixed: i64) -> CalendarDate {
+        let (year, month, day) = gregorian_from_fixed(fixed);
+        if year > 0 {
+            CalendarDate::new(1, year as i32, month, day)
+        } else {
+            CalendarDate::new(0, (1 - year) as i32, month, day)
+        }
+    }
+
+    fn fixed_from_date(&self, date: &CalendarDate) -> i64 {
+        let year = if date.era == 0 {
+            1 - date.year as i64
+        } else {
+            date.year as i64
+        };
+        fixed_from_gregorian(year, date.month, date.day)
+    }
+
+    fn months_in_year(&self, _era: usize, _year: i32) -> usize {
+        12
+    }
+
+    fn days_in_month(&self, era: usize, year: i32, ordinal_month: usize) -> usize {
+        let year = if era == 0 { 1 - year } else { year };
+        days_in_gregorian_month(year as i64, ordinal_month)
+    }
+
+    fn is_leap_year(&self, era: usize, year: i32) -> bool {
+        let year = if era == 0 { 1 - year } else { year };
+        is_gregorian_leap_year(year as i64)
+    }
+
+    fn has_gregorian_months(&self) -> bool {
+        true
+    }
+}
+
+impl Roc {
+    fn gregorian_year(era: usize, year: i32) -> i64 {
+        if era == 0 {
+            ROC_OFFSET + 1 - year as i64
+        } else {
+            year as i64 + ROC_OFFSET
+        }
+    }
+}
+
+impl CalendarSystem for Buddhist {
+    fn name(&self) -> &'static str {
+        "buddhist"
+    }
+
+    fn date_from_fixed(&self, fixed: i64) -> CalendarDate {
+        let (year, month, day) = gregorian_from_fixed(fixed);
+        CalendarDate::new(0, (year + BUDDHIST_OFFSET) as i32, month, day)
+    }
+
+    fn fixed_from_date(&self, date: &CalendarDate) -> i64 {
+        fixed_from_gregorian(date.year as i64 - BUDDHIST_OFFSET, date.month, date.day)
+    }
+
+    fn months_in_year(&self, _era: usize, _year: i32) -> usize {
+        12
+    }
+
+    fn days_in_month(&self, _era: usize, year: i32, ordinal_month: usize) -> usize {
+        days_in_gregorian_month(year as i64 - BUDDHIST_OFFSET, ordinal_month)
+    }
+
+    fn is_leap_year(&self, _era: usize, year: i32) -> bool {
+        is_gregorian_leap_year(year as i64 - BUDDHIST_OFFSET)
+    }
+
+    fn has_gregorian_months(&self) -> bool {
+        true
+    }
+}
+
+impl CalendarSystem for Roc {
+    fn name(&self) -> &'static str {
+        "roc"
+    }
+
+    fn date_from_fixed(&self, fixed: i64) -> CalendarDate {
+        let (year, month, day) = gregorian_from_fixed(fixed);
+        if year > ROC_OFFSET {
+            CalendarDate::new(1, (year - ROC_OFFSET) as i32, month, day)
+        } else {
+            CalendarDate::new(0, (ROC_OFFSET + 1 - year) as i32, month, day)
+        }
+    }
+
+    fn fixed_from_date(&self, date: &CalendarDate) -> i64 {
+        let year = Self::gregorian_year(date.era, date.year);
+        fixed_from_gregorian(year, date.month, date.day)
+    }
+
+    fn months_in_year(&self, _era: usize, _year: i32) -> usize {
+        12
+    }
+
+    fn days_in_month(&self, era: usize, year: i32, ordinal_month: usize) -> usize {
+        days_in_gregorian_month(Self::gregorian_year(era, year), ordinal_month)
+    }
+
+    fn is_leap_year(&self, era: usize, year: i32) -> bool {
+        is_gregorian_leap_year(Self::gregorian_year(era, year))
+    }
+
+    fn has_gregorian_months(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendar::day_of_week;
+
+    #[test]
+    fn test_fixed_round_trip() {
+        assert_eq!(fixed_from_gregorian(1, 1, 1), 1);
+        assert_eq!(fixed_from_gregorian(1945, 11, 12), 710_347);
+        assert_eq!(gregorian_from_fixed(710_347), (1945, 11, 12));
+        assert_eq!(gregorian_from_fixed(0), (0, 12, 31));
+
+        for fixed in -1_000..1_000 {
+            let (year, month, day) = gregorian_from_fixed(fixed * 97);
+            assert_eq!(fixed_from_gregorian(year, month, day), fixed * 97);
+        }
+    }
+
+    #[test]
+    fn test_eras() {
+        let date = Gregorian.date_from_fixed(fixed_from_gregorian(0, 1, 1));
+        assert_eq!(date, CalendarDate::new(0, 1, 1, 1));
+        assert_eq!(
+            Gregorian.fixed_from_date(&date),
+            fixed_from_gregorian(0, 1, 1)
+        );
+        assert_eq!(Gregorian.days_in_month(0, 1, 2), 29);
+        assert_eq!(day_of_week(fixed_from_gregorian(2019, 10, 29)), 2);
+    }
+
+    #[test]
+    fn test_year_offsets() {
+        // Reference values from ICU.
+        let fixed = fixed_from_gregorian(2021, 10, 18);
+        assert_eq!(
+            Buddhist.date_from_fixed(fixed),
+            CalendarDate::new(0, 2564, 10, 18)
+        );
+        assert_eq!(
+            Roc.date_from_fixed(fixed),
+            CalendarDate::new(1, 110, 10, 18)
+        );
+
+        let fixed = fixed_from_gregorian(1912, 1, 1);
+        assert_eq!(Roc.date_from_fixed(fixed), CalendarDate::new(1, 1, 1, 1));
+        assert_eq!(
+            Roc.date_from_fixed(fixed - 1),
+            CalendarDate::new(0, 1, 12, 31)
+        );
+        let fixed = fixed_from_gregorian(-543, 1, 1);
+        assert_eq!(
+            Buddhist.date_from_fixed(fixed),
+            CalendarDate::new(0, 0, 1, 1)
+        );
+
+        assert!(Buddhist.is_leap_year(0, 2567));
+        assert_eq!(Roc.days_in_month(1, 113, 2), 29);
+        for fixed in -1_000..1_000 {
+            let fixed = 700_000 + fixed * 97;
+            for system in &[&Buddhist as &dyn CalendarSystem, &Roc] {
+                let date = system.date_from_fixed(fixed);
+                assert_eq!(system.fixed_from_date(&date), fixed);
+            }
+        }
+    }
+}
@@ -0,0 +1,193 @@
+use super::{CalendarDate, CalendarSystem};
+
+/// The Hebrew calendar, with the era AM (0).
+///
+/// Months are numbered as in CLDR, from Tishri (1) to Elul (13). Adar I (6)
+/// is the leap month and only exists in leap years, where Adar (7) is named
+/// Adar II. Ordinal months count the months of the year actually present.
+pub struct Hebrew;
+
+/// Fixed day number of 1 Tishri 1 AM.
+const HEBREW_EPOCH: i64 = -1_373_427;
+
+const ADAR_I: usize = 6;
+
+fn is_hebrew_leap_year(year: i64) -> bool {
+    (7 * year + 1).rem_euclid(19) < 7
+}
+
+/// Days from the epoch to the molad of Tishri of `year`, postponed if it
+/// falls on Sunday, Wednesday or Friday.
+fn hebrew_elapsed_days(year: i64) -> i64 {
+    let months_elapsed = (235 * year - 234).div_euclid(19);
+    let parts_elapsed = 12_084 + 13_753 * months_elapsed;
+    let days = 29 * months_elapsed + parts_elapsed.div_euclid(25_920);
+    if (3 * (days + 1)).rem_euclid(7) < 3 {
+        days + 1
+    } else {
+        days
+    }
+}
+
+/// Delays that keep the year between 353 and 385 days long.
+fn hebrew_year_length_correction(year: i64) -> i64 {
+    let ny0 = hebrew_elapsed_days(year - 1);
+    let ny1 = hebrew_elapsed_days(year);
+    let ny2 = hebrew_elapsed_days(year + 1);
+    if ny2 - ny1 == 356 {
+        2
+    } else if ny1 - ny0 == 382 {
+        1
+    } else {
+        0
+    }
+}
+
+fn hebrew_new_year(year: i64) -> i64 {
+    HEBREW_EPOCH + hebrew_elapsed_days(year) + hebrew_year_length_correction(year)
+}
+
+fn days_in_hebrew_year(year: i64) -> i64 {
+    hebrew_new_year(year + 1) - hebrew_new_year(year)
+}
+
+/// The CLDR month number of the `ordinal_month`-th month of `year`.
+fn hebrew_month(year: i64, ordinal_month: usize) -> usize {
+    if ordinal_month >= ADAR_I && !is_hebrew_leap_year(year) {
+        ordinal_month + 1
+    } else {
+        ordinal_month
+    }
+}
+
+/// The position in `year` of the month numbered `month` in CLDR. Adar I
+/// counts as Adar in common years.
+fn hebrew_ordinal_month(year: i64, month: usize) -> usize {
+    if month >= ADAR_I && !is_hebrew_leap_year(year) {
+        (month - 1).max(ADAR_I)
+    } else {
+        month
+    }
+}
+
+fn days_in_hebrew_month(year: i64, month: usize) -> usize {
+    match month {
+        // Heshvan is long in complete years, Kislev short in deficient ones.
+        2 if days_in_hebrew_year(year) % 10 == 5 => 30,
+        3 if days_in_hebrew_year(year) % 10 == 3 => 29,
+        2 | 4 | 7 | 9 | 11 | 13 => 29,
+        _ => 30,
+    }
+}
+
+impl CalendarSystem for Hebrew {
+    fn name(&self) -> &'static str {
+        "hebrew"
+    }
+
+    fn date_from_fixed(&self, fixed: i64) -> CalendarDate {
+        let approx = ((fixed - HEBREW_EPOCH) * 98_496).div_euclid(35_975_351) + 1;
+        let mut year = approx - 1;
+        while hebrew_new_year(year + 1) <= fixed {
+            year += 1;
+        }
+
+        let mut day = (fixed - hebrew_new_year(year)) as usize;
+        let mut ordinal_month = 1;
+        loop {
+            let length = days_in_hebrew_month(year, hebrew_month(year, ordinal_month));
+            if day < length {
+                break;
+            }
+            day -= length;
+            ordinal_month += 1;
+        }
+        // Adar I has a name of its own, so it is not marked as a leap month.
+        CalendarDate {
+            ordinal_month,
+            ..CalendarDate::new(0, year as i32, hebrew_month(year, ordinal_month), day + 1)
+        }
+    }
+
+    fn fixed_from_date(&self, date: &CalendarDate) -> i64 {
+        let year = date.year as i64;
+        let prior_days: usize = (1..hebrew_ordinal_month(year, date.month))
+            .map(|ordinal_month| days_in_hebrew_month(year, hebrew_month(year, ordinal_month)))
+            .sum();
+        hebrew_new_year(year) + prior_days as i64 + date.day as i64 - 1
+    }
+
+    fn months_in_year(&self, _era: usize, year: i32) -> usize {
+        if is_hebrew_leap_year(year as i64) {
+            13
+        } else {
+            12
+        }
+    }
+
+    fn days_in_month(&self, _era: usize, year: i32, ordinal_month: usize) -> usize {
+        let year = year as i64;
+        days_in_hebrew_month(year, hebrew_month(year, ordinal_month))
+    }
+
+    fn is_leap_year(&self, _era: usize, year: i32) -> bool {
+        is_hebrew_leap_year(year as i64)
+    }
+
+    fn is_leap_month(&self, _era: usize, year: i32, ordinal_month: usize) -> bool {
+        hebrew_month(year as i64, ordinal_month) == ADAR_I
+    }
+
+    /// Months are written by their position in the year, as in ICU.
+    fn numeric_month(&self, date: &CalendarDate) -> usize {
+        date.ordinal_month
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendar::fixed_from_gregorian;
+
+    fn hebrew(year: i64, month: usize, day: usize) -> CalendarDate {
+        Hebrew.date_from_fixed(fixed_from_gregorian(year, month, day))
+    }
+
+    #[test]
+    fn test_hebrew_from_gregorian() {
+        // Reference values from ICU.
+        assert_eq!(hebrew(1600, 1, 1), CalendarDate::new(0, 5360, 4, 14));
+        assert_eq!(hebrew(1900, 1, 1), CalendarDate::new(0, 5660, 5, 1));
+        assert_eq!(hebrew(1948, 5, 14), CalendarDate::new(0, 5708, 9, 5));
+        assert_eq!(hebrew(2100, 12, 31), CalendarDate::new(0, 5861, 3, 29));
+        assert_eq!(hebrew(2024, 10, 3), CalendarDate::new(0, 5785, 1, 1));
+        assert_eq!(hebrew(2026, 10, 19), CalendarDate::new(0, 5787, 2, 8));
+
+        let adar_i = hebrew(2024, 3, 1);
+        assert_eq!((adar_i.month, adar_i.ordinal_month, adar_i.day), (6, 6, 21));
+        assert!(Hebrew.is_leap_month(0, 5784, 6));
+        let adar_ii = hebrew(2024, 3, 20);
+        assert_eq!(
+            (adar_ii.month, adar_ii.ordinal_month, adar_ii.day),
+            (7, 7, 10)
+        );
+        assert!(!Hebrew.is_leap_month(0, 5784, 7));
+        let adar = hebrew(2023, 3, 1);
+        assert_eq!((adar.month, adar.ordinal_month, adar.day), (7, 6, 8));
+        let nisan = hebrew(2023, 4, 20);
+        assert_eq!((nisan.month, nisan.ordinal_month), (8, 7));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for fixed in -1_000..1_000 {
+            let fixed = 730_000 + fixed * 53;
+            let date = Hebrew.date_from_fixed(fixed);
+            assert_eq!(Hebrew.fixed_from_date(&date), fixed);
+            let year_length: usize = (1..=Hebrew.months_in_year(0, date.year))
+                .map(|month| Hebrew.days_in_month(0, date.year, month))
+                .sum();
+            assert!([353, 354, 355, 383, 384, 385].contains(&year_length));
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use super::{fixed_from_gregorian, gregorian_from_fixed, is_gregorian_leap_year};
+use super::{CalendarDate, CalendarSystem};
+
+/// The Indian national (Saka) calendar, with the era Saka (0).
+///
+/// Years start on March 22, or March 21 in Gregorian leap years, and are
+/// 78 behind the Gregorian year they start in. Leap years are those that
+/// start in Gregorian leap years.
+pub struct Indian;
+
+const SAKA_OFFSET: i64 = 78;
+
+fn is_indian_leap_year(year: i64) -> bool {
+    is_gregorian_leap_year(year + SAKA_OFFSET)
+}
+
+fn indian_new_year(year: i64) -> i64 {
+    let gregorian_year = year + SAKA_OFFSET;
+    let day = if is_gregorian_leap_year(gregorian_year) {
+        21
+    } else {
+        22
+    };
+    fixed_from_gregorian(gregorian_year, 3, day)
+}
+
+fn days_in_indian_month(year: i64, month: usize) -> usize {
+    match month {
+        1 if is_indian_leap_year(year) => 31,
+        2..=6 => 31,
+        _ => 30,
+    }
+}
+
+impl CalendarSystem for Indian {
+    fn name(&self) -> &'static str {
+        "indian"
+    }
+
+    fn date_from_fixed(&self, fixed: i64) -> CalendarDate {
+        let mut year = gregorian_from_fixed(fixed).0 - SAKA_OFFSET;
+        if fixed < indian_new_year(year) {
+            year -= 1;
+        }
+
+        let mut day = (fixed - indian_new_year(year)) as usize;
+        let mut month = 1;
+        while day >= days_in_indian_month(year, month) {
+            day -= days_in_indian_month(year, month);
+            month += 1;
+        }
+        CalendarDate::new(0, year as i32, month, day + 1)
+    }
+
+    fn fixed_from_date(&self, date: &CalendarDate) -> i64 {
+        let year = date.year as i64;
+        let prior_days: usize = (1..date.month)
+            .map(|month| days_in_indian_month(year, month))
+            .sum();
+        indian_new_year(year) + prior_days as i64 + date.day as i64 - 1
+    }
+
+    fn months_in_year(&self, _era: usize, _year: i32) -> usize {
+        12
+    }
+
+    fn days_in_month(&self, _era: usize, year: i32, ordinal_month: usize) -> usize {
+        days_in_indian_month(year as i64, ordinal_month)
+    }
+
+    fn is_leap_year(&self, _era: usize, year: i32) -> bool {
+        is_indian_leap_year(year as i64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn indian(year: i64, month: usize, day: usize) -> CalendarDate {
+        Indian.date_from_fixed(fixed_from_gregorian(year, month, day))
+    }
+
+    #[test]
+    fn test_indian_from_gregorian() {
+        // Reference values from ICU.
+        assert_eq!(indian(2024, 3, 20), CalendarDate::new(0, 1945, 12, 30));
+        assert_eq!(indian(2024, 3, 21), CalendarDate::new(0, 1946, 1, 1));
+        assert_eq!(indian(2023, 3, 21), CalendarDate::new(0, 1944, 12, 30));
+        assert_eq!(indian(2023, 3, 22), CalendarDate::new(0, 1945, 1, 1));
+        assert_eq!(indian(2021, 10, 18), CalendarDate::new(0, 1943, 7, 26));
+        assert_eq!(indian(2024, 9, 11), CalendarDate::new(0, 1946, 6, 20));
+        assert_eq!(indian(100, 1, 1), CalendarDate::new(0, 21, 10, 11));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for fixed in -1_000..1_000 {
+            let fixed = 730_000 + fixed * 53;
+            let date = Indian.date_from_fixed(fixed);
+            assert_eq!(Indian.fixed_from_date(&date), fixed);
+        }
+    }
+}
@@ -0,0 +1,316 @@
+use super::{CalendarDate, CalendarSystem};
+use std::convert::TryFrom;
+
+/// The arithmetic Islamic calendar with the civil (Friday) epoch.
+pub struct IslamicCivil;
+
+/// The arithmetic Islamic calendar with the astronomical (Thursday) epoch,
+/// CLDR's `islamic-tbla`.
+pub struct IslamicTabular;
+
+/// The Umm al-Qura calendar of Saudi Arabia.
+///
+/// Month lengths come from a table covering 1300 to 1600 AH. Dates outside
+/// of it fall back to the civil calendar, as in ICU.
+pub struct UmmAlQura;
+
+/// Fixed day number of 1 Muharram 1 AH in the civil calendar.
+const CIVIL_EPOCH: i64 = 227_015;
+
+/// Fixed day number of 1 Muharram 1 AH in the tabular calendar.
+const TABULAR_EPOCH: i64 = 227_014;
+
+fn is_islamic_leap_year(year: i64) -> bool {
+    (14 + 11 * year).rem_euclid(30) < 11
+}
+
+fn days_in_islamic_month(year: i64, month: usize) -> usize {
+    if month % 2 == 1 || (month == 12 && is_islamic_leap_year(year)) {
+        30
+    } else {
+        29
+    }
+}
+
+fn fixed_from_islamic(epoch: i64, year: i64, month: usize, day: usize) -> i64 {
+    let month = month as i64;
+    epoch - 1
+        + (year - 1) * 354
+        + (3 + 11 * year).div_euclid(30)
+        + 29 * (month - 1)
+        + (6 * month - 1).div_euclid(11)
+        + day as i64
+}
+
+fn islamic_from_fixed(epoch: i64, fixed: i64) -> (i64, usize, usize) {
+    let year = (30 * (fixed - epoch) + 10_646).div_euclid(10_631);
+    let prior_days = fixed - fixed_from_islamic(epoch, year, 1, 1);
+    let month = (11 * prior_days + 330).div_euclid(325) as usize;
+    let day = (fixed - fixed_from_islamic(epoch, year, month, 1) + 1) as usize;
+    (year, month, day)
+}
+
+impl CalendarSystem for IslamicCivil {
+    fn name(&self) -> &'static str {
+        "islamic-civil"
+    }
+
+    fn data_name(&self) -> &'static str {
+        "islamic"
+    }
+
+    fn date_from_fixed(&self, fixed: i64) -> CalendarDate {
+        let (year, month, day) = islamic_from_fixed(CIVIL_EPOCH, fixed);
+        CalendarDate::new(0, year as i32, month, day)
+    }
+
+    fn fixed_from_date(&self, date: &CalendarDate) -> i64 {
+        fixed_from_islamic(CIVIL_EPOCH, date.year as i64, date.month, date.day)
+    }
+
+    fn months_in_year(&self, _era: usize, _year: i32) -> usize {
+        12
+    }
+
+    fn days_in_month(&self, _era: usize, year: i32, ordinal_month: usize) -> usize {
+        days_in_islamic_month(year as i64, ordinal_month)
+    }
+
+    fn is_leap_year(&self, _era: usize, year: i32) -> bool {
+        is_islamic_leap_year(year as i64)
+    }
+}
+
+impl CalendarSystem for IslamicTabular {
+    fn name(&self) -> &'static str {
+        "islamic-tbla"
+    }
+
+    fn data_name(&self) -> &'static str {
+        "islamic"
+    }
+
+    fn date_from_fixed(&self, fixed: i64) -> CalendarDate {
+        let (year, month, day) = islamic_from_fixed(TABULAR_EPOCH, fixed);
+        CalendarDate::new(0, year as i32, month, day)
+    }
+
+    fn fixed_from_date(&self, date: &CalendarDate) -> i64 {
+        fixed_from_islamic(TABULAR_EPOCH, date.year as i64, date.month, date.day)
+    }
+
+    fn months_in_year(&self, _era: usize, _year: i32) -> usize {
+        12
+    }
+
+    fn days_in_month(&self, _era: usize, year: i32, ordinal_month: usize) -> usize {
+        days_in_islamic_month(year as i64, ordinal_month)
+    }
+
+    fn is_leap_year(&self, _era: usize, year: i32) -> bool {
+        is_islamic_leap_year(year as i64)
+    }
+}
+
+const UMALQURA_FIRST_YEAR: i64 = 1300;
+
+/// Fixed day number of 1 Muharram 1300 AH.
+const UMALQURA_EPOCH: i64 = 687_337;
+
+/// A bit per 30-day month of each year from 1300 AH, starting with
+/// Muharram in the lowest bit. Other months have 29 days. The lengths match
+/// ICU's Umm al-Qura data.
+const UMALQURA_MONTHS: [u16; 301] = [
+    0x555, 0x2ab, 0x937, 0x2b6, 0x576, 0x36c, 0xb55, 0xaaa, 0x956, 0x49e, 0x95d, 0x2ba, 0x5b5,
+    0x3aa, 0xb4b, 0xa96, 0x52e, 0x2ad, 0x56d, 0xb5a, 0x752, 0xf25, 0xe8a, 0xd16, 0xa56, 0xab5,
+    0x6b4, 0xda9, 0xb92, 0xb25, 0x64b, 0xa9b, 0x35a, 0x6d9, 0x5d4, 0xda5, 0xd4a, 0xa95, 0x536,
+    0x975, 0x2f4, 0x6e9, 0x6d4, 0x6a9, 0x535, 0x25d, 0x4bd, 0x9ba, 0x3b4, 0xb69, 0xb2a, 0xa55,
+    0x4ad, 0xa5d, 0x2da, 0x6d9, 0xeaa, 0xe94, 0xd2a, 0xc56, 0x4ae, 0xa6d, 0x56a, 0xd55, 0xd4a,
+    0xa93, 0x52b, 0xa5b, 0x53a, 0x6b5, 0xea9, 0xd52, 0xd29, 0xa55, 0x4ad, 0x56d, 0xaea, 0x6e4,
+    0xed1, 0xda2, 0xaaa, 0x95a, 0x2da, 0x5b9, 0xbb2, 0x764, 0x6c9, 0x555, 0x2ab, 0x4db, 0xaba,
+    0x5b4, 0xda9, 0xd52, 0xaa5, 0x92d, 0x26d, 0x8ed, 0x2da, 0xad5, 0xaa5, 0xa4b, 0x497, 0x937,
+    0x2b6, 0x975, 0xd69, 0xd52, 0xc95, 0x92b, 0x25b, 0x4db, 0x9d5, 0x5d2, 0xda5, 0xd4a, 0xa95,
+    0x54d, 0xaad, 0x3aa, 0xbd2, 0xbc4, 0xb89, 0xa95, 0x52d, 0x5ad, 0xb6a, 0x6d4, 0xdc9, 0xd92,
+    0xaa6, 0x956, 0x2ae, 0x56d, 0x36a, 0xb55, 0xaaa, 0x94d, 0x49d, 0x95d, 0x2ba, 0x5b5, 0x5aa,
+    0xd55, 0xa9a, 0x92e, 0x26e, 0x55d, 0xada, 0x6d4, 0x6a5, 0xb27, 0xa4d, 0x4ad, 0x56d, 0xb5a,
+    0x754, 0xf49, 0xe92, 0xd26, 0xa56, 0x356, 0x6b5, 0xbaa, 0xb92, 0xb25, 0x68b, 0xa9b, 0x55a,
+    0xada, 0x5b4, 0xda9, 0xb52, 0xa9a, 0x536, 0x276, 0x575, 0xaf2, 0x6d4, 0x6a9, 0x555, 0x2ad,
+    0x4bd, 0x9ba, 0x574, 0xb69, 0xb52, 0xa95, 0x52d, 0xa5d, 0x4da, 0xad9, 0x6b2, 0xe95, 0xe2a,
+    0xc96, 0x92e, 0xaad, 0x56a, 0xd65, 0xd4a, 0xd15, 0x62b, 0xc5b, 0x53a, 0x6b5, 0xdb2, 0xd64,
+    0xd29, 0xa55, 0x4ad, 0x96d, 0xaea, 0x6e8, 0xed1, 0xda4, 0xd4a, 0xa6a, 0x2da, 0x5b9, 0xb72,
+    0xb68, 0x6d1, 0x655, 0x4ab, 0x95b, 0x2ba, 0x5b5, 0xda9, 0xd52, 0xca6, 0x94e, 0x46e, 0x95d,
+    0x4da, 0xad5, 0xaaa, 0xa4d, 0x49b, 0x937, 0x4b6, 0x975, 0xd6a, 0xd52, 0xaa5, 0x94b, 0x2ab,
+    0x55b, 0xad9, 0x5d2, 0xdc5, 0xd92, 0xb25, 0x555, 0xab5, 0x5b4, 0xba9, 0x7a2, 0x745, 0x593,
+    0xaab, 0x4d6, 0x9d6, 0x5d2, 0xba5, 0xb4a, 0xa95, 0x4ad, 0x15d, 0x2dd, 0x9da, 0x5b4, 0x5a9,
+    0x52d, 0x25b, 0x8b7, 0x176, 0x56d, 0xb6a, 0xaca, 0xa96, 0x52b, 0x15b, 0x2bb, 0x5b6, 0xdaa,
+    0xb94, 0xd46, 0xa8d, 0x52d, 0xa9d, 0x55a, 0x755, 0x749, 0xf13, 0xe4a, 0xa96, 0x556, 0x6b5,
+    0xbaa, 0xb94,
+];
+
+fn umalqura_months(year: i64) -> Option<u16> {
+    let idx = usize::try_from(year - UMALQURA_FIRST_YEAR).ok()?;
+    UMALQURA_MONTHS.get(idx).copied()
+}
+
+fn umalqura_year_length(months: u16) -> i64 {
+    348 + months.count_ones() as i64
+}
+
+fn umalqura_month_length(months: u16, month: usize) -> usize {
+    if months & (1 << (month - 1)) != 0 {
+        30
+    } else {
+        29
+    }
+}
+
+/// The fixed day number of 1 Muharram of `year`, if the table covers it.
+fn umalqura_new_year(year: i64) -> Option<i64> {
+    umalqura_months(year)?;
+    let prior_years = &UMALQURA_MONTHS[..(year - UMALQURA_FIRST_YEAR) as usize];
+    Some(
+        UMALQURA_EPOCH
+            + prior_years
+                .iter()
+                .map(|&months| umalqura_year_length(months))
+                .sum::<i64>(),
+    )
+}
+
+impl CalendarSystem for UmmAlQura {
+    fn name(&self) -> &'static str {
+        "islamic-umalqura"
+    }
+
+    fn data_name(&self) -> &'static str {
+        "islamic"
+    }
+
+    fn date_from_fixed(&self, fixed: i64) -> CalendarDate {
+        let mut new_year = UMALQURA_EPOCH;
+        if fixed >= new_year {
+            for (year, &months) in (UMALQURA_FIRST_YEAR..).zip(UMALQURA_MONTHS.iter()) {
+                let length = umalqura_year_length(months);
+                if fixed < new_year + length {
+                    let mut day = (fixed - new_year) as usize;
+                    let mut month = 1;
+                    while day >= umalqura_month_length(months, month) {
+                        day -= umalqura_month_length(months, month);
+                        month += 1;
+                    }
+                    return CalendarDate::new(0, year as i32, month, day + 1);
+                }
+                new_year += length;
+            }
+        }
+        IslamicCivil.date_from_fixed(fixed)
+    }
+
+    fn fixed_from_date(&self, date: &CalendarDate) -> i64 {
+        let year = date.year as i64;
+        match (umalqura_new_year(year), umalqura_months(year)) {
+            (Some(new_year), Some(months)) => {
+                new_year
+                    + (1..date.month)
+                        .map(|month| umalqura_month_length(months, month) as i64)
+                        .sum::<i64>()
+                    + date.day as i64
+                    - 1
+            }
+            _ => IslamicCivil.fixed_from_date(date),
+        }
+    }
+
+    fn months_in_year(&self, _era: usize, _year: i32) -> usize {
+        12
+    }
+
+    fn days_in_month(&self, era: usize, year: i32, ordinal_month: usize) -> usize {
+        match umalqura_months(year as i64) {
+            Some(months) => umalqura_month_length(months, ordinal_month),
+            None => IslamicCivil.days_in_month(era, year, ordinal_month),
+        }
+    }
+
+    /// Years with 355 days, as in the arithmetic calendars.
+    fn is_leap_year(&self, era: usize, year: i32) -> bool {
+        match umalqura_months(year as i64) {
+            Some(months) => umalqura_year_length(months) == 355,
+            None => IslamicCivil.is_leap_year(era, year),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendar::fixed_from_gregorian;
+
+    fn date(system: &dyn CalendarSystem, year: i64, month: usize, day: usize) -> CalendarDate {
+        system.date_from_fixed(fixed_from_gregorian(year, month, day))
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let civil = &IslamicCivil;
+        assert_eq!(date(civil, 622, 7, 19), CalendarDate::new(0, 1, 1, 1));
+        assert_eq!(date(civil, 622, 7, 16), CalendarDate::new(0, 0, 12, 27));
+        assert_eq!(date(civil, 2021, 10, 18), CalendarDate::new(0, 1443, 3, 11));
+        assert_eq!(
+            date(civil, 2077, 11, 16),
+            CalendarDate::new(0, 1500, 12, 29)
+        );
+        let tabular = &IslamicTabular;
+        assert_eq!(
+            date(tabular, 2021, 10, 18),
+            CalendarDate::new(0, 1443, 3, 12)
+        );
+        assert_eq!(
+            date(tabular, 2077, 11, 16),
+            CalendarDate::new(0, 1501, 1, 1)
+        );
+
+        for fixed in (0..800_000).step_by(997) {
+            for system in &[civil as &dyn CalendarSystem, tabular] {
+                let date = system.date_from_fixed(fixed);
+                assert_eq!(system.fixed_from_date(&date), fixed);
+                assert!(date.day <= system.days_in_month(0, date.year, date.month));
+            }
+        }
+    }
+
+    #[test]
+    fn test_umalqura() {
+        assert_eq!(
+            date(&UmmAlQura, 1882, 11, 12),
+            CalendarDate::new(0, 1300, 1, 1)
+        );
+        assert_eq!(
+            date(&UmmAlQura, 2000, 2, 29),
+            CalendarDate::new(0, 1420, 11, 23)
+        );
+        assert_eq!(
+            date(&UmmAlQura, 2021, 10, 18),
+            CalendarDate::new(0, 1443, 3, 12)
+        );
+        assert_eq!(
+            date(&UmmAlQura, 2077, 11, 16),
+            CalendarDate::new(0, 1500, 12, 30)
+        );
+        // Outside of the table.
+        assert_eq!(
+            date(&UmmAlQura, 1800, 1, 1),
+            CalendarDate::new(0, 1214, 8, 4)
+        );
+        assert_eq!(
+            date(&UmmAlQura, 2200, 1, 1),
+            CalendarDate::new(0, 1626, 11, 14)
+        );
+
+        for fixed in (600_000..800_000).step_by(97) {
+            let date = UmmAlQura.date_from_fixed(fixed);
+            assert_eq!(UmmAlQura.fixed_from_date(&date), fixed);
+            assert!(date.day <= UmmAlQura.days_in_month(0, date.year, date.month));
+        }
+    }
+}
@@ -0,0 +1,345 @@
+use super::gregorian::{
+    fixed_from_gregorian, gregorian_from_fixed, is_gregorian_leap_year, Gregorian,
+};
+use super::{CalendarDate, CalendarSystem};
+
+/// The Japanese imperial calendar: Gregorian months and days, with years
+/// counted from the start of each era.
+///
+/// Every era since Taika (645) is supported. Earlier dates are counted in
+/// Taika, so their years are zero or negative, as in ICU.
+pub struct Japanese;
+
+/// Start dates of the eras, indexed as the CLDR era names.
+///
+/// The dates are CLDR's. Before the calendar reform of 1873 they are dates
+/// of the Japanese lunisolar calendar, which are read here as Gregorian
+/// dates, as in ICU.
+const ERAS: [(i64, usize, usize); 237] = [
+    (645, 6, 19),   // Taika
+    (650, 2, 15),   // Hakuchi
+    (672, 1, 1),    // Hakuhō
+    (686, 7, 20),   // Shuchō
+    (701, 3, 21),   // Taihō
+    (704, 5, 10),   // Keiun
+    (708, 1, 11),   // Wadō
+    (715, 9, 2),    // Reiki
+    (717, 11, 17),  // Yōrō
+    (724, 2, 4),    // Jinki
+    (729, 8, 5),    // Tenpyō
+    (749, 4, 14),   // Tenpyō-kampō
+    (749, 7, 2),    // Tenpyō-shōhō
+    (757, 8, 18),   // Tenpyō-hōji
+    (765, 1, 7),    // Tenpyō-jingo
+    (767, 8, 16),   // Jingo-keiun
+    (770, 10, 1),   // Hōki
+    (781, 1, 1),    // Ten-ō
+    (782, 8, 19),   // Enryaku
+    (806, 5, 18),   // Daidō
+    (810, 9, 19),   // Kōnin
+    (824, 1, 5),    // Tenchō
+    (834, 1, 3),    // Jōwa
+    (848, 6, 13),   // Kajō
+    (851, 4, 28),   // Ninju
+    (854, 11, 30),  // Saikō
+    (857, 2, 21),   // Ten-an
+    (859, 4, 15),   // Jōgan
+    (877, 4, 16),   // Gangyō
+    (885, 2, 21),   // Ninna
+    (889, 4, 27),   // Kanpyō
+    (898, 4, 26),   // Shōtai
+    (901, 7, 15),   // Engi
+    (923, 4, 11),   // Enchō
+    (931, 4, 26),   // Jōhei
+    (938, 5, 22),   // Tengyō
+    (947, 4, 22),   // Tenryaku
+    (957, 10, 27),  // Tentoku
+    (961, 2, 16),   // Ōwa
+    (964, 7, 10),   // Kōhō
+    (968, 8, 13),   // Anna
+    (970, 3, 25),   // Tenroku
+    (973, 12, 20),  // Ten’en
+    (976, 7, 13),   // Jōgen
+    (978, 11, 29),  // Tengen
+    (983, 4, 15),   // Eikan
+    (985, 4, 27),   // Kanna
+    (987, 4, 5),    // Eien
+    (989, 8, 8),    // Eiso
+    (990, 11, 7),   // Shōryaku
+    (995, 2, 22),   // Chōtoku
+    (999, 1, 13),   // Chōhō
+    (1004, 7, 20),  // Kankō
+    (1012, 12, 25), // Chōwa
+    (1017, 4, 23),  // Kannin
+    (1021, 2, 2),   // Jian
+    (1024, 7, 13),  // Manju
+    (1028, 7, 25),  // Chōgen
+    (1037, 4, 21),  // Chōryaku
+    (1040, 11, 10), // Chōkyū
+    (1044, 11, 24), // Kantoku
+    (1046, 4, 14),  // Eishō
+    (1053, 1, 11),  // Tengi
+    (1058, 8, 29),  // Kōhei
+    (1065, 8, 2),   // Jiryaku
+    (1069, 4, 13),  // Enkyū
+    (1074, 8, 23),  // Shōho
+    (1077, 11, 17), // Shōryaku
+    (1081, 2, 10),  // Eihō
+    (1084, 2, 7),   // Ōtoku
+    (1087, 4, 7),   // Kanji
+    (1094, 12, 15), // Kahō
+    (1096, 12, 17), // Eichō
+    (1097, 11, 21), // Jōtoku
+    (1099, 8, 28),  // Kōwa
+    (1104, 2, 10),  // Chōji
+    (1106, 4, 9),   // Kashō
+    (1108, 8, 3),   // Tennin
+    (1110, 7, 13),  // Ten-ei
+    (1113, 7, 13),  // Eikyū
+    (1118, 4, 3),   // Gen’ei
+    (1120, 4, 10),  // Hōan
+    (1124, 4, 3),   // Tenji
+    (1126, 1, 22),  // Daiji
+    (1131, 1, 29),  // Tenshō
+    (1132, 8, 11),  // Chōshō
+    (1135, 4, 27),  // Hōen
+    (1141, 7, 10),  // Eiji
+    (1142, 4, 28),  // Kōji
+    (1144, 2, 23),  // Ten’yō
+    (1145, 7, 22),  // Kyūan
+    (1151, 1, 26),  // Ninpei
+    (1154, 10, 28), // Kyūju
+    (1156, 4, 27),  // Hōgen
+    (1159, 4, 20),  // Heiji
+    (1160, 1, 10),  // Eiryaku
+    (1161, 9, 4),   // Ōho
+    (1163, 3, 29),  // Chōkan
+    (1165, 6, 5),   // Eiman
+    (1166, 8, 27),  // Nin’an
+    (1169, 4, 8),   // Kaō
+    (1171, 4, 21),  // Shōan
+    (1175, 7, 28),  // Angen
+    (1177, 8, 4),   // Jishō
+    (1181, 7, 14),  // Yōwa
+    (1182, 5, 27),  // Juei
+    (1184, 4, 16),  // Genryaku
+    (1185, 8, 14),  // Bunji
+    (1190, 4, 11),  // Kenkyū
+    (1199, 4, 27),  // Shōji
+    (1201, 2, 13),  // Kennin
+    (1204, 2, 20),  // Genkyū
+    (1206, 4, 27),  // Ken’ei
+    (1207, 10, 25), // Jōgen
+    (1211, 3, 9),   // Kenryaku
+    (1213, 12, 6),  // Kenpō
+    (1219, 4, 12),  // Jōkyū
+    (1222, 4, 13),  // Jōō
+    (1224, 11, 20), // Gennin
+    (1225, 4, 20),  // Karoku
+    (1227, 12, 10), // Antei
+    (1229, 3, 5),   // Kanki
+    (1232, 4, 2),   // Jōei
+    (1233, 4, 15),  // Tenpuku
+    (1234, 11, 5),  // Bunryaku
+    (1235, 9, 19),  // Katei
+    (1238, 11, 23), // Ryakunin
+    (1239, 2, 7),   // En’ō
+    (1240, 7, 16),  // Ninji
+    (1243, 2, 26),  // Kangen
+    (1247, 2, 28),  // Hōji
+    (1249, 3, 18),  // Kenchō
+    (1256, 10, 5),  // Kōgen
+    (1257, 3, 14),  // Shōka
+    (1259, 3, 26),  // Shōgen
+    (1260, 4, 13),  // Bun’ō
+    (1261, 2, 20),  // Kōchō
+    (1264, 2, 28),  // Bun’ei
+    (1275, 4, 25),  // Kenji
+    (1278, 2, 29),  // Kōan
+    (1288, 4, 28),  // Shōō
+    (1293, 8, 5),   // Einin
+    (1299, 4, 25),  // Shōan
+    (1302, 11, 21), // Kengen
+    (1303, 8, 5),   // Kagen
+    (1306, 12, 14), // Tokuji
+    (1308, 10, 9),  // Enkyō
+    (1311, 4, 28),  // Ōchō
+    (1312, 3, 20),  // Shōwa
+    (1317, 2, 3),   // Bunpō
+    (1319, 4, 28),  // Genō
+    (1321, 2, 23),  // Genkō
+    (1324, 12, 9),  // Shōchū
+    (1326, 4, 26),  // Karyaku
+    (1329, 8, 29),  // Gentoku
+    (1331, 8, 9),   // Genkō
+    (1334, 1, 29),  // Kenmu
+    (1336, 2, 29),  // Engen
+    (1340, 4, 28),  // Kōkoku
+    (1346, 12, 8),  // Shōhei
+    (1370, 7, 24),  // Kentoku
+    (1372, 4, 1),   // Bunchū
+    (1375, 5, 27),  // Tenju
+    (1379, 3, 22),  // Kōryaku
+    (1381, 2, 10),  // Kōwa
+    (1384, 4, 28),  // Genchū
+    (1387, 8, 22),  // Meitoku
+    (1387, 8, 23),  // Kakei
+    (1389, 2, 9),   // Kōō
+    (1390, 3, 26),  // Meitoku
+    (1394, 7, 5),   // Ōei
+    (1428, 4, 27),  // Shōchō
+    (1429, 9, 5),   // Eikyō
+    (1441, 2, 17),  // Kakitsu
+    (1444, 2, 5),   // Bun’an
+    (1449, 7, 28),  // Hōtoku
+    (1452, 7, 25),  // Kyōtoku
+    (1455, 7, 25),  // Kōshō
+    (1457, 9, 28),  // Chōroku
+    (1460, 12, 21), // Kanshō
+    (1466, 2, 28),  // Bunshō
+    (1467, 3, 3),   // Ōnin
+    (1469, 4, 28),  // Bunmei
+    (1487, 7, 29),  // Chōkyō
+    (1489, 8, 21),  // Entoku
+    (1492, 7, 19),  // Meiō
+    (1501, 2, 29),  // Bunki
+    (1504, 2, 30),  // Eishō
+    (1521, 8, 23),  // Taiei
+    (1528, 8, 20),  // Kyōroku
+    (1532, 7, 29),  // Tenbun
+    (1555, 10, 23), // Kōji
+    (1558, 2, 28),  // Eiroku
+    (1570, 4, 23),  // Genki
+    (1573, 7, 28),  // Tenshō
+    (1592, 12, 8),  // Bunroku
+    (1596, 10, 27), // Keichō
+    (1615, 7, 13),  // Genna
+    (1624, 2, 30),  // Kan’ei
+    (1644, 12, 16), // Shōho
+    (1648, 2, 15),  // Keian
+    (1652, 9, 18),  // Jōō
+    (1655, 4, 13),  // Meireki
+    (1658, 7, 23),  // Manji
+    (1661, 4, 25),  // Kanbun
+    (1673, 9, 21),  // Enpō
+    (1681, 9, 29),  // Tenna
+    (1684, 2, 21),  // Jōkyō
+    (1688, 9, 30),  // Genroku
+    (1704, 3, 13),  // Hōei
+    (1711, 4, 25),  // Shōtoku
+    (1716, 6, 22),  // Kyōhō
+    (1736, 4, 28),  // Genbun
+    (1741, 2, 27),  // Kanpō
+    (1744, 2, 21),  // Enkyō
+    (1748, 7, 12),  // Kan’en
+    (1751, 10, 27), // Hōreki
+    (1764, 6, 2),   // Meiwa
+    (1772, 11, 16), // An’ei
+    (1781, 4, 2),   // Tenmei
+    (1789, 1, 25),  // Kansei
+    (1801, 2, 5),   // Kyōwa
+    (1804, 2, 11),  // Bunka
+    (1818, 4, 22),  // Bunsei
+    (1830, 12, 10), // Tenpō
+    (1844, 12, 2),  // Kōka
+    (1848, 2, 28),  // Kaei
+    (1854, 11, 27), // Ansei
+    (1860, 3, 18),  // Man’en
+    (1861, 2, 19),  // Bunkyū
+    (1864, 2, 20),  // Genji
+    (1865, 4, 7),   // Keiō
+    (1868, 9, 8),   // Meiji
+    (1912, 7, 30),  // Taishō
+    (1926, 12, 25), // Shōwa
+    (1989, 1, 8),   // Heisei
+    (2019, 5, 1),   // Reiwa
+];
+
+/// The Gregorian year in which `era` started. Unknown eras count as Taika,
+/// the first one.
+fn era_start_year(era: usize) -> i64 {
+    ERAS.get(era).map_or(ERAS[0].0, |(year, ..)| *year)
+}
+
+impl CalendarSystem for Japanese {
+    fn name(&self) -> &'static str {
+        "japanese"
+    }
+
+    fn date_from_fixed(&self, fixed: i64) -> CalendarDate {
+        let (year, month, day) = gregorian_from_fixed(fixed);
+        let era = ERAS
+            .partition_point(|&start| start <= (year, month, day))
+            .saturating_sub(1);
+        CalendarDate::new(era, (year - ERAS[era].0 + 1) as i32, month, day)
+    }
+
+    fn fixed_from_date(&self, date: &CalendarDate) -> i64 {
+        let year = date.year as i64 + era_start_year(date.era) - 1;
+        fixed_from_gregorian(year, date.month, date.day)
+    }
+
+    fn months_in_year(&self, _era: usize, _year: i32) -> usize {
+        12
+    }
+
+    fn days_in_month(&self, era: usize, year: i32, ordinal_month: usize) -> usize {
+        let year = year as i64 + era_start_year(era) - 1;
+        Gregorian.days_in_month(1, year as i32, ordinal_month)
+    }
+
+    fn is_leap_year(&self, era: usize, year: i32) -> bool {
+        is_gregorian_leap_year(year as i64 + era_start_year(era) - 1)
+    }
+
+    /// The first year of an era is 元年, "gannen".
+    fn year_name(&self, year: i32, suffix: &str) -> Option<&'static str> {
+        if year == 1 && suffix.starts_with('年') {
+            Some("元")
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_eras() {
+        let date =
+            |year, month, day| Japanese.date_from_fixed(fixed_from_gregorian(year, month, day));
+
+        assert_eq!(date(2021, 10, 18), CalendarDate::new(236, 3, 10, 18));
+        assert_eq!(date(2019, 5, 1), CalendarDate::new(236, 1, 5, 1));
+        assert_eq!(date(2019, 4, 30), CalendarDate::new(235, 31, 4, 30));
+        assert_eq!(date(1989, 1, 7), CalendarDate::new(234, 64, 1, 7));
+        assert_eq!(date(1926, 12, 25), CalendarDate::new(234, 1, 12, 25));
+        assert_eq!(date(1868, 9, 8), CalendarDate::new(232, 1, 9, 8));
+        assert_eq!(date(1867, 1, 1), CalendarDate::new(231, 3, 1, 1));
+        assert_eq!(date(1800, 1, 1), CalendarDate::new(220, 12, 1, 1));
+        assert_eq!(date(645, 6, 19), CalendarDate::new(0, 1, 6, 19));
+        assert_eq!(date(600, 1, 1), CalendarDate::new(0, -44, 1, 1));
+
+        let reiwa = CalendarDate::new(236, 3, 10, 18);
+        assert_eq!(
+            Japanese.fixed_from_date(&reiwa),
+            fixed_from_gregorian(2021, 10, 18)
+        );
+        assert_eq!(Japanese.days_in_month(235, 12, 2), 29);
+
+        let kansei = CalendarDate::new(220, 12, 1, 1);
+        assert_eq!(
+            Japanese.fixed_from_date(&kansei),
+            fixed_from_gregorian(1800, 1, 1)
+        );
+    }
+
+    #[test]
+    fn test_gannen() {
+        assert_eq!(Japanese.year_name(1, "年5月1日"), Some("元"));
+        assert_eq!(Japanese.year_name(1, "/5/1"), None);
+        assert_eq!(Japanese.year_name(2, "年"), None);
+    }
+}
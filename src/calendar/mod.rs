@@ -0,0 +1,218 @@
+//! Calendar systems dates can be formatted in.
+//!
+//! Every calendar converts through fixed day numbers, which count days
+//! from January 1 of year 1 of the proleptic Gregorian calendar, day 1.
+mod chinese;
+mod coptic;
+mod gregorian;
+mod hebrew;
+mod indian;
+mod islamic;
+mod japanese;
+mod persian;
+
+pub use chinese::{Chinese, Dangi};
+pub use coptic::{Coptic, Ethiopic, EthiopicAmeteAlem};
+pub use gregorian::{
+    days_in_gregorian_month, fixed_from_gregorian, gregorian_from_fixed, is_gregorian_leap_year,
+    Buddhist, Gregorian, Roc,
+};
+pub use hebrew::Hebrew;
+pub use indian::Indian;
+pub use islamic::{IslamicCivil, IslamicTabular, UmmAlQura};
+pub use japanese::Japanese;
+pub use persian::Persian;
+
+use crate::DateTime;
+use std::borrow::Cow;
+use std::panic::RefUnwindSafe;
+
+/// A date in a particular calendar system.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct CalendarDate {
+    /// Index of the era in the calendar's era names.
+    pub era: usize,
+    /// The year within `era`.
+    pub year: i32,
+    /// The month number, starting at 1. Month names are looked up by it.
+    pub month: usize,
+    /// The position of the month in the year, starting at 1. It differs
+    /// from `month` in calendars with leap months.
+    pub ordinal_month: usize,
+    /// Whether the month is a leap month that repeats the number of the
+    /// month before it, as in the Chinese calendar. Such months are marked
+    /// with CLDR's leap month patterns.
+    pub leap_month: bool,
+    pub day: usize,
+}
+
+impl CalendarDate {
+    /// Creates a date in a calendar without leap months.
+    pub fn new(era: usize, year: i32, month: usize, day: usize) -> Self {
+        Self {
+            era,
+            year,
+            month,
+            ordinal_month: month,
+            leap_month: false,
+            day,
+        }
+    }
+}
+
+pub trait CalendarSystem: Sync + RefUnwindSafe {
+    /// The CLDR identifier of the calendar, such as `"gregorian"`.
+    fn name(&self) -> &'static str;
+
+    /// The CLDR calendar whose names and patterns this one formats with.
+    /// Variants such as `"islamic-civil"` share the data of `"islamic"`.
+    fn data_name(&self) -> &'static str {
+        self.name()
+    }
+
+    /// Converts a fixed day number into a date of this calendar.
+    fn date_from_fixed(&self, fixed: i64) -> CalendarDate;
+
+    /// Converts a date of this calendar into a fixed day number.
+    fn fixed_from_date(&self, date: &CalendarDate) -> i64;
+
+    /// The number of months in `year` of `era`, leap months included.
+    fn months_in_year(&self, era: usize, year: i32) -> usize;
+
+    /// The number of days in the `ordinal_month`-th month of `year` of `era`.
+    fn days_in_month(&self, era: usize, year: i32, ordinal_month: usize) -> usize;
+
+    /// Whether `year` of `era` is a leap year. Some calendars name their
+    /// months differently in leap years.
+    fn is_leap_year(&self, era: usize, year: i32) -> bool;
+
+    /// Whether the months and days of the calendar are those of the
+    /// Gregorian calendar, so that formatting a year, month or day does not
+    /// need the rest of the date.
+    fn has_gregorian_months(&self) -> bool {
+        false
+    }
+
+    /// Whether the `ordinal_month`-th month of `year` of `era` is a leap month.
+    fn is_leap_month(&self, _era: usize, _year: i32, _ordinal_month: usize) -> bool {
+        false
+    }
+
+    /// The number written for the month of `date` in numeric month fields.
+    fn numeric_month(&self, date: &CalendarDate) -> usize {
+        date.month
+    }
+
+    /// The position of the year of `date` in the 60-year cycle, from 1, in
+    /// calendars that name their years by the cycle.
+    fn cyclic_year(&self, _date: &CalendarDate) -> Option<usize> {
+        None
+    }
+
+    /// The Gregorian year in which the year of `date` starts, written for
+    /// `r` fields.
+    fn related_year(&self, date: &CalendarDate) -> i64 {
+        let new_year = self.fixed_from_date(&CalendarDate::new(date.era, date.year, 1, 1));
+        gregorian_from_fixed(new_year).0
+    }
+
+    /// A word written instead of the number of `year` when the number is
+    /// directly followed by `suffix` in a pattern, such as 元 for the first
+    /// year of a Japanese era.
+    fn year_name(&self, _year: i32, _suffix: &str) -> Option<&'static str> {
+        None
+    }
+
+    /// Converts a date of the proleptic Gregorian calendar.
+    fn date_from_gregorian(&self, date: &DateTime) -> CalendarDate {
+        self.date_from_fixed(fixed_from_gregorian(date.year as i64, date.month, date.day))
+    }
+}
+
+/// Every supported calendar system.
+pub static ALL: &[&dyn CalendarSystem] = &[
+    &Gregorian,
+    &Japanese,
+    &IslamicCivil,
+    &IslamicTabular,
+    &UmmAlQura,
+    &Hebrew,
+    &Chinese,
+    &Dangi,
+    &Persian,
+    &Indian,
+    &Ethiopic,
+    &EthiopicAmeteAlem,
+    &Coptic,
+    &Buddhist,
+    &Roc,
+];
+
+/// Returns the calendar system with the CLDR identifier `name`.
+///
+/// The observational `"islamic"` calendar depends on sightings of the moon
+/// and is approximated by Umm al-Qura, which is computed ahead of time.
+pub fn get(name: &str) -> Option<&'static dyn CalendarSystem> {
+    let name = match name {
+        "islamic" => "islamic-umalqura",
+        name => name,
+    };
+    ALL.iter().copied().find(|system| system.name() == name)
+}
+
+/// Splits the calendar requested by the Unicode extension of a BCP 47
+/// locale, as in `"th-TH-u-ca-buddhist"`, from the locale.
+///
+/// Returns the locale without its Unicode extension, which is how data is
+/// keyed, and the CLDR identifier of the calendar if the extension has a
+/// `ca` key.
+pub fn split_locale(locale: &str) -> (Cow<'_, str>, Option<String>) {
+    let subtags: Vec<&str> = locale.split(['-', '_']).collect();
+    let start = match subtags
+        .iter()
+        .skip(1)
+        .position(|subtag| subtag.eq_ignore_ascii_case("u"))
+    {
+        Some(idx) => idx + 1,
+        None => return (Cow::Borrowed(locale), None),
+    };
+    // The extension runs up to the next singleton, such as `x`.
+    let end = subtags[start + 1..]
+        .iter()
+        .position(|subtag| subtag.len() == 1)
+        .map_or(subtags.len(), |idx| start + 1 + idx);
+
+    let mut calendar = None;
+    let mut idx = start + 1;
+    while idx < end {
+        let key = subtags[idx];
+        let values = subtags[idx + 1..end]
+            .iter()
+            .take_while(|subtag| subtag.len() > 2)
+            .count();
+        if key.eq_ignore_ascii_case("ca") && values > 0 {
+            let value = subtags[idx + 1..idx + 1 + values]
+                .join("-")
+                .to_ascii_lowercase();
+            // BCP 47 abbreviates a few of the CLDR identifiers.
+            calendar = Some(match value.as_str() {
+                "gregory" => "gregorian".to_string(),
+                "ethioaa" => "ethiopic-amete-alem".to_string(),
+                _ => value,
+            });
+        }
+        idx += 1 + values;
+    }
+
+    let rest: Vec<&str> = subtags[..start]
+        .iter()
+        .chain(&subtags[end..])
+        .copied()
+        .collect();
+    (Cow::Owned(rest.join("-")), calendar)
+}
+
+/// The day of the week of a fixed day number, with 0 for Sunday.
+pub fn day_of_week(fixed: i64) -> usize {
+    fixed.rem_euclid(7) as usize
+}
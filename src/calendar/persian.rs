@@ -0,0 +1,110 @@
+use super::{CalendarDate, CalendarSystem};
+
+/// The arithmetic Persian (Solar Hijri) calendar, with the era AP (0).
+///
+/// Leap years follow a 33-year cycle, as in ICU, which matches the
+/// astronomical calendar used in Iran for the foreseeable future.
+pub struct Persian;
+
+/// Fixed day number of 1 Farvardin 1 AP, as ICU counts it.
+const PERSIAN_EPOCH: i64 = 226_895;
+
+fn is_persian_leap_year(year: i64) -> bool {
+    (25 * year + 11).rem_euclid(33) < 8
+}
+
+/// Days from the epoch to the start of `year`.
+fn days_before_year(year: i64) -> i64 {
+    365 * (year - 1) + (8 * year + 21).div_euclid(33)
+}
+
+/// The first six months have 31 days, the next five 30 and Esfand 29, or
+/// 30 in leap years.
+fn days_before_month(month: usize) -> usize {
+    if month <= 7 {
+        31 * (month - 1)
+    } else {
+        30 * (month - 1) + 6
+    }
+}
+
+fn days_in_persian_month(year: i64, month: usize) -> usize {
+    match month {
+        1..=6 => 31,
+        12 if !is_persian_leap_year(year) => 29,
+        _ => 30,
+    }
+}
+
+impl CalendarSystem for Persian {
+    fn name(&self) -> &'static str {
+        "persian"
+    }
+
+    fn date_from_fixed(&self, fixed: i64) -> CalendarDate {
+        let days = fixed - PERSIAN_EPOCH;
+        let year = 1 + (33 * days + 3).div_euclid(12_053);
+        let day_of_year = (days - days_before_year(year)) as usize;
+        let month = if day_of_year < 216 {
+            day_of_year / 31 + 1
+        } else {
+            (day_of_year - 6) / 30 + 1
+        };
+        let day = day_of_year - days_before_month(month) + 1;
+        CalendarDate::new(0, year as i32, month, day)
+    }
+
+    fn fixed_from_date(&self, date: &CalendarDate) -> i64 {
+        PERSIAN_EPOCH
+            + days_before_year(date.year as i64)
+            + days_before_month(date.month) as i64
+            + date.day as i64
+            - 1
+    }
+
+    fn months_in_year(&self, _era: usize, _year: i32) -> usize {
+        12
+    }
+
+    fn days_in_month(&self, _era: usize, year: i32, ordinal_month: usize) -> usize {
+        days_in_persian_month(year as i64, ordinal_month)
+    }
+
+    fn is_leap_year(&self, _era: usize, year: i32) -> bool {
+        is_persian_leap_year(year as i64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendar::fixed_from_gregorian;
+
+    fn persian(year: i64, month: usize, day: usize) -> CalendarDate {
+        Persian.date_from_fixed(fixed_from_gregorian(year, month, day))
+    }
+
+    #[test]
+    fn test_persian_from_gregorian() {
+        // Reference values from ICU.
+        assert_eq!(persian(2024, 3, 19), CalendarDate::new(0, 1402, 12, 29));
+        assert_eq!(persian(2024, 3, 20), CalendarDate::new(0, 1403, 1, 1));
+        assert_eq!(persian(2025, 3, 20), CalendarDate::new(0, 1403, 12, 30));
+        assert_eq!(persian(2021, 10, 18), CalendarDate::new(0, 1400, 7, 26));
+        assert_eq!(persian(2023, 9, 12), CalendarDate::new(0, 1402, 6, 21));
+        assert_eq!(persian(1900, 1, 1), CalendarDate::new(0, 1278, 10, 11));
+        assert_eq!(persian(300, 1, 1), CalendarDate::new(0, -322, 10, 11));
+        assert!(Persian.is_leap_year(0, 1403));
+        assert!(!Persian.is_leap_year(0, 1402));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for fixed in -1_000..1_000 {
+            let fixed = 730_000 + fixed * 53;
+            let date = Persian.date_from_fixed(fixed);
+            assert_eq!(Persian.fixed_from_date(&date), fixed);
+            assert!(date.day <= Persian.days_in_month(0, date.year, date.month));
+        }
+    }
+}
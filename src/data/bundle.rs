@@ -0,0 +1,221 @@
+//! A single-file archive holding the data for many locales.
+//!
+//! The file starts with a `DataHeader` carrying `BUNDLE_MAGIC`, followed by
+//! a little-endian `u64` index length, the bincode-encoded index and the
+//! concatenated locale blobs. The index lists locale names with the offset
+//! and length of each blob, relative to the end of the index, so a single
+//! locale can be read without touching the others.
+use super::error::DataError;
+use super::header::{DataHeader, BUNDLE_MAGIC};
+use super::layout::{CalendarData, LocaleResource, Resource};
+use super::provider::DataProvider;
+use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+use std::io::prelude::*;
+use std::io::SeekFrom;
+use std::sync::Mutex;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct BundleIndex {
+    locales: Vec<BundleEntry>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct BundleEntry {
+    locale: String,
+    offset: u64,
+    length: u64,
+}
+
+impl BundleIndex {
+    fn find(&self, locale: &str) -> Result<&BundleEntry, DataError> {
+        self.locales
+            .binary_search_by(|entry| entry.locale.as_str().cmp(locale))
+            .map(|idx| &self.locales[idx])
+            .map_err(|_| DataError::MissingLocale(locale.to_string()))
+    }
+
+    fn locales(&self) -> impl Iterator<Item = &str> {
+        self.locales.iter().map(|entry| entry.locale.as_str())
+    }
+}
+
+impl BundleEntry {
+    /// The offset of the blob, after checking that a payload of
+    /// `payload_len` bytes holds all of it.
+    fn start(&self, payload_len: u64) -> Result<usize, DataError> {
+        let end = self.offset.checked_add(self.length).ok_or_else(truncated)?;
+        checked_len(end, payload_len)?;
+        Ok(self.offset as usize)
+    }
+}
+
+/// Writes every locale of `resource` into a single bundle.
+pub fn write_bundle<W: Write>(
+    mut writer: W,
+    cldr_version: &str,
+    resource: &Resource,
+) -> Result<(), DataError> {
+    let mut locales: Vec<_> = resource.main.iter().collect();
+    locales.sort_by(|a, b| a.0.cmp(b.0));
+
+    let mut entries = Vec::with_capacity(locales.len());
+    let mut payload = vec![];
+    for (locale, data) in locales {
+        let blob = bincode::serialize(data)?;
+        entries.push(BundleEntry {
+            locale: locale.to_string(),
+            offset: payload.len() as u64,
+            length: blob.len() as u64,
+        });
+        payload.extend_from_slice(&blob);
+    }
+
+    let index = bincode::serialize(&BundleIndex { locales: entries })?;
+
+    DataHeader::new(cldr_version).write(&mut writer, BUNDLE_MAGIC)?;
+    writer.write_all(&(index.len() as u64).to_le_bytes())?;
+    writer.write_all(&index)?;
+    writer.write_all(&payload)?;
+    Ok(())
+}
+
+fn truncated() -> DataError {
+    DataError::Format("bundle is truncated".to_string())
+}
+
+/// Converts `len`, read from the file, to a `usize`, ensuring that `available`
+/// bytes hold it before anything is allocated for it.
+fn checked_len(len: u64, available: u64) -> Result<usize, DataError> {
+    if len > available {
+        return Err(truncated());
+    }
+    usize::try_from(len).map_err(|_| truncated())
+}
+
+fn eof_to_format(err: std::io::Error) -> DataError {
+    if err.kind() == std::io::ErrorKind::UnexpectedEof {
+        truncated()
+    } else {
+        DataError::Io(err)
+    }
+}
+
+/// A bundle held in memory, for example from `include_bytes!` or an mmap.
+///
+/// Locale data is deserialized on request and borrows its strings from the
+/// buffer.
+pub struct Bundle<'a> {
+    header: DataHeader,
+    index: BundleIndex,
+    payload: &'a [u8],
+}
+
+impl<'a> Bundle<'a> {
+    pub fn from_bytes(buffer: &'a [u8]) -> Result<Self, DataError> {
+        let (header, rest) = DataHeader::read_from_bytes(buffer, BUNDLE_MAGIC)?;
+
+        let mut index_len = [0; 8];
+        index_len.copy_from_slice(rest.get(..8).ok_or_else(truncated)?);
+        let rest = &rest[8..];
+        let index_len = checked_len(u64::from_le_bytes(index_len), rest.len() as u64)?;
+
+        Ok(Self {
+            header,
+            index: bincode::deserialize(&rest[..index_len])?,
+            payload: &rest[index_len..],
+        })
+    }
+
+    pub fn cldr_version(&self) -> &str {
+        &self.header.cldr_version
+    }
+
+    pub fn locales(&self) -> impl Iterator<Item = &str> {
+        self.index.locales()
+    }
+
+    pub fn get(&self, locale: &str) -> Result<LocaleResource<'a>, DataError> {
+        let entry = self.index.find(locale)?;
+        let start = entry.start(self.payload.len() as u64)?;
+        let blob = &self.payload[start..start + entry.length as usize];
+        Ok(bincode::deserialize(blob)?)
+    }
+}
+
+impl<'a> DataProvider<'a> for Bundle<'a> {
+    type Calendar = CalendarData<'a>;
+
+    fn get_calendar_data(&self, locale: &str, calendar: &str) -> Result<Self::Calendar, DataError> {
+        self.get(locale)?
+            .into_calendar(calendar)
+            .ok_or_else(|| DataError::MissingCalendar(calendar.to_string()))
+    }
+}
+
+/// A bundle read from a seekable source such as a file.
+///
+/// Only the header and index are read up front. Each request seeks to the
+/// locale's blob and reads just that.
+pub struct BundleReader<R> {
+    header: DataHeader,
+    index: BundleIndex,
+    payload_start: u64,
+    payload_len: u64,
+    reader: Mutex<R>,
+}
+
+impl<R: Read + Seek> BundleReader<R> {
+    pub fn new(mut reader: R) -> Result<Self, DataError> {
+        let header = DataHeader::read(&mut reader, BUNDLE_MAGIC)?;
+
+        let mut index_len = [0; 8];
+        reader.read_exact(&mut index_len).map_err(eof_to_format)?;
+        let position = reader.stream_position()?;
+        let len = reader.seek(SeekFrom::End(0))?;
+        reader.seek(SeekFrom::Start(position))?;
+        let index_len = u64::from_le_bytes(index_len);
+        let available = len.saturating_sub(position);
+
+        let mut index = vec![0; checked_len(index_len, available)?];
+        reader.read_exact(&mut index).map_err(eof_to_format)?;
+        Ok(Self {
+            header,
+            index: bincode::deserialize(&index)?,
+            payload_start: position + index_len,
+            payload_len: available - index_len,
+            reader: Mutex::new(reader),
+        })
+    }
+
+    pub fn cldr_version(&self) -> &str {
+        &self.header.cldr_version
+    }
+
+    pub fn locales(&self) -> impl Iterator<Item = &str> {
+        self.index.locales()
+    }
+
+    pub fn get(&self, locale: &str) -> Result<LocaleResource<'static>, DataError> {
+        let entry = self.index.find(locale)?;
+        entry.start(self.payload_len)?;
+        let mut blob = vec![0; entry.length as usize];
+        {
+            let mut reader = self.reader.lock().unwrap_or_else(|err| err.into_inner());
+            reader.seek(SeekFrom::Start(self.payload_start + entry.offset))?;
+            reader.read_exact(&mut blob).map_err(eof_to_format)?;
+        }
+        let data: LocaleResource = bincode::deserialize(&blob)?;
+        Ok(data.into_owned())
+    }
+}
+
+impl<R: Read + Seek> DataProvider<'static> for BundleReader<R> {
+    type Calendar = CalendarData<'static>;
+
+    fn get_calendar_data(&self, locale: &str, calendar: &str) -> Result<Self::Calendar, DataError> {
+        self.get(locale)?
+            .into_calendar(calendar)
+            .ok_or_else(|| DataError::MissingCalendar(calendar.to_string()))
+    }
+}
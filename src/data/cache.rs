@@ -0,0 +1,119 @@
+use super::error::DataError;
+use super::layout::CalendarData;
+use super::provider::DataProvider;
+use std::collections::HashMap;
+use std::num::NonZeroUsize;
+use std::sync::{Arc, Mutex, PoisonError};
+
+type CacheKey = (String, String);
+
+struct CacheEntry {
+    data: Arc<CalendarData<'static>>,
+    last_used: u64,
+}
+
+struct Cache {
+    entries: HashMap<CacheKey, CacheEntry>,
+    tick: u64,
+}
+
+/// Wraps another provider, loading each locale's calendar data on first use
+/// and keeping up to `capacity` of them in memory.
+///
+/// When the cache is full, the least recently used entry is dropped. Data
+/// is handed out as `Arc`s, so formatters built from this provider are
+/// `Send + Sync` and evicted data stays alive while a formatter uses it.
+pub struct CachingDataProvider<P> {
+    inner: P,
+    capacity: NonZeroUsize,
+    cache: Mutex<Cache>,
+}
+
+impl<P> CachingDataProvider<P> {
+    pub fn new(inner: P, capacity: NonZeroUsize) -> Self {
+        Self {
+            inner,
+            capacity,
+            cache: Mutex::new(Cache {
+                entries: HashMap::new(),
+                tick: 0,
+            }),
+        }
+    }
+
+    pub fn inner(&self) -> &P {
+        &self.inner
+    }
+
+    /// Returns the number of calendars currently held in the cache.
+    pub fn len(&self) -> usize {
+        self.lock().entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn clear(&self) {
+        self.lock().entries.clear();
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Cache> {
+        // The cache is never left half-updated, so a poisoned lock is safe to reuse.
+        self.cache.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<P> DataProvider<'static> for CachingDataProvider<P>
+where
+    P: DataProvider<'static>,
+    P::Calendar: Into<Arc<CalendarData<'static>>>,
+{
+    type Calendar = Arc<CalendarData<'static>>;
+
+    fn get_calendar_data(&self, locale: &str, calendar: &str) -> Result<Self::Calendar, DataError> {
+        let key = (locale.to_string(), calendar.to_string());
+
+        {
+            let mut cache = self.lock();
+            cache.tick += 1;
+            let tick = cache.tick;
+            if let Some(entry) = cache.entries.get_mut(&key) {
+                entry.last_used = tick;
+                return Ok(entry.data.clone());
+            }
+        }
+
+        // Load without holding the lock, so that other locales can be
+        // served in the meantime.
+        let data: Arc<CalendarData<'static>> =
+            self.inner.get_calendar_data(locale, calendar)?.into();
+
+        let mut cache = self.lock();
+        cache.tick += 1;
+        let tick = cache.tick;
+        if let Some(entry) = cache.entries.get_mut(&key) {
+            // Another thread loaded the same data first.
+            entry.last_used = tick;
+            return Ok(entry.data.clone());
+        }
+        if cache.entries.len() >= self.capacity.get() {
+            let oldest = cache
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(key, _)| key.clone());
+            if let Some(oldest) = oldest {
+                cache.entries.remove(&oldest);
+            }
+        }
+        cache.entries.insert(
+            key,
+            CacheEntry {
+                data: data.clone(),
+                last_used: tick,
+            },
+        );
+        Ok(data)
+    }
+}
@@ -0,0 +1,92 @@
+use super::patterns::ParserError;
+use std::fmt;
+
+#[derive(Debug)]
+pub enum DataError {
+    /// Reading the data source failed.
+    Io(std::io::Error),
+    /// The input is not valid JSON or bincode.
+    Format(String),
+    /// The input is well-formed but does not match the data layout.
+    Schema(String),
+    /// The data was written in a format version this crate cannot read.
+    UnsupportedVersion(u32),
+    /// The data was generated for a different data layout and has to be
+    /// regenerated.
+    SchemaMismatch { expected: u64, found: u64 },
+    /// The data source has no data for the requested locale.
+    MissingLocale(String),
+    /// The locale has no data for the requested calendar.
+    MissingCalendar(String),
+    /// The calendar data lacks a pattern or name list, for example because
+    /// it was subset at generation time. Holds the path of the missing data.
+    MissingData(String),
+    /// The pattern at `path` of the calendar data cannot be formatted.
+    InvalidPattern { path: String, error: ParserError },
+    /// Neither a date style nor a time style was requested, leaving nothing
+    /// to format.
+    NoStyle,
+}
+
+impl fmt::Display for DataError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "I/O error: {}", err),
+            Self::Format(msg) => write!(f, "malformed data: {}", msg),
+            Self::Schema(msg) => write!(f, "unexpected data layout: {}", msg),
+            Self::UnsupportedVersion(version) => {
+                write!(f, "unsupported data format version {}", version)
+            }
+            Self::SchemaMismatch { expected, found } => write!(
+                f,
+                "data layout {:016x} does not match {:016x}, regenerate the data",
+                found, expected
+            ),
+            Self::MissingLocale(locale) => write!(f, "no data for locale {}", locale),
+            Self::MissingCalendar(calendar) => write!(f, "no data for calendar {}", calendar),
+            Self::MissingData(path) => write!(f, "missing data: {}", path),
+            Self::InvalidPattern { path, error } => {
+                write!(f, "invalid pattern {}: {}", path, error)
+            }
+            Self::NoStyle => write!(f, "no date or time style requested"),
+        }
+    }
+}
+
+impl std::error::Error for DataError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for DataError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl From<serde_json::Error> for DataError {
+    fn from(err: serde_json::Error) -> Self {
+        use serde_json::error::Category;
+        match err.classify() {
+            Category::Io => Self::Io(err.into()),
+            Category::Syntax | Category::Eof => Self::Format(err.to_string()),
+            Category::Data => Self::Schema(err.to_string()),
+        }
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl From<bincode::Error> for DataError {
+    fn from(err: bincode::Error) -> Self {
+        match *err {
+            bincode::ErrorKind::Io(err) => Self::Io(err),
+            bincode::ErrorKind::Custom(msg) => Self::Schema(msg),
+            err => Self::Format(err.to_string()),
+        }
+    }
+}
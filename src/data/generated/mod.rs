@@ -1,9 +1,10 @@
-use crate::data::layout::Resource;
+use crate::data::layout::LocaleResource;
+mod names;
 pub mod pl;
 
-pub fn get(locale: &str) -> &'static Resource<'static> {
+pub fn get(locale: &str) -> Option<&'static LocaleResource<'static>> {
     match locale {
-        "pl" => &pl::RESOURCE,
-        _ => panic!(),
+        "pl" => Some(&pl::RESOURCE),
+        _ => None,
     }
 }
@@ -0,0 +1,133 @@
+use std::borrow::Cow;
+
+pub static DAYS_0: [Cow<'static, str>; 7] = [
+    Cow::Borrowed("niedz."),
+    Cow::Borrowed("pon."),
+    Cow::Borrowed("wt."),
+    Cow::Borrowed("śr."),
+    Cow::Borrowed("czw."),
+    Cow::Borrowed("pt."),
+    Cow::Borrowed("sob."),
+];
+
+pub static DAYS_1: [Cow<'static, str>; 7] = [
+    Cow::Borrowed("n"),
+    Cow::Borrowed("p"),
+    Cow::Borrowed("w"),
+    Cow::Borrowed("ś"),
+    Cow::Borrowed("c"),
+    Cow::Borrowed("p"),
+    Cow::Borrowed("s"),
+];
+
+pub static DAYS_2: [Cow<'static, str>; 7] = [
+    Cow::Borrowed("nie"),
+    Cow::Borrowed("pon"),
+    Cow::Borrowed("wto"),
+    Cow::Borrowed("śro"),
+    Cow::Borrowed("czw"),
+    Cow::Borrowed("pią"),
+    Cow::Borrowed("sob"),
+];
+
+pub static DAYS_3: [Cow<'static, str>; 7] = [
+    Cow::Borrowed("niedziela"),
+    Cow::Borrowed("poniedziałek"),
+    Cow::Borrowed("wtorek"),
+    Cow::Borrowed("środa"),
+    Cow::Borrowed("czwartek"),
+    Cow::Borrowed("piątek"),
+    Cow::Borrowed("sobota"),
+];
+
+pub static DAYS_4: [Cow<'static, str>; 7] = [
+    Cow::Borrowed("N"),
+    Cow::Borrowed("P"),
+    Cow::Borrowed("W"),
+    Cow::Borrowed("Ś"),
+    Cow::Borrowed("C"),
+    Cow::Borrowed("P"),
+    Cow::Borrowed("S"),
+];
+
+pub static MONTHS_0: [Cow<'static, str>; 12] = [
+    Cow::Borrowed("sty"),
+    Cow::Borrowed("lut"),
+    Cow::Borrowed("mar"),
+    Cow::Borrowed("kwi"),
+    Cow::Borrowed("maj"),
+    Cow::Borrowed("cze"),
+    Cow::Borrowed("lip"),
+    Cow::Borrowed("sie"),
+    Cow::Borrowed("wrz"),
+    Cow::Borrowed("paź"),
+    Cow::Borrowed("lis"),
+    Cow::Borrowed("gru"),
+];
+
+pub static MONTHS_1: [Cow<'static, str>; 12] = [
+    Cow::Borrowed("s"),
+    Cow::Borrowed("l"),
+    Cow::Borrowed("m"),
+    Cow::Borrowed("k"),
+    Cow::Borrowed("m"),
+    Cow::Borrowed("c"),
+    Cow::Borrowed("l"),
+    Cow::Borrowed("s"),
+    Cow::Borrowed("w"),
+    Cow::Borrowed("p"),
+    Cow::Borrowed("l"),
+    Cow::Borrowed("g"),
+];
+
+pub static MONTHS_2: [Cow<'static, str>; 12] = [
+    Cow::Borrowed("stycznia"),
+    Cow::Borrowed("lutego"),
+    Cow::Borrowed("marca"),
+    Cow::Borrowed("kwietnia"),
+    Cow::Borrowed("maja"),
+    Cow::Borrowed("czerwca"),
+    Cow::Borrowed("lipca"),
+    Cow::Borrowed("sierpnia"),
+    Cow::Borrowed("września"),
+    Cow::Borrowed("października"),
+    Cow::Borrowed("listopada"),
+    Cow::Borrowed("grudnia"),
+];
+
+pub static MONTHS_3: [Cow<'static, str>; 12] = [
+    Cow::Borrowed("S"),
+    Cow::Borrowed("L"),
+    Cow::Borrowed("M"),
+    Cow::Borrowed("K"),
+    Cow::Borrowed("M"),
+    Cow::Borrowed("C"),
+    Cow::Borrowed("L"),
+    Cow::Borrowed("S"),
+    Cow::Borrowed("W"),
+    Cow::Borrowed("P"),
+    Cow::Borrowed("L"),
+    Cow::Borrowed("G"),
+];
+
+pub static MONTHS_4: [Cow<'static, str>; 12] = [
+    Cow::Borrowed("styczeń"),
+    Cow::Borrowed("luty"),
+    Cow::Borrowed("marzec"),
+    Cow::Borrowed("kwiecień"),
+    Cow::Borrowed("maj"),
+    Cow::Borrowed("czerwiec"),
+    Cow::Borrowed("lipiec"),
+    Cow::Borrowed("sierpień"),
+    Cow::Borrowed("wrzesień"),
+    Cow::Borrowed("październik"),
+    Cow::Borrowed("listopad"),
+    Cow::Borrowed("grudzień"),
+];
+
+pub static ERAS_0: [Cow<'static, str>; 2] = [Cow::Borrowed("p.n.e."), Cow::Borrowed("n.e.")];
+
+pub static ERAS_1: [Cow<'static, str>; 2] = [
+    Cow::Borrowed("przed naszą erą"),
+    Cow::Borrowed("naszej ery"),
+];
@@ -0,0 +1,155 @@
+//! The header at the start of every binary data file.
+//!
+//! It consists of a four byte magic number identifying the kind of file,
+//! followed by a bincode-encoded `DataHeader`.
+use super::error::DataError;
+use bincode::Options;
+use serde::{Deserialize, Serialize};
+use std::io::prelude::*;
+
+/// Magic number of a single `Resource` file, as written by `generate_res`.
+pub const RESOURCE_MAGIC: [u8; 4] = *b"UDTR";
+/// Magic number of a multi-locale bundle.
+pub const BUNDLE_MAGIC: [u8; 4] = *b"UDTB";
+
+/// Version of the binary framing around the data.
+pub const FORMAT_VERSION: u32 = 1;
+
+/// Hash of `layout.rs`.
+///
+/// Bincode has no field names or type tags, so any change to the types or
+/// to their serde attributes and hand-written `Serialize` and `Deserialize`
+/// impls changes how existing files decode. The hash covers the whole file,
+/// which means data has to be regenerated after editing it. Comments and
+/// whitespace, including line endings, are left out.
+pub const SCHEMA_HASH: u64 = schema_hash(include_str!("layout.rs"));
+
+/// The most bytes an encoded `DataHeader` may take.
+const MAX_HEADER_LEN: u64 = 1024;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DataHeader {
+    pub format_version: u32,
+    pub schema_hash: u64,
+    pub cldr_version: String,
+}
+
+impl DataHeader {
+    /// Creates a header for data generated by this version of the crate.
+    pub fn new<S: Into<String>>(cldr_version: S) -> Self {
+        Self {
+            format_version: FORMAT_VERSION,
+            schema_hash: SCHEMA_HASH,
+            cldr_version: cldr_version.into(),
+        }
+    }
+
+    pub fn write<W: Write>(&self, mut writer: W, magic: [u8; 4]) -> Result<(), DataError> {
+        writer.write_all(&magic)?;
+        bincode::serialize_into(writer, self)?;
+        Ok(())
+    }
+
+    /// Reads and validates a header, leaving `reader` at the start of the data.
+    pub fn read<R: Read>(mut reader: R, magic: [u8; 4]) -> Result<Self, DataError> {
+        let mut found = [0; 4];
+        reader.read_exact(&mut found).map_err(|err| {
+            if err.kind() == std::io::ErrorKind::UnexpectedEof {
+                missing_header(magic)
+            } else {
+                DataError::Io(err)
+            }
+        })?;
+        if found != magic {
+            return Err(missing_header(magic));
+        }
+
+        // The limit keeps a corrupt string length from allocating.
+        let header: Self = bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .allow_trailing_bytes()
+            .with_limit(MAX_HEADER_LEN)
+            .deserialize_from(reader)?;
+        if header.format_version != FORMAT_VERSION {
+            return Err(DataError::UnsupportedVersion(header.format_version));
+        }
+        if header.schema_hash != SCHEMA_HASH {
+            return Err(DataError::SchemaMismatch {
+                expected: SCHEMA_HASH,
+                found: header.schema_hash,
+            });
+        }
+        Ok(header)
+    }
+
+    /// Reads and validates a header at the start of `buffer`, returning the
+    /// header and the data that follows it.
+    pub fn read_from_bytes(buffer: &[u8], magic: [u8; 4]) -> Result<(Self, &[u8]), DataError> {
+        let mut rest = buffer;
+        let header = Self::read(&mut rest, magic).map_err(|err| match err {
+            DataError::Io(err) => DataError::Format(err.to_string()),
+            err => err,
+        })?;
+        Ok((header, rest))
+    }
+}
+
+fn missing_header(magic: [u8; 4]) -> DataError {
+    DataError::Format(format!(
+        "missing {} header",
+        String::from_utf8_lossy(&magic)
+    ))
+}
+
+/// FNV-1a over `source` without comments or whitespace.
+const fn schema_hash(source: &str) -> u64 {
+    let bytes = source.as_bytes();
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    let mut in_comment = false;
+    let mut i = 0;
+    while i < bytes.len() {
+        let byte = bytes[i];
+        if byte == b'\n' {
+            in_comment = false;
+        } else if byte == b'/' && i + 1 < bytes.len() && bytes[i + 1] == b'/' {
+            // Comments end the line, doc comments included.
+            in_comment = true;
+        } else if !in_comment && !byte.is_ascii_whitespace() {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+        i += 1;
+    }
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schema_hash() {
+        let source = "pub struct Formats {\n    pub full: Option<DateTimePattern>,\n}\n";
+        let hash = schema_hash(source);
+        assert_eq!(
+            schema_hash(
+                "/// Formats.\r\npub struct Formats {\r\n\t/// The full one.\r\n\
+                 \tpub full: Option<DateTimePattern>, // FULL\r\n}\r\n\r\n"
+            ),
+            hash
+        );
+        assert_ne!(
+            schema_hash("pub struct Formats {\n    pub full: Option<Cow<str>>,\n}\n"),
+            hash
+        );
+
+        // Impls decide the encoding too.
+        assert_ne!(
+            schema_hash(
+                "pub struct Formats {\n    pub full: Option<DateTimePattern>,\n}\n\
+                 impl Formats {\n    fn f() {}\n}\n"
+            ),
+            hash
+        );
+    }
+}
@@ -1,23 +1,38 @@
+use super::patterns::ParserError;
+use crate::calendar::{fixed_from_gregorian, CalendarSystem};
+use crate::DateTimeInput;
 #[cfg(feature = "serde")]
 use serde::{de, Deserialize, Serialize};
 use std::borrow::Cow;
+#[cfg(feature = "serde")]
+use std::collections::BTreeMap;
 use std::collections::HashMap;
-use std::fmt::Write;
+use std::fmt::{Display, Write};
 
 #[derive(PartialEq, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Resource<'l> {
+    #[cfg_attr(feature = "serde", serde(borrow))]
     pub main: HashMap<Cow<'l, str>, LocaleResource<'l>>,
 }
 
 impl<'l> Resource<'l> {
-    pub fn get<'s>(&self, locale: &'s str) -> Option<&'l LocaleResource> {
-        self.main
-            .iter()
-            .find_map(|(k, v)| if *k == locale { Some(v) } else { None })
+    pub fn get(&self, locale: &str) -> Option<&LocaleResource<'l>> {
+        self.main.get(locale)
+    }
+
+    pub fn into_owned(self) -> Resource<'static> {
+        Resource {
+            main: self
+                .main
+                .into_iter()
+                .map(|(k, v)| (Cow::Owned(k.into_owned()), v.into_owned()))
+                .collect(),
+        }
     }
 }
 
+#[derive(Clone, Copy, PartialEq, Debug)]
 pub enum NamesLength {
     ABBREVIATED,
     NARROW,
@@ -25,9 +40,57 @@ pub enum NamesLength {
     WIDE,
 }
 
+impl NamesLength {
+    pub const ALL: [NamesLength; 4] = [Self::ABBREVIATED, Self::NARROW, Self::SHORT, Self::WIDE];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::ABBREVIATED => "abbreviated",
+            Self::NARROW => "narrow",
+            Self::SHORT => "short",
+            Self::WIDE => "wide",
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum NamesKind {
+    Months,
+    Days,
+    Eras,
+    /// Names of the years of the 60-year cycle.
+    CyclicYears,
+}
+
+impl NamesKind {
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Months => "months",
+            Self::Days => "days",
+            Self::Eras => "eras",
+            Self::CyclicYears => "cyclicNameSets/years",
+        }
+    }
+
+    /// The path of a name list within a calendar, as in CLDR. Eras have no
+    /// stand-alone context, and cyclic names only have a format one.
+    pub fn path(self, stand_alone: bool, length: NamesLength) -> String {
+        match self {
+            Self::Eras => format!("eras/{}", Eras::key(length)),
+            Self::CyclicYears => format!("{}/format/{}", self.name(), length.name()),
+            _ => format!(
+                "{}/{}/{}",
+                self.name(),
+                if stand_alone { "stand-alone" } else { "format" },
+                length.name()
+            ),
+        }
+    }
+}
+
 fn format_number(
     result: &mut impl Write,
-    num: usize,
+    num: impl Display,
     two_digit: bool,
 ) -> Result<(), std::fmt::Error> {
     if two_digit {
@@ -37,68 +100,237 @@ fn format_number(
     }
 }
 
-fn get_day_of_week(year: usize, month: usize, day: usize) -> usize {
-    let t = &[0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
-    let year = if month < 3 { year - 1 } else { year };
-    (year + year / 4 - year / 100 + year / 400 + t[month - 1] + day) % 7
+fn write_name(
+    result: &mut impl Write,
+    names: Option<&[Cow<str>]>,
+    idx: usize,
+) -> Result<(), std::fmt::Error> {
+    let name = names
+        .and_then(|names| names.get(idx))
+        .ok_or(std::fmt::Error)?;
+    result.write_str(name)
 }
 
-impl<'l> Resource<'l> {
-    pub fn format_pattern(
+/// Writes the name of `era`, or its number for eras newer than the data,
+/// such as a Japanese era added after the data was generated.
+fn write_era(
+    result: &mut impl Write,
+    names: Option<&[Cow<str>]>,
+    era: usize,
+) -> Result<(), std::fmt::Error> {
+    let names = names.ok_or(std::fmt::Error)?;
+    match names.get(era) {
+        Some(name) => result.write_str(name),
+        None => format_number(result, era, false),
+    }
+}
+
+/// Writes `month` marked as a leap month with `pattern`, in which `{0}`
+/// stands for the month. Data without such a pattern gets the plain month.
+fn write_leap_month(
+    result: &mut impl Write,
+    pattern: Option<&str>,
+    month: &str,
+) -> Result<(), std::fmt::Error> {
+    let pattern = pattern.and_then(|pattern| Some((pattern, pattern.find("{0}")?)));
+    match pattern {
+        Some((pattern, idx)) => {
+            result.write_str(&pattern[..idx])?;
+            result.write_str(month)?;
+            result.write_str(&pattern[idx + 3..])
+        }
+        None => result.write_str(month),
+    }
+}
+
+/// Writes `offset`, in minutes east of UTC, in the GMT format of the root
+/// locale: `GMT+2`, or `GMT+02:00` when `long`.
+fn write_gmt_offset(
+    result: &mut impl Write,
+    offset: i32,
+    long: bool,
+) -> Result<(), std::fmt::Error> {
+    if offset == 0 {
+        return result.write_str("GMT");
+    }
+    let sign = if offset < 0 { '-' } else { '+' };
+    let (hours, minutes) = (offset.abs() / 60, offset.abs() % 60);
+    if long {
+        write!(result, "GMT{}{:02}:{:02}", sign, hours, minutes)
+    } else if minutes == 0 {
+        write!(result, "GMT{}{}", sign, hours)
+    } else {
+        write!(result, "GMT{}{}:{:02}", sign, hours, minutes)
+    }
+}
+
+impl<'l> CalendarData<'l> {
+    pub fn has_names(&self, kind: NamesKind, stand_alone: bool, length: NamesLength) -> bool {
+        match kind {
+            NamesKind::Months => self.months.get_list(stand_alone, length).is_some(),
+            NamesKind::Days => self.days.get_list(stand_alone, length).is_some(),
+            NamesKind::Eras => self.get_eras(length).is_some(),
+            NamesKind::CyclicYears => self.get_cyclic_years(length).is_some(),
+        }
+    }
+
+    fn get_eras(&self, length: NamesLength) -> Option<&EraList<'l>> {
+        self.eras.as_ref().and_then(|eras| eras.get_list(length))
+    }
+
+    fn get_cyclic_years(&self, length: NamesLength) -> Option<&CyclicNameList<'l>> {
+        let years = self.cyclic_name_sets.as_ref()?.years.as_ref()?;
+        years.format.get_list(length)
+    }
+
+    /// The pattern marking leap months in `length` names, or in numbers for
+    /// `None`.
+    fn get_leap_month_pattern(&self, length: Option<NamesLength>) -> Option<&str> {
+        let patterns = self.month_patterns.as_ref()?;
+        let pattern = match length {
+            Some(length) => patterns.format.as_ref()?.get(length)?,
+            None => patterns.numeric.as_ref()?.all.as_ref()?,
+        };
+        pattern.leap.as_deref()
+    }
+
+    /// Formats `input`, a proleptic Gregorian date, in `calendar`. Patterns
+    /// that fail to parse are reported as `std::fmt::Error`.
+    pub fn format_pattern<I: DateTimeInput + ?Sized>(
         &self,
-        mut result: &mut impl Write,
+        result: &mut impl Write,
         pattern: &DateTimePattern,
-        input: &crate::DateTime,
+        input: &I,
+        calendar: &dyn CalendarSystem,
     ) -> Result<(), std::fmt::Error> {
-        let calendar_data = &self.get("pl").unwrap().dates.calendars.gregorian;
-        for elem in pattern.to_parsed().iter() {
+        let pattern = pattern.to_parsed().map_err(|_| std::fmt::Error)?;
+        self.format_elements(result, &pattern, input, calendar)
+    }
+
+    /// Formats `input` with an already parsed pattern.
+    pub(crate) fn format_elements<I: DateTimeInput + ?Sized>(
+        &self,
+        mut result: &mut impl Write,
+        pattern: &[PatternElement],
+        input: &I,
+        calendar: &dyn CalendarSystem,
+    ) -> Result<(), std::fmt::Error> {
+        let fixed = fixed_from_gregorian(input.year(), input.month(), input.day());
+        let date = calendar.date_from_fixed(fixed);
+        let month_name = |length| {
+            let leap_year = calendar.is_leap_year(date.era, date.year);
+            self.months
+                .get_list(false, length)
+                .and_then(|list| list.get_in_year(date.month - 1, leap_year))
+                .ok_or(std::fmt::Error)
+        };
+        let eras = |length| self.get_eras(length).map(|list| &list.0[..]);
+        for (idx, elem) in pattern.iter().enumerate() {
             match elem {
                 PatternElement::Literal(s) => result.write_str(s.as_ref())?,
                 PatternElement::Token(t) => match t {
-                    DateTimeToken::WeekDayWide => {
-                        let day_name = &calendar_data
-                            .days
+                    DateTimeToken::WeekDayWide => write_name(
+                        &mut result,
+                        self.days
                             .get_list(false, NamesLength::WIDE)
-                            .unwrap()
-                            .get(get_day_of_week(input.year, input.month, input.day));
-                        result.write_str(day_name.as_ref())?
+                            .map(|list| &list.0[..]),
+                        input.weekday(),
+                    )?,
+                    DateTimeToken::DayNumeric => format_number(&mut result, date.day, false)?,
+                    DateTimeToken::Day2digit => format_number(&mut result, date.day, true)?,
+                    DateTimeToken::MonthNumeric | DateTimeToken::Month2digit => {
+                        let two_digit = *t == DateTimeToken::Month2digit;
+                        let month = calendar.numeric_month(&date);
+                        if date.leap_month {
+                            let mut number = String::new();
+                            format_number(&mut number, month, two_digit)?;
+                            let pattern = self.get_leap_month_pattern(None);
+                            write_leap_month(&mut result, pattern, &number)?
+                        } else {
+                            format_number(&mut result, month, two_digit)?
+                        }
                     }
-                    DateTimeToken::DayNumeric => format_number(&mut result, input.day, false)?,
-                    DateTimeToken::Day2digit => format_number(&mut result, input.day, true)?,
-                    DateTimeToken::MonthNumeric => format_number(&mut result, input.month, false)?,
-                    DateTimeToken::Month2digit => format_number(&mut result, input.month, true)?,
-                    DateTimeToken::MonthNameLong => {
-                        let month_name = &calendar_data
-                            .months
-                            .get_list(false, NamesLength::WIDE)
-                            .unwrap()
-                            .get(input.month - 1);
-                        result.write_str(month_name.as_ref())?
+                    DateTimeToken::MonthNameLong | DateTimeToken::MonthNameAbbreviated => {
+                        let length = if *t == DateTimeToken::MonthNameLong {
+                            NamesLength::WIDE
+                        } else {
+                            NamesLength::ABBREVIATED
+                        };
+                        let name = month_name(length)?;
+                        if date.leap_month {
+                            let pattern = self.get_leap_month_pattern(Some(length));
+                            write_leap_month(&mut result, pattern, name)?
+                        } else {
+                            result.write_str(name)?
+                        }
+                    }
+                    DateTimeToken::YearNumeric => {
+                        let suffix = match pattern.get(idx + 1) {
+                            Some(PatternElement::Literal(s)) => s.as_ref(),
+                            _ => "",
+                        };
+                        match calendar.year_name(date.year, suffix) {
+                            Some(name) => result.write_str(name)?,
+                            None => format_number(&mut result, date.year, false)?,
+                        }
+                    }
+                    DateTimeToken::Year2digit => {
+                        format_number(&mut result, date.year.rem_euclid(100), true)?
                     }
-                    DateTimeToken::MonthNameAbbreviated => {
-                        let month_name = &calendar_data
-                            .months
-                            .get_list(false, NamesLength::ABBREVIATED)
-                            .unwrap()
-                            .get(input.month - 1);
-                        result.write_str(month_name.as_ref())?
+                    DateTimeToken::RelatedYear => {
+                        format_number(&mut result, calendar.related_year(&date), false)?
+                    }
+                    DateTimeToken::CyclicYearAbbreviated
+                    | DateTimeToken::CyclicYearWide
+                    | DateTimeToken::CyclicYearNarrow => match calendar.cyclic_year(&date) {
+                        Some(year) => {
+                            let length = t.names().map(|(_, length)| length);
+                            let names = length
+                                .and_then(|length| self.get_cyclic_years(length))
+                                .map(|list| &list.0[..]);
+                            write_name(&mut result, names, year - 1)?
+                        }
+                        // Calendars without cycles write the year number, as
+                        // in ICU.
+                        None => format_number(&mut result, date.year, false)?,
+                    },
+                    DateTimeToken::EraAbbreviated => {
+                        write_era(&mut result, eras(NamesLength::ABBREVIATED), date.era)?
+                    }
+                    DateTimeToken::EraWide => {
+                        write_era(&mut result, eras(NamesLength::WIDE), date.era)?
+                    }
+                    DateTimeToken::EraNarrow => {
+                        write_era(&mut result, eras(NamesLength::NARROW), date.era)?
+                    }
+                    DateTimeToken::Hour2digit => format_number(&mut result, input.hour(), true)?,
+                    DateTimeToken::HourNumeric => format_number(&mut result, input.hour(), false)?,
+                    DateTimeToken::Minute2digit => {
+                        format_number(&mut result, input.minute(), true)?
                     }
-                    DateTimeToken::YearNumeric => format_number(&mut result, input.year, false)?,
-                    DateTimeToken::Year2digit => format_number(&mut result, input.year, true)?,
-                    DateTimeToken::Hour2digit => format_number(&mut result, input.hour, true)?,
-                    DateTimeToken::HourNumeric => format_number(&mut result, input.hour, false)?,
-                    DateTimeToken::Minute2digit => format_number(&mut result, input.minute, true)?,
                     DateTimeToken::MinuteNumeric => {
-                        format_number(&mut result, input.minute, false)?
+                        format_number(&mut result, input.minute(), false)?
+                    }
+                    DateTimeToken::Second2digit => {
+                        format_number(&mut result, input.second(), true)?
                     }
-                    DateTimeToken::Second2digit => format_number(&mut result, input.second, true)?,
                     DateTimeToken::SecondNumeric => {
-                        format_number(&mut result, input.second, false)?
+                        format_number(&mut result, input.second(), false)?
                     }
 
-                    DateTimeToken::ZoneLong => result.write_str("Pacific Daylight Time")?,
-                    DateTimeToken::ZoneShort => result.write_str("PDT")?,
-                    _ => unimplemented!(),
+                    DateTimeToken::ZoneLong | DateTimeToken::ZoneShort => {
+                        let long = *t == DateTimeToken::ZoneLong;
+                        match (input.zone(), input.offset()) {
+                            (Some(zone), _) => result.write_str(zone)?,
+                            (None, Some(offset)) => write_gmt_offset(&mut result, offset, long)?,
+                            // Inputs without a zone keep the names formatting
+                            // has always written.
+                            (None, None) if long => result.write_str("Pacific Daylight Time")?,
+                            (None, None) => result.write_str("PDT")?,
+                        }
+                    }
+                    // Placeholders are only valid in `dateTimeFormats`.
+                    DateTimeToken::Sub0 | DateTimeToken::Sub1 => return Err(std::fmt::Error),
                 },
             }
         }
@@ -106,29 +338,189 @@ impl<'l> Resource<'l> {
     }
 }
 
-#[derive(PartialEq, Debug)]
+#[derive(PartialEq, Debug, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct LocaleResource<'l> {
+    #[cfg_attr(feature = "serde", serde(borrow))]
     pub dates: CalendarDates<'l>,
 }
 
-#[derive(PartialEq, Debug)]
+impl<'l> LocaleResource<'l> {
+    pub fn get_calendar(&self, calendar: &str) -> Option<&CalendarData<'l>> {
+        self.dates.calendars.get(calendar)
+    }
+
+    pub fn into_calendar(self, calendar: &str) -> Option<CalendarData<'l>> {
+        self.dates.calendars.into_calendar(calendar)
+    }
+
+    pub fn into_owned(self) -> LocaleResource<'static> {
+        LocaleResource {
+            dates: self.dates.into_owned(),
+        }
+    }
+}
+
+#[derive(PartialEq, Debug, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CalendarDates<'l> {
+    #[cfg_attr(feature = "serde", serde(borrow))]
     pub calendars: Calendar<'l>,
 }
 
-#[derive(PartialEq, Debug)]
+impl<'l> CalendarDates<'l> {
+    pub fn into_owned(self) -> CalendarDates<'static> {
+        CalendarDates {
+            calendars: self.calendars.into_owned(),
+        }
+    }
+}
+
+/// Data for each calendar, keyed by its CLDR identifier. A resource may
+/// hold any subset of the calendars.
+#[derive(PartialEq, Debug, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Calendar<'l> {
-    pub gregorian: GregorianCalendar<'l>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub gregorian: Option<CalendarData<'l>>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub japanese: Option<CalendarData<'l>>,
+    /// Shared by every variant of the Islamic calendar.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub islamic: Option<CalendarData<'l>>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub hebrew: Option<CalendarData<'l>>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub chinese: Option<CalendarData<'l>>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub dangi: Option<CalendarData<'l>>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub persian: Option<CalendarData<'l>>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub indian: Option<CalendarData<'l>>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub ethiopic: Option<CalendarData<'l>>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub coptic: Option<CalendarData<'l>>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub buddhist: Option<CalendarData<'l>>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub roc: Option<CalendarData<'l>>,
 }
 
-#[derive(PartialEq, Debug)]
+impl<'l> Calendar<'l> {
+    /// CLDR identifiers of the calendars the layout has a place for.
+    pub const NAMES: [&'static str; 12] = [
+        "gregorian",
+        "japanese",
+        "islamic",
+        "hebrew",
+        "chinese",
+        "dangi",
+        "persian",
+        "indian",
+        "ethiopic",
+        "coptic",
+        "buddhist",
+        "roc",
+    ];
+
+    fn fields(&self) -> [&Option<CalendarData<'l>>; 12] {
+        [
+            &self.gregorian,
+            &self.japanese,
+            &self.islamic,
+            &self.hebrew,
+            &self.chinese,
+            &self.dangi,
+            &self.persian,
+            &self.indian,
+            &self.ethiopic,
+            &self.coptic,
+            &self.buddhist,
+            &self.roc,
+        ]
+    }
+
+    fn fields_mut(&mut self) -> [&mut Option<CalendarData<'l>>; 12] {
+        [
+            &mut self.gregorian,
+            &mut self.japanese,
+            &mut self.islamic,
+            &mut self.hebrew,
+            &mut self.chinese,
+            &mut self.dangi,
+            &mut self.persian,
+            &mut self.indian,
+            &mut self.ethiopic,
+            &mut self.coptic,
+            &mut self.buddhist,
+            &mut self.roc,
+        ]
+    }
+
+    pub fn get(&self, calendar: &str) -> Option<&CalendarData<'l>> {
+        let idx = Self::NAMES.iter().position(|name| *name == calendar)?;
+        self.fields()[idx].as_ref()
+    }
+
+    /// Returns the slot for `calendar`, or `None` if the layout has no
+    /// place for it.
+    pub fn get_mut(&mut self, calendar: &str) -> Option<&mut Option<CalendarData<'l>>> {
+        let idx = Self::NAMES.iter().position(|name| *name == calendar)?;
+        IntoIterator::into_iter(self.fields_mut()).nth(idx)
+    }
+
+    pub fn into_calendar(mut self, calendar: &str) -> Option<CalendarData<'l>> {
+        self.get_mut(calendar)?.take()
+    }
+
+    /// Iterates over the calendars present, with their identifiers.
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, &CalendarData<'l>)> {
+        Self::NAMES
+            .iter()
+            .zip(IntoIterator::into_iter(self.fields()))
+            .filter_map(|(name, data)| Some((*name, data.as_ref()?)))
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (&'static str, &mut CalendarData<'l>)> {
+        Self::NAMES
+            .iter()
+            .zip(IntoIterator::into_iter(self.fields_mut()))
+            .filter_map(|(name, data)| Some((*name, data.as_mut()?)))
+    }
+
+    pub fn into_owned(self) -> Calendar<'static> {
+        Calendar {
+            gregorian: self.gregorian.map(CalendarData::into_owned),
+            japanese: self.japanese.map(CalendarData::into_owned),
+            islamic: self.islamic.map(CalendarData::into_owned),
+            hebrew: self.hebrew.map(CalendarData::into_owned),
+            chinese: self.chinese.map(CalendarData::into_owned),
+            dangi: self.dangi.map(CalendarData::into_owned),
+            persian: self.persian.map(CalendarData::into_owned),
+            indian: self.indian.map(CalendarData::into_owned),
+            ethiopic: self.ethiopic.map(CalendarData::into_owned),
+            coptic: self.coptic.map(CalendarData::into_owned),
+            buddhist: self.buddhist.map(CalendarData::into_owned),
+            roc: self.roc.map(CalendarData::into_owned),
+        }
+    }
+}
+
+#[derive(PartialEq, Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct GregorianCalendar<'l> {
+pub struct CalendarData<'l> {
+    #[cfg_attr(feature = "serde", serde(borrow))]
     pub months: Months<'l>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
     pub days: Days<'l>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub eras: Option<Eras<'l>>,
+    #[cfg_attr(feature = "serde", serde(rename = "cyclicNameSets", borrow))]
+    pub cyclic_name_sets: Option<CyclicNameSets<'l>>,
+    #[cfg_attr(feature = "serde", serde(rename = "monthPatterns", borrow))]
+    pub month_patterns: Option<MonthPatterns<'l>>,
     #[cfg_attr(feature = "serde", serde(rename = "dateFormats"))]
     pub date_formats: Formats,
     #[cfg_attr(feature = "serde", serde(rename = "timeFormats"))]
@@ -137,15 +529,57 @@ pub struct GregorianCalendar<'l> {
     pub date_time_formats: Formats,
 }
 
-#[derive(PartialEq, Debug)]
+impl<'l> CalendarData<'l> {
+    pub fn into_owned(self) -> CalendarData<'static> {
+        CalendarData {
+            months: self.months.into_owned(),
+            days: self.days.into_owned(),
+            eras: self.eras.map(Eras::into_owned),
+            cyclic_name_sets: self.cyclic_name_sets.map(CyclicNameSets::into_owned),
+            month_patterns: self.month_patterns.map(MonthPatterns::into_owned),
+            date_formats: self.date_formats,
+            time_formats: self.time_formats,
+            date_time_formats: self.date_time_formats,
+        }
+    }
+}
+
+#[derive(PartialEq, Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Days<'l> {
-    #[cfg_attr(feature = "serde", serde(rename = "stand-alone"))]
+    #[cfg_attr(feature = "serde", serde(rename = "stand-alone", borrow))]
     pub stand_alone: DayTypes<'l>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
     pub format: DayTypes<'l>,
 }
 
 impl<'l> Days<'l> {
+    pub fn into_owned(self) -> Days<'static> {
+        Days {
+            stand_alone: self.stand_alone.into_owned(),
+            format: self.format.into_owned(),
+        }
+    }
+
+    pub fn get_list_mut(
+        &mut self,
+        stand_alone: bool,
+        length: NamesLength,
+    ) -> &mut Option<DayList<'l>> {
+        let list = if stand_alone {
+            &mut self.stand_alone
+        } else {
+            &mut self.format
+        };
+
+        match length {
+            NamesLength::ABBREVIATED => &mut list.abbreviated,
+            NamesLength::NARROW => &mut list.narrow,
+            NamesLength::SHORT => &mut list.short,
+            NamesLength::WIDE => &mut list.wide,
+        }
+    }
+
     pub fn get_list(&self, stand_alone: bool, length: NamesLength) -> Option<&DayList<'l>> {
         let list = if stand_alone {
             &self.stand_alone
@@ -163,15 +597,42 @@ impl<'l> Days<'l> {
     }
 }
 
-#[derive(PartialEq, Debug)]
+#[derive(PartialEq, Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Months<'l> {
-    #[cfg_attr(feature = "serde", serde(rename = "stand-alone"))]
+    #[cfg_attr(feature = "serde", serde(rename = "stand-alone", borrow))]
     pub stand_alone: MonthTypes<'l>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
     pub format: MonthTypes<'l>,
 }
 
 impl<'l> Months<'l> {
+    pub fn into_owned(self) -> Months<'static> {
+        Months {
+            stand_alone: self.stand_alone.into_owned(),
+            format: self.format.into_owned(),
+        }
+    }
+
+    pub fn get_list_mut(
+        &mut self,
+        stand_alone: bool,
+        length: NamesLength,
+    ) -> &mut Option<MonthList<'l>> {
+        let list = if stand_alone {
+            &mut self.stand_alone
+        } else {
+            &mut self.format
+        };
+
+        match length {
+            NamesLength::ABBREVIATED => &mut list.abbreviated,
+            NamesLength::NARROW => &mut list.narrow,
+            NamesLength::SHORT => &mut list.short,
+            NamesLength::WIDE => &mut list.wide,
+        }
+    }
+
     pub fn get_list(&self, stand_alone: bool, length: NamesLength) -> Option<&MonthList<'l>> {
         let list = if stand_alone {
             &self.stand_alone
@@ -189,101 +650,624 @@ impl<'l> Months<'l> {
     }
 }
 
-#[derive(PartialEq, Debug)]
+#[derive(PartialEq, Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DayTypes<'l> {
+    #[cfg_attr(feature = "serde", serde(borrow))]
     pub abbreviated: Option<DayList<'l>>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
     pub narrow: Option<DayList<'l>>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
     pub short: Option<DayList<'l>>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
     pub wide: Option<DayList<'l>>,
 }
 
-#[derive(PartialEq, Debug)]
+impl<'l> DayTypes<'l> {
+    pub fn into_owned(self) -> DayTypes<'static> {
+        DayTypes {
+            abbreviated: self.abbreviated.map(DayList::into_owned),
+            narrow: self.narrow.map(DayList::into_owned),
+            short: self.short.map(DayList::into_owned),
+            wide: self.wide.map(DayList::into_owned),
+        }
+    }
+}
+
+#[derive(PartialEq, Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct MonthTypes<'l> {
+    #[cfg_attr(feature = "serde", serde(borrow))]
     pub abbreviated: Option<MonthList<'l>>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
     pub narrow: Option<MonthList<'l>>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
     pub short: Option<MonthList<'l>>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
     pub wide: Option<MonthList<'l>>,
 }
 
-#[derive(PartialEq, Debug)]
+impl<'l> MonthTypes<'l> {
+    pub fn into_owned(self) -> MonthTypes<'static> {
+        MonthTypes {
+            abbreviated: self.abbreviated.map(MonthList::into_owned),
+            narrow: self.narrow.map(MonthList::into_owned),
+            short: self.short.map(MonthList::into_owned),
+            wide: self.wide.map(MonthList::into_owned),
+        }
+    }
+}
+
+/// Era names by width. CLDR has no short era names and no stand-alone
+/// context for them.
+#[derive(PartialEq, Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct DayList<'l> {
-    pub sun: Cow<'l, str>,
-    pub mon: Cow<'l, str>,
-    pub tue: Cow<'l, str>,
-    pub wed: Cow<'l, str>,
-    pub thu: Cow<'l, str>,
-    pub fri: Cow<'l, str>,
-    pub sat: Cow<'l, str>,
+pub struct Eras<'l> {
+    #[cfg_attr(feature = "serde", serde(rename = "eraAbbr", borrow))]
+    pub abbreviated: Option<EraList<'l>>,
+    #[cfg_attr(feature = "serde", serde(rename = "eraNarrow", borrow))]
+    pub narrow: Option<EraList<'l>>,
+    #[cfg_attr(feature = "serde", serde(rename = "eraNames", borrow))]
+    pub wide: Option<EraList<'l>>,
 }
 
-impl<'l> DayList<'l> {
-    pub fn get(&self, idx: usize) -> &Cow<'l, str> {
-        match idx {
-            0 => &self.sun,
-            1 => &self.mon,
-            2 => &self.tue,
-            3 => &self.wed,
-            4 => &self.thu,
-            5 => &self.fri,
-            6 => &self.sat,
-            _ => panic!(),
+impl<'l> Eras<'l> {
+    /// The CLDR key of the list of `length`.
+    pub fn key(length: NamesLength) -> &'static str {
+        match length {
+            NamesLength::ABBREVIATED => "eraAbbr",
+            NamesLength::NARROW => "eraNarrow",
+            NamesLength::SHORT => "eraShort",
+            NamesLength::WIDE => "eraNames",
+        }
+    }
+
+    pub fn into_owned(self) -> Eras<'static> {
+        Eras {
+            abbreviated: self.abbreviated.map(EraList::into_owned),
+            narrow: self.narrow.map(EraList::into_owned),
+            wide: self.wide.map(EraList::into_owned),
+        }
+    }
+
+    /// Returns `None` for `NamesLength::SHORT`.
+    pub fn get_list_mut(&mut self, length: NamesLength) -> Option<&mut Option<EraList<'l>>> {
+        match length {
+            NamesLength::ABBREVIATED => Some(&mut self.abbreviated),
+            NamesLength::NARROW => Some(&mut self.narrow),
+            NamesLength::SHORT => None,
+            NamesLength::WIDE => Some(&mut self.wide),
+        }
+    }
+
+    pub fn get_list(&self, length: NamesLength) -> Option<&EraList<'l>> {
+        match length {
+            NamesLength::ABBREVIATED => self.abbreviated.as_ref(),
+            NamesLength::NARROW => self.narrow.as_ref(),
+            NamesLength::SHORT => None,
+            NamesLength::WIDE => self.wide.as_ref(),
         }
     }
 }
 
-#[derive(PartialEq, Debug)]
+/// Names in the cycles of sixty of the Chinese family of calendars. Only the
+/// names of years are kept.
+#[derive(PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CyclicNameSets<'l> {
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub years: Option<CyclicNames<'l>>,
+}
+
+impl<'l> CyclicNameSets<'l> {
+    pub fn into_owned(self) -> CyclicNameSets<'static> {
+        CyclicNameSets {
+            years: self.years.map(CyclicNames::into_owned),
+        }
+    }
+}
+
+/// CLDR has cyclic names in the format context only.
+#[derive(PartialEq, Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CyclicNames<'l> {
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub format: CyclicNameTypes<'l>,
+}
+
+impl<'l> CyclicNames<'l> {
+    pub fn into_owned(self) -> CyclicNames<'static> {
+        CyclicNames {
+            format: self.format.into_owned(),
+        }
+    }
+}
+
+#[derive(PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CyclicNameTypes<'l> {
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub abbreviated: Option<CyclicNameList<'l>>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub narrow: Option<CyclicNameList<'l>>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub wide: Option<CyclicNameList<'l>>,
+}
+
+impl<'l> CyclicNameTypes<'l> {
+    pub fn into_owned(self) -> CyclicNameTypes<'static> {
+        CyclicNameTypes {
+            abbreviated: self.abbreviated.map(CyclicNameList::into_owned),
+            narrow: self.narrow.map(CyclicNameList::into_owned),
+            wide: self.wide.map(CyclicNameList::into_owned),
+        }
+    }
+
+    /// Returns `None` for `NamesLength::SHORT`.
+    pub fn get_list_mut(&mut self, length: NamesLength) -> Option<&mut Option<CyclicNameList<'l>>> {
+        match length {
+            NamesLength::ABBREVIATED => Some(&mut self.abbreviated),
+            NamesLength::NARROW => Some(&mut self.narrow),
+            NamesLength::SHORT => None,
+            NamesLength::WIDE => Some(&mut self.wide),
+        }
+    }
+
+    pub fn get_list(&self, length: NamesLength) -> Option<&CyclicNameList<'l>> {
+        match length {
+            NamesLength::ABBREVIATED => self.abbreviated.as_ref(),
+            NamesLength::NARROW => self.narrow.as_ref(),
+            NamesLength::SHORT => None,
+            NamesLength::WIDE => self.wide.as_ref(),
+        }
+    }
+}
+
+/// Patterns that mark leap months, where `{0}` stands for the name or the
+/// number of the month.
+#[derive(PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MonthPatterns<'l> {
+    /// Patterns for month names, by width.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub format: Option<MonthPatternTypes<'l>>,
+    /// Patterns for month numbers.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub numeric: Option<NumericMonthPatterns<'l>>,
+}
+
+impl<'l> MonthPatterns<'l> {
+    pub fn into_owned(self) -> MonthPatterns<'static> {
+        MonthPatterns {
+            format: self.format.map(MonthPatternTypes::into_owned),
+            numeric: self.numeric.map(NumericMonthPatterns::into_owned),
+        }
+    }
+}
+
+#[derive(PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MonthPatternTypes<'l> {
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub abbreviated: Option<MonthPattern<'l>>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub narrow: Option<MonthPattern<'l>>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub wide: Option<MonthPattern<'l>>,
+}
+
+impl<'l> MonthPatternTypes<'l> {
+    pub fn into_owned(self) -> MonthPatternTypes<'static> {
+        MonthPatternTypes {
+            abbreviated: self.abbreviated.map(MonthPattern::into_owned),
+            narrow: self.narrow.map(MonthPattern::into_owned),
+            wide: self.wide.map(MonthPattern::into_owned),
+        }
+    }
+
+    pub fn get(&self, length: NamesLength) -> Option<&MonthPattern<'l>> {
+        match length {
+            NamesLength::ABBREVIATED => self.abbreviated.as_ref(),
+            NamesLength::NARROW => self.narrow.as_ref(),
+            NamesLength::SHORT => None,
+            NamesLength::WIDE => self.wide.as_ref(),
+        }
+    }
+}
+
+#[derive(PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NumericMonthPatterns<'l> {
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub all: Option<MonthPattern<'l>>,
+}
+
+impl<'l> NumericMonthPatterns<'l> {
+    pub fn into_owned(self) -> NumericMonthPatterns<'static> {
+        NumericMonthPatterns {
+            all: self.all.map(MonthPattern::into_owned),
+        }
+    }
+}
+
+#[derive(PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MonthPattern<'l> {
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub leap: Option<Cow<'l, str>>,
+}
+
+impl<'l> MonthPattern<'l> {
+    pub fn into_owned(self) -> MonthPattern<'static> {
+        MonthPattern {
+            leap: self.leap.map(|leap| Cow::Owned(leap.into_owned())),
+        }
+    }
+}
+
+/// Day names, starting with Sunday.
+///
+/// The names are a `Cow` slice so that generated tables can point every
+/// identical list at a single static array.
+#[derive(PartialEq, Debug, Clone)]
+pub struct DayList<'l>(pub Cow<'l, [Cow<'l, str>]>);
+
+impl<'l> DayList<'l> {
+    /// CLDR keys of the names, in list order.
+    pub const KEYS: [&'static str; 7] = ["sun", "mon", "tue", "wed", "thu", "fri", "sat"];
+
+    /// The position of the name keyed `key` in CLDR.
+    pub fn index_of(key: &str) -> Option<usize> {
+        ListKeys::Days.index(key)
+    }
+
+    pub fn into_owned(self) -> DayList<'static> {
+        DayList(names_into_owned(self.0))
+    }
+
+    pub fn get(&self, idx: usize) -> &Cow<'l, str> {
+        &self.0[idx]
+    }
+}
+
+/// Month names, starting with the first month. Calendars differ in the
+/// number of months.
+///
+/// The names are a `Cow` slice so that generated tables can point every
+/// identical list at a single static array.
+#[derive(PartialEq, Debug, Clone)]
 pub struct MonthList<'l> {
-    #[cfg_attr(feature = "serde", serde(rename = "1"))]
-    pub m1: Cow<'l, str>,
-    #[cfg_attr(feature = "serde", serde(rename = "2"))]
-    pub m2: Cow<'l, str>,
-    #[cfg_attr(feature = "serde", serde(rename = "3"))]
-    pub m3: Cow<'l, str>,
-    #[cfg_attr(feature = "serde", serde(rename = "4"))]
-    pub m4: Cow<'l, str>,
-    #[cfg_attr(feature = "serde", serde(rename = "5"))]
-    pub m5: Cow<'l, str>,
-    #[cfg_attr(feature = "serde", serde(rename = "6"))]
-    pub m6: Cow<'l, str>,
-    #[cfg_attr(feature = "serde", serde(rename = "7"))]
-    pub m7: Cow<'l, str>,
-    #[cfg_attr(feature = "serde", serde(rename = "8"))]
-    pub m8: Cow<'l, str>,
-    #[cfg_attr(feature = "serde", serde(rename = "9"))]
-    pub m9: Cow<'l, str>,
-    #[cfg_attr(feature = "serde", serde(rename = "10"))]
-    pub m10: Cow<'l, str>,
-    #[cfg_attr(feature = "serde", serde(rename = "11"))]
-    pub m11: Cow<'l, str>,
-    #[cfg_attr(feature = "serde", serde(rename = "12"))]
-    pub m12: Cow<'l, str>,
+    pub names: Cow<'l, [Cow<'l, str>]>,
+    /// Names used instead in leap years, with the index of the name they
+    /// replace. CLDR keys them like `"7-yeartype-leap"`, for Adar II.
+    pub leap_names: Cow<'l, [(usize, Cow<'l, str>)]>,
 }
 
 impl<'l> MonthList<'l> {
+    pub const fn new(names: Cow<'l, [Cow<'l, str>]>) -> Self {
+        Self {
+            names,
+            leap_names: Cow::Borrowed(&[]),
+        }
+    }
+
+    /// The position of the name keyed `key` in CLDR.
+    pub fn index_of(key: &str) -> Option<usize> {
+        ListKeys::Months.index(key)
+    }
+
+    /// The position of the name that the leap year name keyed `key` in
+    /// CLDR replaces, such as 6 for `"7-yeartype-leap"`.
+    pub fn leap_index_of(key: &str) -> Option<usize> {
+        ListKeys::Months.leap_index(key)
+    }
+
+    pub fn into_owned(self) -> MonthList<'static> {
+        MonthList {
+            names: names_into_owned(self.names),
+            leap_names: Cow::Owned(
+                self.leap_names
+                    .iter()
+                    .map(|(idx, name)| (*idx, Cow::Owned(name.to_string())))
+                    .collect(),
+            ),
+        }
+    }
+
     pub fn get(&self, idx: usize) -> &Cow<'l, str> {
-        match idx {
-            0 => &self.m1,
-            1 => &self.m2,
-            2 => &self.m3,
-            3 => &self.m4,
-            4 => &self.m5,
-            5 => &self.m6,
-            6 => &self.m7,
-            7 => &self.m8,
-            8 => &self.m9,
-            9 => &self.m10,
-            10 => &self.m11,
-            11 => &self.m12,
-            _ => panic!(),
+        &self.names[idx]
+    }
+
+    /// The name at `idx`, taking the leap year variant if there is one.
+    pub fn get_in_year(&self, idx: usize, leap_year: bool) -> Option<&Cow<'l, str>> {
+        let leap_name = self
+            .leap_names
+            .iter()
+            .find(|(leap_idx, _)| leap_year && *leap_idx == idx);
+        match leap_name {
+            Some((_, name)) => Some(name),
+            None => self.names.get(idx),
         }
     }
 }
 
-#[derive(PartialEq, Debug)]
+/// Era names, starting with era 0.
+#[derive(PartialEq, Debug, Clone)]
+pub struct EraList<'l>(pub Cow<'l, [Cow<'l, str>]>);
+
+impl<'l> EraList<'l> {
+    /// The position of the name keyed `key` in CLDR.
+    pub fn index_of(key: &str) -> Option<usize> {
+        ListKeys::Eras.index(key)
+    }
+
+    pub fn into_owned(self) -> EraList<'static> {
+        EraList(names_into_owned(self.0))
+    }
+
+    pub fn get(&self, idx: usize) -> &Cow<'l, str> {
+        &self.0[idx]
+    }
+}
+
+/// Suffix of the CLDR keys of month names used in leap years.
+const LEAP_SUFFIX: &str = "-yeartype-leap";
+
+/// Names of the years of the 60-year cycle, starting with the first year.
+#[derive(PartialEq, Debug, Clone)]
+pub struct CyclicNameList<'l>(pub Cow<'l, [Cow<'l, str>]>);
+
+impl<'l> CyclicNameList<'l> {
+    /// The position of the name keyed `key` in CLDR.
+    pub fn index_of(key: &str) -> Option<usize> {
+        ListKeys::Cyclic.index(key)
+    }
+
+    pub fn into_owned(self) -> CyclicNameList<'static> {
+        CyclicNameList(names_into_owned(self.0))
+    }
+
+    pub fn get(&self, idx: usize) -> &Cow<'l, str> {
+        &self.0[idx]
+    }
+}
+
+/// How CLDR keys the entries of a name list.
+#[derive(Clone, Copy)]
+enum ListKeys {
+    /// `"sun"` to `"sat"`.
+    Days,
+    /// Consecutive numbers from `"1"`.
+    Months,
+    /// Consecutive numbers from `"0"`.
+    Eras,
+    /// Consecutive numbers from `"1"`.
+    Cyclic,
+}
+
+impl ListKeys {
+    fn first(self) -> usize {
+        match self {
+            Self::Months | Self::Cyclic => 1,
+            _ => 0,
+        }
+    }
+
+    /// Variants such as `"0-alt-variant"` have no position and are skipped.
+    fn index(self, key: &str) -> Option<usize> {
+        match self {
+            Self::Days => DayList::KEYS.iter().position(|k| *k == key),
+            _ if key.bytes().all(|b| b.is_ascii_digit()) => {
+                key.parse::<usize>().ok()?.checked_sub(self.first())
+            }
+            _ => None,
+        }
+    }
+
+    /// Leap year variants such as `"7-yeartype-leap"` only exist for months.
+    fn leap_index(self, key: &str) -> Option<usize> {
+        match self {
+            Self::Months => self.index(key.strip_suffix(LEAP_SUFFIX)?),
+            _ => None,
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    fn has_leap_names(self) -> bool {
+        matches!(self, Self::Months)
+    }
+
+    #[cfg(feature = "serde")]
+    fn key(self, idx: usize) -> String {
+        match self {
+            Self::Days => DayList::KEYS[idx].to_string(),
+            _ => (idx + self.first()).to_string(),
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    fn len(self) -> Option<usize> {
+        match self {
+            Self::Days => Some(DayList::KEYS.len()),
+            _ => None,
+        }
+    }
+}
+
+fn names_into_owned(names: Cow<[Cow<str>]>) -> Cow<'static, [Cow<'static, str>]> {
+    Cow::Owned(
+        names
+            .iter()
+            .map(|name| Cow::Owned(name.to_string()))
+            .collect(),
+    )
+}
+
+/// Name lists are maps keyed as in CLDR in human-readable formats and plain
+/// sequences in binary ones. Month lists are a pair of sequences in binary
+/// formats, the names and the leap year names.
+#[cfg(feature = "serde")]
+fn serialize_names<S: serde::Serializer>(
+    names: &[Cow<str>],
+    leap_names: &[(usize, Cow<str>)],
+    keys: ListKeys,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    use serde::ser::SerializeMap;
+
+    if serializer.is_human_readable() {
+        let mut map = serializer.serialize_map(Some(names.len() + leap_names.len()))?;
+        for (idx, name) in names.iter().enumerate() {
+            map.serialize_entry(&keys.key(idx), name)?;
+        }
+        for (idx, name) in leap_names {
+            map.serialize_entry(&format!("{}{}", keys.key(*idx), LEAP_SUFFIX), name)?;
+        }
+        map.end()
+    } else if keys.has_leap_names() {
+        (names, leap_names).serialize(serializer)
+    } else {
+        serializer.collect_seq(names)
+    }
+}
+
+/// Borrows the name from the input whenever the format allows it.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct Name<'a>(#[serde(borrow)] Cow<'a, str>);
+
+/// Names and leap year names, with the index of the name they replace.
+#[cfg(feature = "serde")]
+type Names<'a> = (Vec<Cow<'a, str>>, Vec<(usize, Cow<'a, str>)>);
+
+#[cfg(feature = "serde")]
+fn deserialize_names<'de, D: de::Deserializer<'de>>(
+    deserializer: D,
+    keys: ListKeys,
+) -> Result<Names<'de>, D::Error> {
+    struct NamesVisitor(ListKeys);
+    impl<'de> de::Visitor<'de> for NamesVisitor {
+        type Value = Names<'de>;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+            match self.0.len() {
+                Some(len) => write!(formatter, "a list of {} names", len),
+                None => write!(formatter, "a list of names"),
+            }
+        }
+
+        fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            if self.0.has_leap_names() {
+                let names: Vec<Name> = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                let leap_names: Vec<(usize, Name)> = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+                return Ok((
+                    names.into_iter().map(|Name(name)| name).collect(),
+                    leap_names
+                        .into_iter()
+                        .map(|(idx, Name(name))| (idx, name))
+                        .collect(),
+                ));
+            }
+
+            let mut names = vec![];
+            while let Some(Name(name)) = seq.next_element()? {
+                names.push(name);
+            }
+            match self.0.len() {
+                Some(len) if len != names.len() => {
+                    Err(de::Error::invalid_length(names.len(), &self))
+                }
+                _ => Ok((names, vec![])),
+            }
+        }
+
+        fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+            let mut names = BTreeMap::new();
+            let mut leap_names = vec![];
+            while let Some(Name(key)) = map.next_key()? {
+                if let Some(idx) = self.0.index(&key) {
+                    names.insert(idx, map.next_value::<Name>()?.0);
+                } else if let Some(idx) = self.0.leap_index(&key) {
+                    leap_names.push((idx, map.next_value::<Name>()?.0));
+                } else {
+                    map.next_value::<de::IgnoredAny>()?;
+                }
+            }
+            leap_names.sort_by_key(|(idx, _)| *idx);
+            // A leap year name needs the name it replaces.
+            let len = self.0.len().unwrap_or_else(|| {
+                let last = names
+                    .keys()
+                    .next_back()
+                    .max(leap_names.last().map(|(idx, _)| idx));
+                last.map_or(0, |idx| idx + 1)
+            });
+            let names = (0..len)
+                .map(|idx| {
+                    names.remove(&idx).ok_or_else(|| {
+                        de::Error::custom(format_args!("missing name {:?}", self.0.key(idx)))
+                    })
+                })
+                .collect::<Result<_, _>>()?;
+            Ok((names, leap_names))
+        }
+    }
+
+    let visitor = NamesVisitor(keys);
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_map(visitor)
+    } else if keys.has_leap_names() {
+        deserializer.deserialize_tuple(2, visitor)
+    } else {
+        deserializer.deserialize_seq(visitor)
+    }
+}
+
+macro_rules! name_list_serde {
+    ($list:ident, $keys:expr) => {
+        #[cfg(feature = "serde")]
+        impl<'l> Serialize for $list<'l> {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serialize_names(&self.0, &[], $keys, serializer)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de: 'l, 'l> Deserialize<'de> for $list<'l> {
+            fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                deserialize_names(deserializer, $keys).map(|(names, _)| $list(Cow::Owned(names)))
+            }
+        }
+    };
+}
+
+name_list_serde!(DayList, ListKeys::Days);
+name_list_serde!(EraList, ListKeys::Eras);
+name_list_serde!(CyclicNameList, ListKeys::Cyclic);
+
+#[cfg(feature = "serde")]
+impl<'l> Serialize for MonthList<'l> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_names(&self.names, &self.leap_names, ListKeys::Months, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de: 'l, 'l> Deserialize<'de> for MonthList<'l> {
+    fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (names, leap_names) = deserialize_names(deserializer, ListKeys::Months)?;
+        Ok(MonthList {
+            names: Cow::Owned(names),
+            leap_names: Cow::Owned(leap_names),
+        })
+    }
+}
+
+#[derive(PartialEq, Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Formats {
     pub full: Option<DateTimePattern>,
@@ -293,13 +1277,15 @@ pub struct Formats {
 }
 
 impl Formats {
+    pub const NAMES: [&'static str; 4] = ["full", "long", "medium", "short"];
+
     pub fn get(&self, idx: usize) -> Option<&DateTimePattern> {
         let pattern = match idx {
             0 => &self.full,
             1 => &self.long,
             2 => &self.medium,
             3 => &self.short,
-            _ => panic!(),
+            _ => return None,
         };
         pattern.as_ref()
     }
@@ -314,16 +1300,14 @@ pub enum DateTimePattern {
 }
 
 impl DateTimePattern {
-    pub fn to_parsed(&self) -> Vec<PatternElement> {
+    pub fn to_parsed(&self) -> Result<Vec<PatternElement>, ParserError> {
         match *self {
-            DateTimePattern::Raw(ref s) => super::patterns::parse_pattern(&s.as_ref()).unwrap(),
-            DateTimePattern::Parsed(ref elements) => elements.to_vec(),
+            DateTimePattern::Raw(ref s) => super::patterns::parse_pattern(s.as_ref()),
+            DateTimePattern::Parsed(ref elements) => Ok(elements.to_vec()),
         }
     }
 }
 
-pub static mut IN_JSON: bool = false;
-
 #[cfg(feature = "serde")]
 impl<'de> de::Deserialize<'de> for DateTimePattern {
     fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
@@ -348,7 +1332,9 @@ impl<'de> de::Deserialize<'de> for DateTimePattern {
             }
         }
 
-        if unsafe { IN_JSON } {
+        // JSON holds raw pattern strings, which only self-describing formats
+        // can tell apart from parsed ones.
+        if deserializer.is_human_readable() {
             deserializer.deserialize_any(MyVisitor)
         } else {
             deserializer.deserialize_seq(MyVisitor)
@@ -366,15 +1352,22 @@ pub enum PatternElement {
 #[derive(PartialEq, Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum DateTimeToken {
-    WeekDayWide,          // EEEE
-    DayNumeric,           // d
-    Day2digit,            // dd
-    MonthNameLong,        // MMMM
-    MonthNameAbbreviated, // MMM
-    Month2digit,          // MM
-    MonthNumeric,         // M
-    YearNumeric,          // y
-    Year2digit,           // yy
+    WeekDayWide,           // EEEE
+    DayNumeric,            // d
+    Day2digit,             // dd
+    MonthNameLong,         // MMMM
+    MonthNameAbbreviated,  // MMM
+    Month2digit,           // MM
+    MonthNumeric,          // M
+    YearNumeric,           // y
+    Year2digit,            // yy
+    EraAbbreviated,        // G, GG, GGG
+    EraWide,               // GGGG
+    EraNarrow,             // GGGGG
+    RelatedYear,           // r
+    CyclicYearAbbreviated, // U, UU, UUU
+    CyclicYearWide,        // UUUU
+    CyclicYearNarrow,      // UUUUU
 
     Hour2digit,    // HH
     HourNumeric,   // H
@@ -391,6 +1384,22 @@ pub enum DateTimeToken {
 }
 
 impl DateTimeToken {
+    /// Returns the list of names this token is formatted with, if any.
+    pub fn names(&self) -> Option<(NamesKind, NamesLength)> {
+        match self {
+            Self::WeekDayWide => Some((NamesKind::Days, NamesLength::WIDE)),
+            Self::MonthNameLong => Some((NamesKind::Months, NamesLength::WIDE)),
+            Self::MonthNameAbbreviated => Some((NamesKind::Months, NamesLength::ABBREVIATED)),
+            Self::EraAbbreviated => Some((NamesKind::Eras, NamesLength::ABBREVIATED)),
+            Self::EraWide => Some((NamesKind::Eras, NamesLength::WIDE)),
+            Self::EraNarrow => Some((NamesKind::Eras, NamesLength::NARROW)),
+            Self::CyclicYearAbbreviated => Some((NamesKind::CyclicYears, NamesLength::ABBREVIATED)),
+            Self::CyclicYearWide => Some((NamesKind::CyclicYears, NamesLength::WIDE)),
+            Self::CyclicYearNarrow => Some((NamesKind::CyclicYears, NamesLength::NARROW)),
+            _ => None,
+        }
+    }
+
     pub fn get_name(&self) -> &'static str {
         match self {
             Self::WeekDayWide => "WeekDayWide",
@@ -402,6 +1411,13 @@ impl DateTimeToken {
             Self::MonthNumeric => "MonthNumeric",
             Self::YearNumeric => "YearNumeric",
             Self::Year2digit => "Year2digit",
+            Self::EraAbbreviated => "EraAbbreviated",
+            Self::EraWide => "EraWide",
+            Self::EraNarrow => "EraNarrow",
+            Self::RelatedYear => "RelatedYear",
+            Self::CyclicYearAbbreviated => "CyclicYearAbbreviated",
+            Self::CyclicYearWide => "CyclicYearWide",
+            Self::CyclicYearNarrow => "CyclicYearNarrow",
             Self::Hour2digit => "Hour2digit",
             Self::HourNumeric => "HourNumeric",
             Self::Minute2digit => "Minute2digit",
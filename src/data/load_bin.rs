@@ -1,13 +1,70 @@
+use super::error::DataError;
+use super::header::{DataHeader, RESOURCE_MAGIC};
 use super::layout;
+use super::provider::{take_calendar_data, DataProvider};
 use bincode;
 use std::fs::File;
 use std::io::prelude::*;
 
-pub fn get_calendar_data<'l, 'a>(path: &'l str, locale: &'l str) -> layout::Resource<'a> {
-    let mut fh = File::open(format!("{}/{}.dat", path, locale)).expect("Opening file failed");
+/// Writes `resource` with a header, in the format read by this module.
+pub fn write_calendar_data<W: Write>(
+    mut writer: W,
+    cldr_version: &str,
+    resource: &layout::Resource,
+) -> Result<(), DataError> {
+    DataHeader::new(cldr_version).write(&mut writer, RESOURCE_MAGIC)?;
+    bincode::serialize_into(writer, resource)?;
+    Ok(())
+}
+
+/// Reads the header of a data file, which carries its CLDR version.
+pub fn get_header(buffer: &[u8]) -> Result<DataHeader, DataError> {
+    DataHeader::read_from_bytes(buffer, RESOURCE_MAGIC).map(|(header, _)| header)
+}
 
+/// Deserializes calendar data from a bincode buffer without copying it.
+///
+/// All month and day names in the returned `Resource` are `Cow::Borrowed`
+/// from `buffer`, so the buffer can come from `include_bytes!` or an mmap.
+pub fn get_calendar_data_from_bytes(buffer: &[u8]) -> Result<layout::Resource<'_>, DataError> {
+    let (_, data) = DataHeader::read_from_bytes(buffer, RESOURCE_MAGIC)?;
+    bincode::deserialize(data).map_err(|err| match *err {
+        // A slice cannot fail to read, it can only end too early.
+        bincode::ErrorKind::Io(err) => DataError::Format(err.to_string()),
+        err => Box::new(err).into(),
+    })
+}
+
+pub fn get_calendar_data_from_reader<R: Read>(
+    mut reader: R,
+) -> Result<layout::Resource<'static>, DataError> {
     let mut buffer: Vec<u8> = vec![];
-    fh.read_to_end(&mut buffer).expect("Failed to read");
+    reader.read_to_end(&mut buffer)?;
+
+    Ok(get_calendar_data_from_bytes(&buffer)?.into_owned())
+}
+
+pub fn get_calendar_data(path: &str, locale: &str) -> Result<layout::Resource<'static>, DataError> {
+    let fh = File::open(format!("{}/{}.dat", path, locale))?;
+    get_calendar_data_from_reader(fh)
+}
+
+/// Loads data on demand from a directory of `{locale}.dat` files produced
+/// by `generate_res`.
+pub struct BincodeDataProvider {
+    path: String,
+}
+
+impl BincodeDataProvider {
+    pub fn new<S: Into<String>>(path: S) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+impl DataProvider<'static> for BincodeDataProvider {
+    type Calendar = layout::CalendarData<'static>;
 
-    bincode::deserialize(&buffer).unwrap()
+    fn get_calendar_data(&self, locale: &str, calendar: &str) -> Result<Self::Calendar, DataError> {
+        take_calendar_data(get_calendar_data(&self.path, locale)?, locale, calendar)
+    }
 }
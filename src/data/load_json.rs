@@ -1,11 +1,84 @@
+use super::error::DataError;
 use super::layout;
-use std::fs;
-
-pub fn get_calendar_data<'l, 'a>(path: &'l str, locale: &'l str) -> layout::Resource<'a> {
-    unsafe { layout::IN_JSON = true };
-    let path = format!("{}/main/{}/ca-gregorian.json", path, locale);
-    let contents = fs::read_to_string(path).expect("Something went wrong reading the file");
-    let list: layout::Resource = serde_json::from_str(&contents).unwrap();
-    unsafe { layout::IN_JSON = false };
-    list
+use super::provider::{take_calendar_data, DataProvider};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::prelude::*;
+
+pub fn get_calendar_data_from_str(input: &str) -> Result<layout::Resource<'_>, DataError> {
+    Ok(serde_json::from_str(input)?)
+}
+
+pub fn get_calendar_data_from_bytes(input: &[u8]) -> Result<layout::Resource<'_>, DataError> {
+    let input = std::str::from_utf8(input).map_err(|err| DataError::Format(err.to_string()))?;
+    get_calendar_data_from_str(input)
+}
+
+pub fn get_calendar_data_from_reader<R: Read>(
+    mut reader: R,
+) -> Result<layout::Resource<'static>, DataError> {
+    let mut contents = String::new();
+    reader.read_to_string(&mut contents)?;
+
+    Ok(get_calendar_data_from_str(&contents)?.into_owned())
+}
+
+pub fn get_calendar_data(path: &str, locale: &str) -> Result<layout::Resource<'static>, DataError> {
+    let fh = File::open(format!("{}/main/{}/ca-gregorian.json", path, locale))?;
+    get_calendar_data_from_reader(fh)
+}
+
+/// Loads `{path}/main/{locale}/ca-{calendar}.json` for each of `calendars`
+/// into a single resource.
+pub fn get_calendars_data<S: AsRef<str>>(
+    path: &str,
+    locale: &str,
+    calendars: &[S],
+) -> Result<layout::Resource<'static>, DataError> {
+    let mut merged = layout::Resource {
+        main: HashMap::new(),
+    };
+    for calendar in calendars {
+        let calendar = calendar.as_ref();
+        let fh = File::open(format!("{}/main/{}/ca-{}.json", path, locale, calendar))?;
+        for (locale, locale_res) in get_calendar_data_from_reader(fh)?.main {
+            let data = locale_res
+                .into_calendar(calendar)
+                .ok_or_else(|| DataError::MissingCalendar(calendar.to_string()))?;
+            let slot = merged
+                .main
+                .entry(locale)
+                .or_default()
+                .dates
+                .calendars
+                .get_mut(calendar)
+                .ok_or_else(|| DataError::MissingCalendar(calendar.to_string()))?;
+            *slot = Some(data);
+        }
+    }
+    Ok(merged)
+}
+
+/// Loads data on demand from a CLDR JSON directory laid out as
+/// `{path}/main/{locale}/ca-{calendar}.json`.
+pub struct JsonDataProvider {
+    path: String,
+}
+
+impl JsonDataProvider {
+    pub fn new<S: Into<String>>(path: S) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+impl DataProvider<'static> for JsonDataProvider {
+    type Calendar = layout::CalendarData<'static>;
+
+    fn get_calendar_data(&self, locale: &str, calendar: &str) -> Result<Self::Calendar, DataError> {
+        let fh = File::open(format!(
+            "{}/main/{}/ca-{}.json",
+            self.path, locale, calendar
+        ))?;
+        take_calendar_data(get_calendar_data_from_reader(fh)?, locale, calendar)
+    }
 }
@@ -1,9 +1,19 @@
+pub mod cache;
+pub mod error;
 pub mod layout;
+pub mod overlay;
 pub mod patterns;
+pub mod provider;
+pub mod subset;
+pub mod validate;
 
+#[cfg(feature = "bincode")]
+pub mod bundle;
 #[cfg(not(feature = "no-static"))]
 pub mod generated;
 #[cfg(feature = "bincode")]
+pub mod header;
+#[cfg(feature = "bincode")]
 pub mod load_bin;
 #[cfg(feature = "serde")]
 pub mod load_json;
@@ -0,0 +1,290 @@
+//! Application-level overrides of patterns and names.
+use super::error::DataError;
+use super::layout::{CalendarData, DateTimePattern, DayList, Formats, MonthList, NamesLength};
+use super::provider::DataProvider;
+#[cfg(feature = "serde")]
+use serde::Deserialize;
+use std::borrow::{Borrow, Cow};
+use std::collections::HashMap;
+
+/// Names replacing single entries of a list, keyed as in CLDR: `"1"` and up
+/// for months and `"sun"` to `"sat"` for days.
+pub type NameOverrides = HashMap<String, String>;
+
+#[derive(Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+pub struct WidthOverrides {
+    pub abbreviated: NameOverrides,
+    pub narrow: NameOverrides,
+    pub short: NameOverrides,
+    pub wide: NameOverrides,
+}
+
+impl WidthOverrides {
+    fn get(&self, length: NamesLength) -> &NameOverrides {
+        match length {
+            NamesLength::ABBREVIATED => &self.abbreviated,
+            NamesLength::NARROW => &self.narrow,
+            NamesLength::SHORT => &self.short,
+            NamesLength::WIDE => &self.wide,
+        }
+    }
+}
+
+#[derive(Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+pub struct ContextOverrides {
+    pub format: WidthOverrides,
+    #[cfg_attr(feature = "serde", serde(rename = "stand-alone"))]
+    pub stand_alone: WidthOverrides,
+}
+
+#[derive(Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+pub struct FormatsOverrides {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "deserialize_pattern"))]
+    pub full: Option<DateTimePattern>,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "deserialize_pattern"))]
+    pub long: Option<DateTimePattern>,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "deserialize_pattern"))]
+    pub medium: Option<DateTimePattern>,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "deserialize_pattern"))]
+    pub short: Option<DateTimePattern>,
+}
+
+/// Patterns are written as in CLDR and parsed on load.
+#[cfg(feature = "serde")]
+fn deserialize_pattern<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<DateTimePattern>, D::Error> {
+    let pattern = String::deserialize(deserializer)?;
+    super::patterns::parse_pattern(&pattern)
+        .map(|elements| Some(DateTimePattern::Parsed(elements.into())))
+        .map_err(|err| serde::de::Error::custom(format!("invalid pattern {:?}: {}", pattern, err)))
+}
+
+/// Replacements for parts of one calendar. Everything left empty falls
+/// back to the underlying data.
+#[derive(Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(default, rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct CalendarOverrides {
+    pub months: ContextOverrides,
+    pub days: ContextOverrides,
+    pub date_formats: FormatsOverrides,
+    pub time_formats: FormatsOverrides,
+    pub date_time_formats: FormatsOverrides,
+}
+
+impl CalendarOverrides {
+    /// Replaces the overridden patterns and names in `calendar`, the data
+    /// of the calendar identified by `name`.
+    ///
+    /// Fails if a name is overridden in a list `calendar` does not have,
+    /// or under a key that does not exist.
+    pub fn apply(&self, name: &str, calendar: &mut CalendarData) -> Result<(), DataError> {
+        apply_formats(&mut calendar.date_formats, &self.date_formats);
+        apply_formats(&mut calendar.time_formats, &self.time_formats);
+        apply_formats(&mut calendar.date_time_formats, &self.date_time_formats);
+
+        for &length in &NamesLength::ALL {
+            for &(stand_alone, context, overrides) in &[
+                (false, "format", &self.months.format),
+                (true, "stand-alone", &self.months.stand_alone),
+            ] {
+                let list = calendar.months.get_list_mut(stand_alone, length);
+                apply_names(
+                    list.as_mut().map(|list| &mut list.names),
+                    overrides.get(length),
+                    MonthList::index_of,
+                    || format!("{}/months/{}/{}", name, context, length.name()),
+                )?;
+            }
+            for &(stand_alone, context, overrides) in &[
+                (false, "format", &self.days.format),
+                (true, "stand-alone", &self.days.stand_alone),
+            ] {
+                let list = calendar.days.get_list_mut(stand_alone, length);
+                apply_names(
+                    list.as_mut().map(|list| &mut list.0),
+                    overrides.get(length),
+                    DayList::index_of,
+                    || format!("{}/days/{}/{}", name, context, length.name()),
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn apply_formats(formats: &mut Formats, overrides: &FormatsOverrides) {
+    for (pattern, overridden) in [
+        &mut formats.full,
+        &mut formats.long,
+        &mut formats.medium,
+        &mut formats.short,
+    ]
+    .iter_mut()
+    .zip(&[
+        &overrides.full,
+        &overrides.long,
+        &overrides.medium,
+        &overrides.short,
+    ]) {
+        if let Some(overridden) = overridden {
+            **pattern = Some(overridden.clone());
+        }
+    }
+}
+
+fn apply_names<'l>(
+    names: Option<&mut Cow<'l, [Cow<'l, str>]>>,
+    overrides: &NameOverrides,
+    index_of: fn(&str) -> Option<usize>,
+    path: impl Fn() -> String,
+) -> Result<(), DataError> {
+    if overrides.is_empty() {
+        return Ok(());
+    }
+    let names = names
+        .ok_or_else(|| DataError::MissingData(path()))?
+        .to_mut();
+    for (key, name) in overrides {
+        let entry = index_of(key)
+            .and_then(|idx| names.get_mut(idx))
+            .ok_or_else(|| DataError::Schema(format!("unknown key {:?} in {}", key, path())))?;
+        *entry = Cow::Owned(name.clone());
+    }
+    Ok(())
+}
+
+/// Overrides for any number of locales and calendars.
+#[derive(Debug, Default, PartialEq)]
+pub struct Overrides {
+    calendars: HashMap<(String, String), CalendarOverrides>,
+}
+
+impl Overrides {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses overrides shaped like a CLDR `ca-*.json` file holding only the
+    /// replaced values, for any number of locales and calendars:
+    ///
+    /// ```json
+    /// {"main": {"en-GB": {"dates": {"calendars": {"gregorian": {
+    ///     "dateFormats": {"short": "dd/MM/y"},
+    ///     "months": {"format": {"abbreviated": {"9": "Sept"}}}
+    /// }}}}}}
+    /// ```
+    #[cfg(feature = "serde_json")]
+    pub fn from_json(input: &str) -> Result<Self, DataError> {
+        #[derive(Deserialize)]
+        #[serde(deny_unknown_fields)]
+        struct Main {
+            main: HashMap<String, Locale>,
+        }
+        #[derive(Deserialize)]
+        #[serde(deny_unknown_fields)]
+        struct Locale {
+            dates: Dates,
+        }
+        #[derive(Deserialize)]
+        #[serde(deny_unknown_fields)]
+        struct Dates {
+            calendars: HashMap<String, CalendarOverrides>,
+        }
+
+        let json: Main = serde_json::from_str(input)?;
+        let mut overrides = Self::new();
+        for (locale, data) in json.main {
+            for (calendar, calendar_overrides) in data.dates.calendars {
+                calendar_overrides.check(&calendar)?;
+                overrides.insert(&locale, &calendar, calendar_overrides);
+            }
+        }
+        Ok(overrides)
+    }
+
+    /// Sets the overrides for `calendar` in `locale`, replacing earlier ones.
+    pub fn insert(&mut self, locale: &str, calendar: &str, overrides: CalendarOverrides) {
+        self.calendars
+            .insert((locale.to_string(), calendar.to_string()), overrides);
+    }
+
+    pub fn get(&self, locale: &str, calendar: &str) -> Option<&CalendarOverrides> {
+        self.calendars
+            .get(&(locale.to_string(), calendar.to_string()))
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl CalendarOverrides {
+    /// Rejects name keys that do not exist, so typos surface on load.
+    fn check(&self, calendar: &str) -> Result<(), DataError> {
+        for (kind, context, index_of) in &[
+            (
+                "months",
+                &self.months,
+                MonthList::index_of as fn(&str) -> Option<usize>,
+            ),
+            ("days", &self.days, DayList::index_of),
+        ] {
+            for widths in &[&context.format, &context.stand_alone] {
+                for &length in &NamesLength::ALL {
+                    if let Some(key) = widths.get(length).keys().find(|k| index_of(k).is_none()) {
+                        return Err(DataError::Schema(format!(
+                            "unknown key {:?} in {}/{}",
+                            key, calendar, kind
+                        )));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Layers `Overrides` over the data of another provider.
+///
+/// Calendars without overrides are copied from the inner provider, which
+/// is cheap for static and borrowed data. Wrap the overlay in a
+/// `CachingDataProvider` to avoid repeating the merge for owned data.
+pub struct OverlayProvider<P> {
+    inner: P,
+    overrides: Overrides,
+}
+
+impl<P> OverlayProvider<P> {
+    pub fn new(inner: P, overrides: Overrides) -> Self {
+        Self { inner, overrides }
+    }
+
+    pub fn inner(&self) -> &P {
+        &self.inner
+    }
+
+    pub fn overrides(&self) -> &Overrides {
+        &self.overrides
+    }
+}
+
+impl<'d, P: DataProvider<'d>> DataProvider<'d> for OverlayProvider<P> {
+    type Calendar = CalendarData<'d>;
+
+    fn get_calendar_data(&self, locale: &str, calendar: &str) -> Result<Self::Calendar, DataError> {
+        let data = self.inner.get_calendar_data(locale, calendar)?;
+        let mut data = data.borrow().clone();
+        if let Some(overrides) = self.overrides.get(locale, calendar) {
+            overrides.apply(calendar, &mut data)?;
+        }
+        Ok(data)
+    }
+}
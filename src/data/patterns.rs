@@ -1,11 +1,42 @@
 use super::layout::{DateTimeToken, PatternElement};
 use std::borrow::Cow;
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub enum ParserError {
     UnterminatedLiteral,
+    /// A field symbol, or a length of it, that formatting does not support.
+    UnsupportedField {
+        symbol: char,
+        length: usize,
+        offset: usize,
+    },
+    /// A `{` that does not start a `{0}` or `{1}` placeholder.
+    InvalidPlaceholder {
+        offset: usize,
+    },
 }
 
+impl std::fmt::Display for ParserError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::UnterminatedLiteral => write!(f, "unterminated literal"),
+            Self::UnsupportedField {
+                symbol,
+                length,
+                offset,
+            } => write!(
+                f,
+                "unsupported field {} at {}",
+                symbol.to_string().repeat(*length),
+                offset
+            ),
+            Self::InvalidPlaceholder { offset } => write!(f, "invalid placeholder at {}", offset),
+        }
+    }
+}
+
+impl std::error::Error for ParserError {}
+
 fn collect_literal(
     literal_start: &mut usize,
     idx: usize,
@@ -35,7 +66,9 @@ pub fn parse_pattern<S: AsRef<[u8]>>(input: S) -> Result<Vec<PatternElement>, Pa
                 let next = iter.next();
                 if let Some((_, b'\'')) = next {
                     result.push(PatternElement::Literal(Cow::Owned("'".to_string())));
+                    literal_start = i + 2;
                 } else if let Some((start, _)) = next {
+                    let mut terminated = false;
                     while let Some((i, ch)) = iter.next() {
                         if ch == &b'\'' {
                             if let Some((_, b'\'')) = iter.peek() {
@@ -46,10 +79,14 @@ pub fn parse_pattern<S: AsRef<[u8]>>(input: S) -> Result<Vec<PatternElement>, Pa
                                     slice.replace("''", "'"),
                                 )));
                                 literal_start = i + 1;
+                                terminated = true;
                                 break;
                             }
                         }
                     }
+                    if !terminated {
+                        return Err(ParserError::UnterminatedLiteral);
+                    }
                 } else {
                     return Err(ParserError::UnterminatedLiteral);
                 }
@@ -66,7 +103,13 @@ pub fn parse_pattern<S: AsRef<[u8]>>(input: S) -> Result<Vec<PatternElement>, Pa
                     3 => DateTimeToken::MonthNameAbbreviated,
                     2 => DateTimeToken::Month2digit,
                     1 => DateTimeToken::MonthNumeric,
-                    _ => unimplemented!(),
+                    _ => {
+                        return Err(ParserError::UnsupportedField {
+                            symbol: 'M',
+                            length,
+                            offset: i,
+                        })
+                    }
                 };
                 literal_start += length;
                 result.push(PatternElement::Token(token));
@@ -81,7 +124,77 @@ pub fn parse_pattern<S: AsRef<[u8]>>(input: S) -> Result<Vec<PatternElement>, Pa
                 let token = match length {
                     1 => DateTimeToken::YearNumeric,
                     2 => DateTimeToken::Year2digit,
-                    _ => unimplemented!(),
+                    _ => {
+                        return Err(ParserError::UnsupportedField {
+                            symbol: 'y',
+                            length,
+                            offset: i,
+                        })
+                    }
+                };
+                literal_start += length;
+                result.push(PatternElement::Token(token));
+            }
+            b'G' => {
+                collect_literal(&mut literal_start, i, input.as_ref(), &mut result);
+                let mut length = 1;
+                while let Some((_, b'G')) = iter.peek() {
+                    length += 1;
+                    iter.next();
+                }
+                let token = match length {
+                    1..=3 => DateTimeToken::EraAbbreviated,
+                    4 => DateTimeToken::EraWide,
+                    5 => DateTimeToken::EraNarrow,
+                    _ => {
+                        return Err(ParserError::UnsupportedField {
+                            symbol: 'G',
+                            length,
+                            offset: i,
+                        })
+                    }
+                };
+                literal_start += length;
+                result.push(PatternElement::Token(token));
+            }
+            b'U' => {
+                collect_literal(&mut literal_start, i, input.as_ref(), &mut result);
+                let mut length = 1;
+                while let Some((_, b'U')) = iter.peek() {
+                    length += 1;
+                    iter.next();
+                }
+                let token = match length {
+                    1..=3 => DateTimeToken::CyclicYearAbbreviated,
+                    4 => DateTimeToken::CyclicYearWide,
+                    5 => DateTimeToken::CyclicYearNarrow,
+                    _ => {
+                        return Err(ParserError::UnsupportedField {
+                            symbol: 'U',
+                            length,
+                            offset: i,
+                        })
+                    }
+                };
+                literal_start += length;
+                result.push(PatternElement::Token(token));
+            }
+            b'r' => {
+                collect_literal(&mut literal_start, i, input.as_ref(), &mut result);
+                let mut length = 1;
+                while let Some((_, b'r')) = iter.peek() {
+                    length += 1;
+                    iter.next();
+                }
+                let token = match length {
+                    1 => DateTimeToken::RelatedYear,
+                    _ => {
+                        return Err(ParserError::UnsupportedField {
+                            symbol: 'r',
+                            length,
+                            offset: i,
+                        })
+                    }
                 };
                 literal_start += length;
                 result.push(PatternElement::Token(token));
@@ -96,7 +209,13 @@ pub fn parse_pattern<S: AsRef<[u8]>>(input: S) -> Result<Vec<PatternElement>, Pa
                 let token = match length {
                     1 => DateTimeToken::DayNumeric,
                     2 => DateTimeToken::Day2digit,
-                    _ => unimplemented!(),
+                    _ => {
+                        return Err(ParserError::UnsupportedField {
+                            symbol: 'd',
+                            length,
+                            offset: i,
+                        })
+                    }
                 };
                 literal_start += length;
                 result.push(PatternElement::Token(token));
@@ -110,7 +229,13 @@ pub fn parse_pattern<S: AsRef<[u8]>>(input: S) -> Result<Vec<PatternElement>, Pa
                 }
                 let token = match length {
                     4 => DateTimeToken::WeekDayWide,
-                    _ => unimplemented!(),
+                    _ => {
+                        return Err(ParserError::UnsupportedField {
+                            symbol: 'E',
+                            length,
+                            offset: i,
+                        })
+                    }
                 };
                 literal_start += length;
                 result.push(PatternElement::Token(token));
@@ -125,7 +250,13 @@ pub fn parse_pattern<S: AsRef<[u8]>>(input: S) -> Result<Vec<PatternElement>, Pa
                 let token = match length {
                     2 => DateTimeToken::Hour2digit,
                     1 => DateTimeToken::HourNumeric,
-                    _ => unimplemented!(),
+                    _ => {
+                        return Err(ParserError::UnsupportedField {
+                            symbol: 'H',
+                            length,
+                            offset: i,
+                        })
+                    }
                 };
                 literal_start += length;
                 result.push(PatternElement::Token(token));
@@ -140,7 +271,13 @@ pub fn parse_pattern<S: AsRef<[u8]>>(input: S) -> Result<Vec<PatternElement>, Pa
                 let token = match length {
                     2 => DateTimeToken::Minute2digit,
                     1 => DateTimeToken::MinuteNumeric,
-                    _ => unimplemented!(),
+                    _ => {
+                        return Err(ParserError::UnsupportedField {
+                            symbol: 'm',
+                            length,
+                            offset: i,
+                        })
+                    }
                 };
                 literal_start += length;
                 result.push(PatternElement::Token(token));
@@ -155,7 +292,13 @@ pub fn parse_pattern<S: AsRef<[u8]>>(input: S) -> Result<Vec<PatternElement>, Pa
                 let token = match length {
                     2 => DateTimeToken::Second2digit,
                     1 => DateTimeToken::SecondNumeric,
-                    _ => unimplemented!(),
+                    _ => {
+                        return Err(ParserError::UnsupportedField {
+                            symbol: 's',
+                            length,
+                            offset: i,
+                        })
+                    }
                 };
                 literal_start += length;
                 result.push(PatternElement::Token(token));
@@ -172,24 +315,48 @@ pub fn parse_pattern<S: AsRef<[u8]>>(input: S) -> Result<Vec<PatternElement>, Pa
                     2 => DateTimeToken::ZoneShort,
                     3 => DateTimeToken::ZoneShort,
                     4 => DateTimeToken::ZoneLong,
-                    _ => unimplemented!(),
+                    _ => {
+                        return Err(ParserError::UnsupportedField {
+                            symbol: 'z',
+                            length,
+                            offset: i,
+                        })
+                    }
                 };
                 literal_start += length;
                 result.push(PatternElement::Token(token));
             }
             b'{' => {
                 collect_literal(&mut literal_start, i, input.as_ref(), &mut result);
-                let num = iter.next().unwrap();
-                match num.1 {
-                    b'0' => result.push(PatternElement::Token(DateTimeToken::Sub0)),
-                    b'1' => result.push(PatternElement::Token(DateTimeToken::Sub1)),
-                    _ => unimplemented!(),
-                }
-                iter.next(); // }
+                let token = match (iter.next(), iter.next()) {
+                    (Some((_, b'0')), Some((_, b'}'))) => DateTimeToken::Sub0,
+                    (Some((_, b'1')), Some((_, b'}'))) => DateTimeToken::Sub1,
+                    _ => return Err(ParserError::InvalidPlaceholder { offset: i }),
+                };
+                result.push(PatternElement::Token(token));
                 literal_start += 3;
             }
+            // Every other ASCII letter is a field symbol in CLDR patterns.
+            ch if ch.is_ascii_alphabetic() => {
+                let mut length = 1;
+                while iter.peek().is_some_and(|(_, next)| *next == ch) {
+                    length += 1;
+                    iter.next();
+                }
+                return Err(ParserError::UnsupportedField {
+                    symbol: *ch as char,
+                    length,
+                    offset: i,
+                });
+            }
             _ => {}
         }
     }
+    collect_literal(
+        &mut literal_start,
+        input.as_ref().len(),
+        input.as_ref(),
+        &mut result,
+    );
     Ok(result)
 }
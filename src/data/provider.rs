@@ -0,0 +1,68 @@
+use super::error::DataError;
+use super::layout::{CalendarData, Resource};
+use std::borrow::Borrow;
+
+/// A source of calendar data.
+///
+/// Implement this trait to feed `DateTimeFormat` from a custom location,
+/// such as a database or a virtual filesystem.
+pub trait DataProvider<'d> {
+    /// The handle returned for a single calendar. It can be a reference into
+    /// the provider, an owned value, or a shared pointer.
+    type Calendar: Borrow<CalendarData<'d>>;
+
+    /// Returns data for `calendar` (for example `"gregorian"`) in `locale`.
+    fn get_calendar_data(&self, locale: &str, calendar: &str) -> Result<Self::Calendar, DataError>;
+}
+
+impl<'d, P: DataProvider<'d>> DataProvider<'d> for &P {
+    type Calendar = P::Calendar;
+
+    fn get_calendar_data(&self, locale: &str, calendar: &str) -> Result<Self::Calendar, DataError> {
+        (**self).get_calendar_data(locale, calendar)
+    }
+}
+
+impl<'p, 'd> DataProvider<'d> for &'p Resource<'d> {
+    type Calendar = &'p CalendarData<'d>;
+
+    fn get_calendar_data(&self, locale: &str, calendar: &str) -> Result<Self::Calendar, DataError> {
+        let resource: &'p Resource<'d> = self;
+        resource
+            .get(locale)
+            .ok_or_else(|| DataError::MissingLocale(locale.to_string()))?
+            .get_calendar(calendar)
+            .ok_or_else(|| DataError::MissingCalendar(calendar.to_string()))
+    }
+}
+
+/// Provides data compiled into the binary by `generate_tables`.
+#[cfg(not(feature = "no-static"))]
+pub struct StaticDataProvider;
+
+#[cfg(not(feature = "no-static"))]
+impl DataProvider<'static> for StaticDataProvider {
+    type Calendar = &'static CalendarData<'static>;
+
+    fn get_calendar_data(&self, locale: &str, calendar: &str) -> Result<Self::Calendar, DataError> {
+        super::generated::get(locale)
+            .ok_or_else(|| DataError::MissingLocale(locale.to_string()))?
+            .get_calendar(calendar)
+            .ok_or_else(|| DataError::MissingCalendar(calendar.to_string()))
+    }
+}
+
+/// Moves the data for `calendar` in `locale` out of a loaded `Resource`.
+#[cfg(feature = "serde")]
+pub(crate) fn take_calendar_data<'d>(
+    mut resource: Resource<'d>,
+    locale: &str,
+    calendar: &str,
+) -> Result<CalendarData<'d>, DataError> {
+    resource
+        .main
+        .remove(locale)
+        .ok_or_else(|| DataError::MissingLocale(locale.to_string()))?
+        .into_calendar(calendar)
+        .ok_or_else(|| DataError::MissingCalendar(calendar.to_string()))
+}
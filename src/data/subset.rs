@@ -0,0 +1,198 @@
+//! Stripping calendar data down to what an application uses.
+#[cfg(feature = "serde_json")]
+use super::error::DataError;
+use super::layout::{
+    CalendarData, DateTimePattern, DateTimeToken, Formats, NamesKind, NamesLength, PatternElement,
+};
+use crate::{DateStyle, TimeStyle};
+
+/// Lists the styles and pattern tokens an application formats with.
+///
+/// Generators use it to drop every pattern and name list the application
+/// cannot reach. Requesting anything outside the manifest from the
+/// resulting data makes `DateTimeFormat::new` fail with
+/// `DataError::MissingData`.
+#[derive(Debug, Default, PartialEq)]
+pub struct UsageManifest {
+    pub date_styles: Vec<DateStyle>,
+    pub time_styles: Vec<TimeStyle>,
+    /// Tokens used outside of the styles, for example by custom patterns.
+    pub tokens: Vec<DateTimeToken>,
+}
+
+impl UsageManifest {
+    /// Parses a manifest such as
+    /// `{"dateStyles": ["short"], "timeStyles": [], "tokens": ["MMMM"]}`.
+    ///
+    /// Each entry of `tokens` is a pattern, so a whole pattern like
+    /// `"d MMMM y"` can be listed instead of its individual symbols.
+    #[cfg(feature = "serde_json")]
+    pub fn from_json(input: &str) -> Result<Self, DataError> {
+        use serde::Deserialize;
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase", deny_unknown_fields)]
+        struct ManifestJson {
+            #[serde(default)]
+            date_styles: Vec<String>,
+            #[serde(default)]
+            time_styles: Vec<String>,
+            #[serde(default)]
+            tokens: Vec<String>,
+        }
+
+        let json: ManifestJson = serde_json::from_str(input)?;
+        let style_idx = |name: &String| {
+            Formats::NAMES
+                .iter()
+                .position(|n| n == name)
+                .ok_or_else(|| DataError::Schema(format!("unknown style {:?}", name)))
+        };
+
+        let mut manifest = Self::default();
+        for name in &json.date_styles {
+            manifest.date_styles.push(DateStyle::ALL[style_idx(name)?]);
+        }
+        for name in &json.time_styles {
+            manifest.time_styles.push(TimeStyle::ALL[style_idx(name)?]);
+        }
+        for pattern in &json.tokens {
+            let elements = super::patterns::parse_pattern(pattern).map_err(|err| {
+                DataError::Schema(format!("invalid pattern {:?}: {:?}", pattern, err))
+            })?;
+            manifest
+                .tokens
+                .extend(elements.into_iter().filter_map(|elem| match elem {
+                    PatternElement::Token(token) => Some(token),
+                    PatternElement::Literal(_) => None,
+                }));
+        }
+        Ok(manifest)
+    }
+
+    /// Removes the patterns and name lists of `calendar` not reachable
+    /// from this manifest.
+    pub fn subset(&self, calendar: &mut CalendarData) {
+        let date_styles: Vec<usize> = self.date_styles.iter().map(|s| s.idx()).collect();
+        let time_styles: Vec<usize> = self.time_styles.iter().map(|s| s.idx()).collect();
+
+        retain_formats(&mut calendar.date_formats, &date_styles);
+        retain_formats(&mut calendar.time_formats, &time_styles);
+        // Date and time are joined with the pattern of the date style.
+        if time_styles.is_empty() {
+            retain_formats(&mut calendar.date_time_formats, &[]);
+        } else {
+            retain_formats(&mut calendar.date_time_formats, &date_styles);
+        }
+
+        let mut names = vec![];
+        for formats in &[
+            &calendar.date_formats,
+            &calendar.time_formats,
+            &calendar.date_time_formats,
+        ] {
+            for idx in 0..Formats::NAMES.len() {
+                if let Some(pattern) = formats.get(idx) {
+                    collect_names(pattern, &mut names);
+                }
+            }
+        }
+        names.extend(self.tokens.iter().filter_map(DateTimeToken::names));
+
+        // Formatting only reads names in the format context, so every
+        // stand-alone list goes.
+        let months = &mut calendar.months;
+        retain_lists(
+            [
+                &mut months.format.abbreviated,
+                &mut months.format.narrow,
+                &mut months.format.short,
+                &mut months.format.wide,
+            ],
+            |length| names.contains(&(NamesKind::Months, length)),
+        );
+        retain_lists(
+            [
+                &mut months.stand_alone.abbreviated,
+                &mut months.stand_alone.narrow,
+                &mut months.stand_alone.short,
+                &mut months.stand_alone.wide,
+            ],
+            |_| false,
+        );
+        let days = &mut calendar.days;
+        retain_lists(
+            [
+                &mut days.format.abbreviated,
+                &mut days.format.narrow,
+                &mut days.format.short,
+                &mut days.format.wide,
+            ],
+            |length| names.contains(&(NamesKind::Days, length)),
+        );
+        retain_lists(
+            [
+                &mut days.stand_alone.abbreviated,
+                &mut days.stand_alone.narrow,
+                &mut days.stand_alone.short,
+                &mut days.stand_alone.wide,
+            ],
+            |_| false,
+        );
+        if let Some(eras) = &mut calendar.eras {
+            for &length in &NamesLength::ALL {
+                if !names.contains(&(NamesKind::Eras, length)) {
+                    if let Some(list) = eras.get_list_mut(length) {
+                        *list = None;
+                    }
+                }
+            }
+        }
+        if let Some(years) = calendar
+            .cyclic_name_sets
+            .as_mut()
+            .and_then(|sets| sets.years.as_mut())
+        {
+            for &length in &NamesLength::ALL {
+                if !names.contains(&(NamesKind::CyclicYears, length)) {
+                    if let Some(list) = years.format.get_list_mut(length) {
+                        *list = None;
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn retain_formats(formats: &mut Formats, keep: &[usize]) {
+    let mut patterns = [
+        &mut formats.full,
+        &mut formats.long,
+        &mut formats.medium,
+        &mut formats.short,
+    ];
+    for (idx, pattern) in patterns.iter_mut().enumerate() {
+        if !keep.contains(&idx) {
+            **pattern = None;
+        }
+    }
+}
+
+/// Clears the lists, given in `NamesLength::ALL` order, that are not kept.
+fn retain_lists<T>(mut lists: [&mut Option<T>; 4], keep: impl Fn(NamesLength) -> bool) {
+    for (list, length) in lists.iter_mut().zip(NamesLength::ALL.iter()) {
+        if !keep(*length) {
+            **list = None;
+        }
+    }
+}
+
+/// Adds the name lists `pattern` formats with. Patterns that fail to parse
+/// cannot be formatted, so they need none.
+fn collect_names(pattern: &DateTimePattern, names: &mut Vec<(NamesKind, NamesLength)>) {
+    for elem in pattern.to_parsed().unwrap_or_default() {
+        if let PatternElement::Token(token) = elem {
+            names.extend(token.names());
+        }
+    }
+}
@@ -0,0 +1,260 @@
+//! Checking that calendar data can be parsed and formatted.
+use super::layout::{CalendarData, DateTimePattern, Formats, PatternElement};
+use super::patterns::{parse_pattern, ParserError};
+use crate::calendar::CalendarSystem;
+use crate::{DateStyle, DateTime, TimeStyle};
+use std::fmt;
+
+/// Dates every pattern is formatted with. They cover one and two digit
+/// fields, both ends of the year, a leap day and midnight.
+pub const SAMPLE_DATES: [DateTime; 5] = [
+    DateTime {
+        year: 2019,
+        month: 10,
+        day: 29,
+        hour: 10,
+        minute: 23,
+        second: 5,
+    },
+    DateTime {
+        year: 2000,
+        month: 1,
+        day: 1,
+        hour: 0,
+        minute: 0,
+        second: 0,
+    },
+    DateTime {
+        year: 1999,
+        month: 12,
+        day: 31,
+        hour: 23,
+        minute: 59,
+        second: 59,
+    },
+    DateTime {
+        year: 2024,
+        month: 2,
+        day: 29,
+        hour: 12,
+        minute: 30,
+        second: 0,
+    },
+    DateTime {
+        year: 2021,
+        month: 7,
+        day: 4,
+        hour: 9,
+        minute: 5,
+        second: 7,
+    },
+];
+
+#[derive(Debug, PartialEq)]
+pub enum Issue {
+    /// The pattern at `path` is absent.
+    MissingPattern { path: String },
+    /// The pattern at `path` failed to parse.
+    Parse {
+        path: String,
+        pattern: String,
+        error: ParserError,
+    },
+    /// The pattern at `path` uses a name list that is absent.
+    MissingNames { path: String, names: String },
+    /// The pattern at `path` failed to format `date`.
+    Format { path: String, date: String },
+}
+
+impl Issue {
+    /// The unsupported field symbol behind a parse issue, if any.
+    pub fn unsupported_symbol(&self) -> Option<char> {
+        match self {
+            Self::Parse {
+                error: ParserError::UnsupportedField { symbol, .. },
+                ..
+            } => Some(*symbol),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::MissingPattern { path } => write!(f, "{}: missing pattern", path),
+            Self::Parse {
+                path,
+                pattern,
+                error,
+            } => write!(f, "{}: {} in {:?}", path, error, pattern),
+            Self::MissingNames { path, names } => write!(f, "{}: missing names {}", path, names),
+            Self::Format { path, date } => write!(f, "{}: failed to format {}", path, date),
+        }
+    }
+}
+
+/// Parses `pattern`, recording issues under `path` on failure.
+///
+/// Every unsupported field is reported, not only the first one.
+fn check_pattern(
+    pattern: Option<&DateTimePattern>,
+    path: &str,
+    issues: &mut Vec<Issue>,
+) -> Option<Vec<PatternElement>> {
+    let raw = match pattern {
+        None => {
+            issues.push(Issue::MissingPattern {
+                path: path.to_string(),
+            });
+            return None;
+        }
+        Some(DateTimePattern::Raw(raw)) => raw,
+        Some(DateTimePattern::Parsed(elements)) => return Some(elements.to_vec()),
+    };
+
+    let mut remaining = raw.to_string();
+    let mut valid = true;
+    loop {
+        let error = match parse_pattern(&remaining) {
+            Ok(elements) if valid => return Some(elements),
+            Ok(_) => return None,
+            Err(error) => error,
+        };
+        valid = false;
+        let next = match error {
+            // Blank out the field, keeping offsets, and look for more.
+            ParserError::UnsupportedField { length, offset, .. } => {
+                let mut next = remaining.clone();
+                next.replace_range(offset..offset + length, &" ".repeat(length));
+                Some(next)
+            }
+            _ => None,
+        };
+        issues.push(Issue::Parse {
+            path: path.to_string(),
+            pattern: raw.to_string(),
+            error,
+        });
+        match next {
+            Some(next) => remaining = next,
+            None => return None,
+        }
+    }
+}
+
+/// Records an issue for every name list `pattern` needs but `calendar` lacks.
+fn check_names(
+    calendar: &CalendarData,
+    pattern: &[PatternElement],
+    path: &str,
+    issues: &mut Vec<Issue>,
+) -> bool {
+    let mut complete = true;
+    for elem in pattern {
+        if let PatternElement::Token(token) = elem {
+            if let Some((kind, length)) = token.names() {
+                if !calendar.has_names(kind, false, length) {
+                    complete = false;
+                    issues.push(Issue::MissingNames {
+                        path: path.to_string(),
+                        names: kind.path(false, length),
+                    });
+                }
+            }
+        }
+    }
+    complete
+}
+
+fn check_format(
+    calendar: &CalendarData,
+    system: &dyn CalendarSystem,
+    pattern: &[PatternElement],
+    path: &str,
+    issues: &mut Vec<Issue>,
+) {
+    for date in &SAMPLE_DATES {
+        let mut result = String::new();
+        if calendar
+            .format_elements(&mut result, pattern, date, system)
+            .is_err()
+        {
+            issues.push(Issue::Format {
+                path: path.to_string(),
+                date: format!(
+                    "{}-{:02}-{:02} {:02}:{:02}:{:02}",
+                    date.year, date.month, date.day, date.hour, date.minute, date.second
+                ),
+            });
+        }
+    }
+}
+
+/// Parses every pattern of `calendar` and formats the sample dates in
+/// `system` with each date and time style, alone and combined.
+///
+/// Returns the issues found. Patterns that fail to parse or lack names are
+/// not formatted, so each problem is reported once.
+pub fn validate_calendar(calendar: &CalendarData, system: &dyn CalendarSystem) -> Vec<Issue> {
+    let mut issues = vec![];
+    let name = system.data_name();
+    let check_formats = |formats: &Formats, key: &str, issues: &mut Vec<Issue>| {
+        let mut usable = vec![];
+        for (idx, style) in Formats::NAMES.iter().enumerate() {
+            let path = format!("{}/{}/{}", name, key, style);
+            let pattern = check_pattern(formats.get(idx), &path, issues);
+            usable.push(pattern.filter(|p| check_names(calendar, p, &path, issues)));
+        }
+        usable
+    };
+    let date_formats = check_formats(&calendar.date_formats, "dateFormats", &mut issues);
+    let time_formats = check_formats(&calendar.time_formats, "timeFormats", &mut issues);
+    let date_time_formats =
+        check_formats(&calendar.date_time_formats, "dateTimeFormats", &mut issues);
+
+    for (pattern, style) in date_formats.iter().zip(&Formats::NAMES) {
+        if let Some(pattern) = pattern {
+            check_format(
+                calendar,
+                system,
+                pattern,
+                &format!("{}/dateFormats/{}", name, style),
+                &mut issues,
+            );
+        }
+    }
+    for (pattern, style) in time_formats.iter().zip(&Formats::NAMES) {
+        if let Some(pattern) = pattern {
+            check_format(
+                calendar,
+                system,
+                pattern,
+                &format!("{}/timeFormats/{}", name, style),
+                &mut issues,
+            );
+        }
+    }
+
+    for &date_style in &DateStyle::ALL {
+        for &time_style in &TimeStyle::ALL {
+            let (glue, date, time) = match (
+                &date_time_formats[date_style.idx()],
+                &date_formats[date_style.idx()],
+                &time_formats[time_style.idx()],
+            ) {
+                (Some(glue), Some(date), Some(time)) => (glue, date, time),
+                _ => continue,
+            };
+            let pattern = crate::join_date_time_pattern(glue, date, time);
+            let path = format!(
+                "{}/dateTimeFormats/{}+{}",
+                name,
+                Formats::NAMES[date_style.idx()],
+                Formats::NAMES[time_style.idx()]
+            );
+            check_format(calendar, system, &pattern, &path, &mut issues);
+        }
+    }
+    issues
+}
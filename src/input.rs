@@ -0,0 +1,394 @@
+//! The values dates and times are formatted from.
+use crate::calendar::{day_of_week, fixed_from_gregorian};
+use crate::{DateTime, DateTimeError, DateTimeField};
+
+/// A date and time that can be formatted, in the proleptic Gregorian
+/// calendar.
+///
+/// Besides `DateTime`, it is implemented for the date and time types of
+/// `chrono` and `time` with the features of the same names.
+pub trait DateTimeInput {
+    /// The year, where 0 is 1 BC.
+    fn year(&self) -> i64;
+
+    /// The month, from 1 for January.
+    fn month(&self) -> usize;
+
+    fn day(&self) -> usize;
+
+    /// The day of the week, with 0 for Sunday.
+    fn weekday(&self) -> usize {
+        day_of_week(fixed_from_gregorian(self.year(), self.month(), self.day()))
+    }
+
+    fn hour(&self) -> usize;
+
+    fn minute(&self) -> usize;
+
+    fn second(&self) -> usize;
+
+    /// Nanoseconds past the second.
+    fn nanosecond(&self) -> u32 {
+        0
+    }
+
+    /// Minutes east of UTC, or `None` for local time.
+    fn offset(&self) -> Option<i32> {
+        None
+    }
+
+    /// The name of the time zone, which zone fields write as is.
+    fn zone(&self) -> Option<&str> {
+        None
+    }
+
+    /// Whether the input holds `field`, which inputs have to say for each
+    /// field they hold. Inputs that do not, such as `Time` for the year,
+    /// return placeholders for it, and `DateTimeFormat::for_input` rejects
+    /// patterns that would write them.
+    fn provides(_field: DateTimeField) -> bool
+    where
+        Self: Sized,
+    {
+        false
+    }
+}
+
+/// A `DateTimeInput` holding every field from the year to the second, which
+/// `DateTimeFormat::format` formats with any pattern.
+///
+/// Inputs without some of them, such as `Date`, are formatted through
+/// `DateTimeFormat::for_input` instead.
+pub trait FullDateTimeInput: DateTimeInput {}
+
+/// Whether inputs holding every field but the zone provide `field`.
+fn provides_local(field: DateTimeField) -> bool {
+    field != DateTimeField::Zone
+}
+
+impl DateTimeInput for DateTime {
+    fn year(&self) -> i64 {
+        self.year as i64
+    }
+
+    fn month(&self) -> usize {
+        self.month
+    }
+
+    fn day(&self) -> usize {
+        self.day
+    }
+
+    fn hour(&self) -> usize {
+        self.hour
+    }
+
+    fn minute(&self) -> usize {
+        self.minute
+    }
+
+    fn second(&self) -> usize {
+        self.second
+    }
+
+    fn provides(field: DateTimeField) -> bool {
+        provides_local(field)
+    }
+}
+
+impl FullDateTimeInput for DateTime {}
+
+#[cfg(feature = "chrono")]
+mod chrono_input {
+    use super::{provides_local, DateTimeInput, FullDateTimeInput};
+    use crate::DateTimeField;
+    use chrono::{Datelike, Offset, TimeZone, Timelike};
+
+    macro_rules! impl_chrono_input {
+        () => {
+            fn year(&self) -> i64 {
+                Datelike::year(self) as i64
+            }
+
+            fn month(&self) -> usize {
+                Datelike::month(self) as usize
+            }
+
+            fn day(&self) -> usize {
+                Datelike::day(self) as usize
+            }
+
+            fn weekday(&self) -> usize {
+                Datelike::weekday(self).num_days_from_sunday() as usize
+            }
+
+            fn hour(&self) -> usize {
+                Timelike::hour(self) as usize
+            }
+
+            fn minute(&self) -> usize {
+                Timelike::minute(self) as usize
+            }
+
+            fn second(&self) -> usize {
+                Timelike::second(self) as usize
+            }
+
+            fn nanosecond(&self) -> u32 {
+                Timelike::nanosecond(self)
+            }
+        };
+    }
+
+    impl<Tz: TimeZone> DateTimeInput for chrono::DateTime<Tz> {
+        impl_chrono_input!();
+
+        fn offset(&self) -> Option<i32> {
+            Some(chrono::DateTime::offset(self).fix().local_minus_utc() / 60)
+        }
+
+        fn provides(_field: DateTimeField) -> bool {
+            true
+        }
+    }
+
+    impl DateTimeInput for chrono::NaiveDateTime {
+        impl_chrono_input!();
+
+        fn provides(field: DateTimeField) -> bool {
+            provides_local(field)
+        }
+    }
+
+    impl<Tz: TimeZone> FullDateTimeInput for chrono::DateTime<Tz> {}
+    impl FullDateTimeInput for chrono::NaiveDateTime {}
+}
+
+#[cfg(feature = "time")]
+mod time_input {
+    use super::{provides_local, DateTimeInput, FullDateTimeInput};
+    use crate::DateTimeField;
+
+    macro_rules! impl_time_input {
+        () => {
+            fn year(&self) -> i64 {
+                Self::year(*self) as i64
+            }
+
+            fn month(&self) -> usize {
+                u8::from(Self::month(*self)) as usize
+            }
+
+            fn day(&self) -> usize {
+                Self::day(*self) as usize
+            }
+
+            fn weekday(&self) -> usize {
+                Self::weekday(*self).number_days_from_sunday() as usize
+            }
+
+            fn hour(&self) -> usize {
+                Self::hour(*self) as usize
+            }
+
+            fn minute(&self) -> usize {
+                Self::minute(*self) as usize
+            }
+
+            fn second(&self) -> usize {
+                Self::second(*self) as usize
+            }
+
+            fn nanosecond(&self) -> u32 {
+                Self::nanosecond(*self)
+            }
+        };
+    }
+
+    impl DateTimeInput for time::OffsetDateTime {
+        impl_time_input!();
+
+        fn offset(&self) -> Option<i32> {
+            Some(Self::offset(*self).whole_seconds() / 60)
+        }
+
+        fn provides(_field: DateTimeField) -> bool {
+            true
+        }
+    }
+
+    impl DateTimeInput for time::PrimitiveDateTime {
+        impl_time_input!();
+
+        fn provides(field: DateTimeField) -> bool {
+            provides_local(field)
+        }
+    }
+
+    impl FullDateTimeInput for time::OffsetDateTime {}
+    impl FullDateTimeInput for time::PrimitiveDateTime {}
+}
+
+/// A date without a time, formatted as midnight.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct Date {
+    pub year: usize,
+    pub month: usize,
+    pub day: usize,
+}
+
+/// A time of day without a date, formatted on January 1, 1970.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct Time {
+    pub hour: usize,
+    pub minute: usize,
+    pub second: usize,
+}
+
+/// A month of a year, such as October 2019.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct YearMonth {
+    pub year: usize,
+    pub month: usize,
+}
+
+/// A day of a month in any year, such as February 29.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct MonthDay {
+    pub month: usize,
+    pub day: usize,
+}
+
+/// The year `MonthDay` is formatted in, a leap year so that February 29
+/// exists.
+const MONTH_DAY_YEAR: usize = 2000;
+
+impl Date {
+    pub fn new(year: usize, month: usize, day: usize) -> Self {
+        Self { year, month, day }
+    }
+
+    /// Creates a date, checking that it exists, as `DateTime::try_new`.
+    pub fn try_new(year: usize, month: usize, day: usize) -> Result<Self, DateTimeError> {
+        DateTime::try_new(year, month, day, 0, 0, 0)?;
+        Ok(Self::new(year, month, day))
+    }
+}
+
+impl Time {
+    pub fn new(hour: usize, minute: usize, second: usize) -> Self {
+        Self {
+            hour,
+            minute,
+            second,
+        }
+    }
+
+    /// Creates a time, checking that it exists, as `DateTime::try_new`.
+    pub fn try_new(hour: usize, minute: usize, second: usize) -> Result<Self, DateTimeError> {
+        DateTime::try_new(1970, 1, 1, hour, minute, second)?;
+        Ok(Self::new(hour, minute, second))
+    }
+}
+
+impl YearMonth {
+    pub fn new(year: usize, month: usize) -> Self {
+        Self { year, month }
+    }
+
+    /// Creates a month, checking that it exists, as `DateTime::try_new`.
+    pub fn try_new(year: usize, month: usize) -> Result<Self, DateTimeError> {
+        DateTime::try_new(year, month, 1, 0, 0, 0)?;
+        Ok(Self::new(year, month))
+    }
+}
+
+impl MonthDay {
+    pub fn new(month: usize, day: usize) -> Self {
+        Self { month, day }
+    }
+
+    /// Creates a day, checking that it exists in some year, as
+    /// `DateTime::try_new`.
+    pub fn try_new(month: usize, day: usize) -> Result<Self, DateTimeError> {
+        DateTime::try_new(MONTH_DAY_YEAR, month, day, 0, 0, 0)?;
+        Ok(Self::new(month, day))
+    }
+}
+
+impl From<DateTime> for Date {
+    fn from(dt: DateTime) -> Self {
+        Self::new(dt.year, dt.month, dt.day)
+    }
+}
+
+impl From<DateTime> for Time {
+    fn from(dt: DateTime) -> Self {
+        Self::new(dt.hour, dt.minute, dt.second)
+    }
+}
+
+impl Date {
+    fn with_placeholders(&self) -> DateTime {
+        DateTime::new(self.year, self.month, self.day, 0, 0, 0)
+    }
+}
+
+impl Time {
+    fn with_placeholders(&self) -> DateTime {
+        DateTime::new(1970, 1, 1, self.hour, self.minute, self.second)
+    }
+}
+
+impl YearMonth {
+    fn with_placeholders(&self) -> DateTime {
+        DateTime::new(self.year, self.month, 1, 0, 0, 0)
+    }
+}
+
+impl MonthDay {
+    fn with_placeholders(&self) -> DateTime {
+        DateTime::new(MONTH_DAY_YEAR, self.month, self.day, 0, 0, 0)
+    }
+}
+
+/// Implements `DateTimeInput` for a partial input holding the `$provided`
+/// fields, taking the others from its `with_placeholders`.
+macro_rules! impl_partial_input {
+    ($input:ty, [$($provided:ident),*]) => {
+        impl DateTimeInput for $input {
+            fn year(&self) -> i64 {
+                self.with_placeholders().year as i64
+            }
+
+            fn month(&self) -> usize {
+                self.with_placeholders().month
+            }
+
+            fn day(&self) -> usize {
+                self.with_placeholders().day
+            }
+
+            fn hour(&self) -> usize {
+                self.with_placeholders().hour
+            }
+
+            fn minute(&self) -> usize {
+                self.with_placeholders().minute
+            }
+
+            fn second(&self) -> usize {
+                self.with_placeholders().second
+            }
+
+            fn provides(field: DateTimeField) -> bool {
+                matches!(field, $(DateTimeField::$provided)|*)
+            }
+        }
+    };
+}
+
+impl_partial_input!(Date, [Year, Month, Day]);
+impl_partial_input!(Time, [Hour, Minute, Second]);
+impl_partial_input!(YearMonth, [Year, Month]);
+impl_partial_input!(MonthDay, [Month, Day]);
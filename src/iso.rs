@@ -0,0 +1,456 @@
+//! ISO 8601 representations of dates and times, of which RFC 3339 is a
+//! profile.
+use crate::calendar::{
+    day_of_week, days_in_gregorian_month, fixed_from_gregorian, gregorian_from_fixed,
+    is_gregorian_leap_year,
+};
+use crate::{DateTime, MAX_YEAR};
+use std::fmt;
+
+/// The forms of ISO 8601 representations.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum IsoFormat {
+    /// With separators, as in `2019-10-29T10:23:05+02:00`. RFC 3339 uses
+    /// this form, for years up to 9999.
+    Extended,
+    /// Without separators, as in `20191029T102305+0200`.
+    Basic,
+}
+
+/// A field of an ISO 8601 date or time.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum IsoField {
+    Year,
+    Month,
+    Day,
+    Week,
+    WeekDay,
+    DayOfYear,
+    Hour,
+    Minute,
+    Second,
+    Offset,
+}
+
+/// An error parsing an ISO 8601 date or time, with the byte offset in the
+/// input it was found at.
+#[derive(Debug, PartialEq)]
+pub enum IsoError {
+    /// The input does not follow any of the representations.
+    Syntax { offset: usize },
+    /// `field` holds a value that does not exist, such as week 53 of a year
+    /// with 52 weeks.
+    OutOfRange { field: IsoField, offset: usize },
+}
+
+impl fmt::Display for IsoError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Syntax { offset } => write!(f, "invalid ISO 8601 date or time at {}", offset),
+            Self::OutOfRange { field, offset } => {
+                write!(f, "{:?} out of range at {}", field, offset)
+            }
+        }
+    }
+}
+
+impl std::error::Error for IsoError {}
+
+/// A date and time as ISO 8601 writes it, with the fraction of the second
+/// and the UTC offset that `DateTime` does not hold.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct IsoDateTime {
+    pub date_time: DateTime,
+    /// Nanoseconds past the second.
+    pub nanosecond: u32,
+    /// Minutes east of UTC, or `None` for local time.
+    pub offset: Option<i32>,
+}
+
+impl From<DateTime> for IsoDateTime {
+    fn from(date_time: DateTime) -> Self {
+        Self::new(date_time, 0, None)
+    }
+}
+
+impl IsoDateTime {
+    pub fn new(date_time: DateTime, nanosecond: u32, offset: Option<i32>) -> Self {
+        Self {
+            date_time,
+            nanosecond,
+            offset,
+        }
+    }
+
+    /// Parses a date, a time, or a date and time joined by `T` or a space,
+    /// in either form.
+    ///
+    /// Dates are calendar dates (`2019-10-29`), week dates (`2019-W44-2`)
+    /// or ordinal dates (`2019-302`), with four-digit years or expanded
+    /// ones after `+`, as in `+012019-10-29`. Expanded years have six
+    /// digits in basic calendar and ordinal dates, leaving out year
+    /// 1,000,000, and any number above four elsewhere. Times have
+    /// hours, and optionally minutes, seconds and a fraction of a second
+    /// after `.` or `,`, followed by `Z` or an offset such as `+02:00`.
+    /// Times alone may start with `T`, which they need in the basic form.
+    /// As in `DateTimeParser`, a missing date is January 1, 1970 and a
+    /// missing time is midnight.
+    pub fn parse(input: &str) -> Result<Self, IsoError> {
+        let mut cursor = Cursor::new(input);
+        let time_only = cursor.eat(b"Tt") || input.as_bytes().get(2) == Some(&b':');
+        let mut fixed = fixed_from_gregorian(1970, 1, 1);
+        let mut time = Time::default();
+        if time_only {
+            time = parse_time(&mut cursor)?;
+        } else {
+            fixed = parse_date(&mut cursor)?;
+            if cursor.eat(b"Tt ") {
+                time = parse_time(&mut cursor)?;
+            }
+        }
+        if cursor.peek().is_some() {
+            return Err(cursor.syntax_error());
+        }
+
+        // Week 1 of year 0 starts on January 3, so no date is before it.
+        let (year, month, day) = gregorian_from_fixed(fixed);
+        let date_time = DateTime::try_new(
+            year as usize,
+            month,
+            day,
+            time.hour,
+            time.minute,
+            time.second,
+        )
+        // The other fields were checked while parsing them.
+        .map_err(|_| IsoError::OutOfRange {
+            field: IsoField::Year,
+            offset: 0,
+        })?;
+        Ok(Self::new(date_time, time.nanosecond, time.offset))
+    }
+
+    /// Writes the date and time, as in `2019-10-29T10:23:05.25+02:00`.
+    pub fn format(&self, format: IsoFormat) -> String {
+        format!("{}T{}", self.format_date(format), self.format_time(format))
+    }
+
+    /// Writes the calendar date, as in `2019-10-29`.
+    pub fn format_date(&self, format: IsoFormat) -> String {
+        let dt = &self.date_time;
+        match format {
+            IsoFormat::Extended => format!(
+                "{}-{:02}-{:02}",
+                format_year(dt.year as i64),
+                dt.month,
+                dt.day
+            ),
+            IsoFormat::Basic => format!(
+                "{}{:02}{:02}",
+                format_year(dt.year as i64),
+                dt.month,
+                dt.day
+            ),
+        }
+    }
+
+    /// Writes the week date, as in `2019-W44-2`, whose year is the one the
+    /// Thursday of the week falls in.
+    pub fn format_week_date(&self, format: IsoFormat) -> String {
+        let fixed = self.date_time.fixed();
+        let weekday = (day_of_week(fixed) + 6) % 7;
+        let (year, _, _) = gregorian_from_fixed(fixed - weekday as i64 + 3);
+        let week = (fixed - iso_week_start(year)) / 7 + 1;
+        match format {
+            IsoFormat::Extended => format!("{}-W{:02}-{}", format_year(year), week, weekday + 1),
+            IsoFormat::Basic => format!("{}W{:02}{}", format_year(year), week, weekday + 1),
+        }
+    }
+
+    /// Writes the ordinal date, as in `2019-302`.
+    pub fn format_ordinal_date(&self, format: IsoFormat) -> String {
+        let year = self.date_time.year;
+        let day = self.date_time.fixed() - fixed_from_gregorian(year as i64, 1, 1) + 1;
+        match format {
+            IsoFormat::Extended => format!("{}-{:03}", format_year(year as i64), day),
+            IsoFormat::Basic => format!("{}{:03}", format_year(year as i64), day),
+        }
+    }
+
+    /// Writes the time with its fraction of a second, in as many groups of
+    /// three digits as it needs, and its offset, as in `10:23:05.25+02:00`.
+    pub fn format_time(&self, format: IsoFormat) -> String {
+        let dt = &self.date_time;
+        let mut result = match format {
+            IsoFormat::Extended => format!("{:02}:{:02}:{:02}", dt.hour, dt.minute, dt.second),
+            IsoFormat::Basic => format!("{:02}{:02}{:02}", dt.hour, dt.minute, dt.second),
+        };
+        if self.nanosecond > 0 {
+            let mut fraction = format!("{:09}", self.nanosecond);
+            while fraction.ends_with("000") {
+                fraction.truncate(fraction.len() - 3);
+            }
+            result.push('.');
+            result.push_str(&fraction);
+        }
+        match self.offset {
+            None => {}
+            Some(0) => result.push('Z'),
+            Some(offset) => {
+                let sign = if offset < 0 { '-' } else { '+' };
+                let (hours, minutes) = (offset.abs() / 60, offset.abs() % 60);
+                match format {
+                    IsoFormat::Extended => {
+                        result.push_str(&format!("{}{:02}:{:02}", sign, hours, minutes))
+                    }
+                    IsoFormat::Basic => {
+                        result.push_str(&format!("{}{:02}{:02}", sign, hours, minutes))
+                    }
+                }
+            }
+        }
+        result
+    }
+}
+
+/// Writes `year` with four digits, or in the expanded form with a `+` and
+/// six digits past 9999.
+fn format_year(year: i64) -> String {
+    if year > 9999 {
+        format!("+{:06}", year)
+    } else {
+        format!("{:04}", year)
+    }
+}
+
+/// The fixed day number of the Monday that starts week 1 of the ISO
+/// week-numbering `year`, the week with January 4.
+fn iso_week_start(year: i64) -> i64 {
+    let jan4 = fixed_from_gregorian(year, 1, 4);
+    jan4 - ((day_of_week(jan4) + 6) % 7) as i64
+}
+
+#[derive(Default)]
+struct Time {
+    hour: usize,
+    minute: usize,
+    second: usize,
+    nanosecond: u32,
+    offset: Option<i32>,
+}
+
+struct Cursor<'a> {
+    input: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(input: &'a str) -> Self {
+        Self {
+            input: input.as_bytes(),
+            offset: 0,
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.input.get(self.offset).copied()
+    }
+
+    /// Skips the next byte if it is one of `bytes`.
+    fn eat(&mut self, bytes: &[u8]) -> bool {
+        match self.peek() {
+            Some(b) if bytes.contains(&b) => {
+                self.offset += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), IsoError> {
+        if self.eat(&[byte]) {
+            Ok(())
+        } else {
+            Err(self.syntax_error())
+        }
+    }
+
+    fn digit_count(&self) -> usize {
+        self.input[self.offset..]
+            .iter()
+            .take_while(|b| b.is_ascii_digit())
+            .count()
+    }
+
+    /// Reads a number of exactly `len` digits, returning it with the offset
+    /// it starts at.
+    fn number(&mut self, len: usize) -> Result<(usize, usize), IsoError> {
+        if self.digit_count() < len {
+            return Err(self.syntax_error());
+        }
+        let start = self.offset;
+        self.offset += len;
+        let value = self.input[start..self.offset]
+            .iter()
+            .fold(0, |value, b| value * 10 + (b - b'0') as usize);
+        Ok((value, start))
+    }
+
+    fn syntax_error(&self) -> IsoError {
+        IsoError::Syntax {
+            offset: self.offset,
+        }
+    }
+}
+
+fn check(
+    value: usize,
+    min: usize,
+    max: usize,
+    field: IsoField,
+    offset: usize,
+) -> Result<(), IsoError> {
+    if value < min || value > max {
+        return Err(IsoError::OutOfRange { field, offset });
+    }
+    Ok(())
+}
+
+/// Reads a calendar, week or ordinal date, returning its fixed day number.
+fn parse_date(cursor: &mut Cursor) -> Result<i64, IsoError> {
+    let year = if cursor.eat(b"+") {
+        // Only separators tell where longer years end.
+        let mut len = cursor.digit_count();
+        if !matches!(
+            cursor.input.get(cursor.offset + len),
+            Some(b'-') | Some(b'W')
+        ) {
+            len = len.min(6);
+        }
+        if len <= 4 {
+            return Err(cursor.syntax_error());
+        }
+        let offset = cursor.offset;
+        let year_out_of_range = IsoError::OutOfRange {
+            field: IsoField::Year,
+            offset,
+        };
+        if len > 7 {
+            return Err(year_out_of_range);
+        }
+        let (year, _) = cursor.number(len)?;
+        if year > MAX_YEAR {
+            return Err(year_out_of_range);
+        }
+        year
+    } else {
+        cursor.number(4)?.0
+    };
+    let year = year as i64;
+    let extended = cursor.eat(b"-");
+    if cursor.eat(b"W") {
+        let (week, week_offset) = cursor.number(2)?;
+        if extended {
+            cursor.expect(b'-')?;
+        }
+        let (weekday, weekday_offset) = cursor.number(1)?;
+        let start = iso_week_start(year);
+        let weeks = (iso_week_start(year + 1) - start) / 7;
+        check(week, 1, weeks as usize, IsoField::Week, week_offset)?;
+        check(weekday, 1, 7, IsoField::WeekDay, weekday_offset)?;
+        return Ok(start + 7 * (week as i64 - 1) + weekday as i64 - 1);
+    }
+    match (extended, cursor.digit_count()) {
+        (_, 3) => {
+            let (day, day_offset) = cursor.number(3)?;
+            let days = if is_gregorian_leap_year(year) {
+                366
+            } else {
+                365
+            };
+            check(day, 1, days, IsoField::DayOfYear, day_offset)?;
+            Ok(fixed_from_gregorian(year, 1, 1) + day as i64 - 1)
+        }
+        (true, 2) | (false, 4) => {
+            let (month, month_offset) = cursor.number(2)?;
+            if extended {
+                cursor.expect(b'-')?;
+            }
+            let (day, day_offset) = cursor.number(2)?;
+            check(month, 1, 12, IsoField::Month, month_offset)?;
+            let days = days_in_gregorian_month(year, month);
+            check(day, 1, days, IsoField::Day, day_offset)?;
+            Ok(fixed_from_gregorian(year, month, day))
+        }
+        _ => Err(cursor.syntax_error()),
+    }
+}
+
+/// Reads a time with its optional fraction and offset.
+fn parse_time(cursor: &mut Cursor) -> Result<Time, IsoError> {
+    let mut time = Time::default();
+    let (hour, hour_offset) = cursor.number(2)?;
+    check(hour, 0, 23, IsoField::Hour, hour_offset)?;
+    time.hour = hour;
+    let extended = cursor.eat(b":");
+    if extended || cursor.digit_count() > 0 {
+        let (minute, minute_offset) = cursor.number(2)?;
+        check(minute, 0, 59, IsoField::Minute, minute_offset)?;
+        time.minute = minute;
+        let seconds = if extended {
+            cursor.eat(b":")
+        } else {
+            cursor.digit_count() > 0
+        };
+        if seconds {
+            let (second, second_offset) = cursor.number(2)?;
+            check(second, 0, 59, IsoField::Second, second_offset)?;
+            time.second = second;
+            if cursor.eat(b".,") {
+                let len = cursor.digit_count();
+                if len == 0 {
+                    return Err(cursor.syntax_error());
+                }
+                // Digits past nanoseconds are dropped.
+                let (fraction, _) = cursor.number(len.min(9))?;
+                time.nanosecond = (fraction * 10usize.pow(9 - len.min(9) as u32)) as u32;
+                cursor.offset += len.saturating_sub(9);
+            }
+        }
+    }
+
+    time.offset = match cursor.peek() {
+        Some(b'Z') | Some(b'z') => {
+            cursor.offset += 1;
+            Some(0)
+        }
+        Some(sign @ b'+') | Some(sign @ b'-') => {
+            cursor.offset += 1;
+            let (hours, offset) = cursor.number(2)?;
+            let minutes = if cursor.eat(b":") || cursor.digit_count() > 0 {
+                cursor.number(2)?.0
+            } else {
+                0
+            };
+            check(hours, 0, 23, IsoField::Offset, offset)?;
+            check(minutes, 0, 59, IsoField::Offset, offset)?;
+            let minutes = (hours * 60 + minutes) as i32;
+            Some(if sign == b'-' { -minutes } else { minutes })
+        }
+        _ => None,
+    };
+    Ok(time)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_iso_week_start() {
+        // 2021 starts on a Friday, 2026 on a Thursday.
+        assert_eq!(iso_week_start(2021), fixed_from_gregorian(2021, 1, 4));
+        assert_eq!(iso_week_start(2026), fixed_from_gregorian(2025, 12, 29));
+        assert_eq!(iso_week_start(2020), fixed_from_gregorian(2019, 12, 30));
+    }
+}
@@ -3,13 +3,34 @@
 // Unfortunately, we use this for data generation binary.
 pub mod data;
 
+pub mod calendar;
+mod input;
+mod iso;
+mod parse;
+mod timestamp;
+
+pub use input::{Date, DateTimeInput, FullDateTimeInput, MonthDay, Time, YearMonth};
+pub use iso::{IsoDateTime, IsoError, IsoField, IsoFormat};
+pub use parse::{DateTimeParser, ParseError};
+
+use calendar::CalendarSystem;
+use data::error::DataError;
 use data::layout;
-use data::layout::Resource;
+use data::layout::CalendarData;
+use data::provider::DataProvider;
 
 use std::borrow::Borrow;
 use std::borrow::Cow;
+use std::marker::PhantomData;
 
 /* DateTime */
+
+/// A proleptic Gregorian date and time.
+///
+/// Values made with `DateTime::new` are not checked, and formatting ones
+/// that do not exist, such as month 13, panics. `DateTime::try_new` checks
+/// them.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
 pub struct DateTime {
     pub year: usize,
     pub month: usize,
@@ -37,11 +58,166 @@ impl DateTime {
             second,
         }
     }
+
+    /// Creates a date and time, checking that it exists.
+    pub fn try_new(
+        year: usize,
+        month: usize,
+        day: usize,
+        hour: usize,
+        minute: usize,
+        second: usize,
+    ) -> Result<Self, DateTimeError> {
+        if year > MAX_YEAR {
+            return Err(YEAR_OUT_OF_RANGE);
+        }
+        let dt = Self::new(year, month, day, hour, minute, second);
+        check_fields(&dt)?;
+        Ok(dt)
+    }
+
+    /// The fixed day number of the date, as in `calendar`.
+    pub(crate) fn fixed(&self) -> i64 {
+        calendar::fixed_from_gregorian(self.year as i64, self.month, self.day)
+    }
+
+    fn seconds_of_day(&self) -> i64 {
+        (self.hour * 3600 + self.minute * 60 + self.second) as i64
+    }
+
+    /// The same time on the date of the fixed day number `fixed`.
+    fn with_fixed(&self, fixed: i64) -> Result<Self, DateTimeError> {
+        let (year, month, day) = calendar::gregorian_from_fixed(fixed);
+        if year < 0 || year > MAX_YEAR as i64 {
+            return Err(YEAR_OUT_OF_RANGE);
+        }
+        Ok(Self::new(
+            year as usize,
+            month,
+            day,
+            self.hour,
+            self.minute,
+            self.second,
+        ))
+    }
+
+    /// The day of the week, with 0 for Sunday.
+    pub fn day_of_week(&self) -> usize {
+        calendar::day_of_week(self.fixed())
+    }
+
+    /// The day of the year, from 1 for January 1.
+    pub fn day_of_year(&self) -> usize {
+        (self.fixed() - calendar::fixed_from_gregorian(self.year as i64, 1, 1)) as usize + 1
+    }
+
+    /// Adds `days`, which may be negative, keeping the time.
+    pub fn add_days(&self, days: i64) -> Result<Self, DateTimeError> {
+        let fixed = self.fixed().checked_add(days).ok_or(YEAR_OUT_OF_RANGE)?;
+        self.with_fixed(fixed)
+    }
+
+    /// Adds `months`, which may be negative, keeping the time. Days past
+    /// the end of the resulting month become its last day, so one month
+    /// after January 31 is February 28 or 29.
+    pub fn add_months(&self, months: i64) -> Result<Self, DateTimeError> {
+        let month = (self.year as i64 * 12 + self.month as i64 - 1)
+            .checked_add(months)
+            .ok_or(YEAR_OUT_OF_RANGE)?;
+        let year = month.div_euclid(12);
+        if year < 0 || year > MAX_YEAR as i64 {
+            return Err(YEAR_OUT_OF_RANGE);
+        }
+        let month = month.rem_euclid(12) as usize + 1;
+        let day = self.day.min(calendar::days_in_gregorian_month(year, month));
+        Ok(Self::new(
+            year as usize,
+            month,
+            day,
+            self.hour,
+            self.minute,
+            self.second,
+        ))
+    }
+
+    /// Adds `years`, which may be negative, as `12 * years` months, so
+    /// one year after February 29 is February 28.
+    pub fn add_years(&self, years: i64) -> Result<Self, DateTimeError> {
+        let months = years.checked_mul(12).ok_or(YEAR_OUT_OF_RANGE)?;
+        self.add_months(months)
+    }
+
+    /// The number of days from the date to the date of `other`, regardless
+    /// of their times.
+    pub fn days_until(&self, other: &DateTime) -> i64 {
+        other.fixed() - self.fixed()
+    }
+
+    /// The number of seconds from `self` to `other`.
+    pub fn seconds_until(&self, other: &DateTime) -> i64 {
+        (other.fixed() - self.fixed()) * 86_400 + other.seconds_of_day() - self.seconds_of_day()
+    }
+
+    /// The number of whole months from `self` to `other`: the most months
+    /// that can be added to `self`, as in `add_months`, without passing
+    /// `other`.
+    pub fn months_until(&self, other: &DateTime) -> i64 {
+        let mut months = (other.year as i64 * 12 + other.month as i64)
+            - (self.year as i64 * 12 + self.month as i64);
+        // The day and time can leave the last month incomplete.
+        if months > 0 && self.add_months(months).map_or(true, |dt| dt > *other) {
+            months -= 1;
+        } else if months < 0 && self.add_months(months).map_or(true, |dt| dt < *other) {
+            months += 1;
+        }
+        months
+    }
+}
+
+/// The last year a `DateTime` can have, which leaves room for the years of
+/// other calendars and for Unix milliseconds.
+const MAX_YEAR: usize = 1_000_000;
+
+const YEAR_OUT_OF_RANGE: DateTimeError = DateTimeError::OutOfRange {
+    field: DateTimeField::Year,
+};
+
+/// A field of a date and time.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum DateTimeField {
+    Year,
+    Month,
+    Day,
+    Hour,
+    Minute,
+    Second,
+    /// The time zone or UTC offset, which zone fields write.
+    Zone,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum DateTimeError {
+    /// `field` holds a value that does not exist, such as month 13 or a
+    /// year before 0.
+    OutOfRange { field: DateTimeField },
+    /// A pattern uses `field`, which the input it formats does not hold.
+    MissingField { field: DateTimeField },
+}
+
+impl std::fmt::Display for DateTimeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::OutOfRange { field } => write!(f, "{:?} out of range", field),
+            Self::MissingField { field } => write!(f, "the input has no {:?}", field),
+        }
+    }
 }
 
+impl std::error::Error for DateTimeError {}
+
 /* DateTimeFormat */
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, PartialEq, Debug)]
 pub enum DateStyle {
     FULL,
     LONG,
@@ -50,6 +226,8 @@ pub enum DateStyle {
 }
 
 impl DateStyle {
+    pub const ALL: [DateStyle; 4] = [Self::FULL, Self::LONG, Self::MEDIUM, Self::SHORT];
+
     pub fn idx(self) -> usize {
         match self {
             Self::FULL => 0,
@@ -60,7 +238,7 @@ impl DateStyle {
     }
 }
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, PartialEq, Debug)]
 pub enum TimeStyle {
     FULL,
     LONG,
@@ -69,6 +247,8 @@ pub enum TimeStyle {
 }
 
 impl TimeStyle {
+    pub const ALL: [TimeStyle; 4] = [Self::FULL, Self::LONG, Self::MEDIUM, Self::SHORT];
+
     pub fn idx(self) -> usize {
         match self {
             Self::FULL => 0,
@@ -80,133 +260,350 @@ impl TimeStyle {
 }
 
 pub struct DateTimeFormat<R> {
-    pattern: data::layout::DateTimePattern,
+    pattern: Cow<'static, [layout::PatternElement]>,
     calendar_data: R,
+    calendar: &'static dyn CalendarSystem,
 }
 
 #[cfg(not(feature = "no-static"))]
-impl DateTimeFormat<&'static Resource<'_>> {
+impl DateTimeFormat<&'static CalendarData<'static>> {
     pub fn new_from_static(
         locale: &str,
         date_style: Option<DateStyle>,
         time_style: Option<TimeStyle>,
-    ) -> Self {
-        let data = crate::data::generated::get(locale);
-        Self::new(locale, date_style, time_style, &data)
+    ) -> Result<Self, DataError> {
+        Self::new(
+            locale,
+            date_style,
+            time_style,
+            data::provider::StaticDataProvider,
+        )
     }
 }
 
-fn create_date_time_pattern<'l, R: Borrow<Resource<'l>>>(
-    pattern: &layout::DateTimePattern,
-    date_style: DateStyle,
-    time_style: TimeStyle,
-    resource: R,
+/// Checks that the month, day and time of `value` exist, leaving the year
+/// to the input.
+fn check_fields<I: DateTimeInput + ?Sized>(value: &I) -> Result<(), DateTimeError> {
+    let check = |value, min, max, field| {
+        if value < min || value > max {
+            Err(DateTimeError::OutOfRange { field })
+        } else {
+            Ok(())
+        }
+    };
+    let month = value.month();
+    check(month, 1, 12, DateTimeField::Month)?;
+    let days = calendar::days_in_gregorian_month(value.year(), month);
+    check(value.day(), 1, days, DateTimeField::Day)?;
+    check(value.hour(), 0, 23, DateTimeField::Hour)?;
+    check(value.minute(), 0, 59, DateTimeField::Minute)?;
+    check(value.second(), 0, 59, DateTimeField::Second)
+}
+
+/// Reads and parses the pattern of `formats` at `idx`.
+fn get_format(
+    formats: &layout::Formats,
+    calendar: &str,
+    key: &str,
+    idx: usize,
+) -> Result<Vec<layout::PatternElement>, DataError> {
+    let path = || format!("{}/{}/{}", calendar, key, layout::Formats::NAMES[idx]);
+    formats
+        .get(idx)
+        .ok_or_else(|| DataError::MissingData(path()))?
+        .to_parsed()
+        .map_err(|error| DataError::InvalidPattern {
+            path: path(),
+            error,
+        })
+}
+
+/// Ensures that every name list `pattern` formats with is present.
+fn check_names(
+    pattern: &[layout::PatternElement],
+    calendar: &str,
+    calendar_data: &CalendarData,
+) -> Result<(), DataError> {
+    for elem in pattern {
+        if let layout::PatternElement::Token(token) = elem {
+            if let Some((kind, length)) = token.names() {
+                if !calendar_data.has_names(kind, false, length) {
+                    return Err(DataError::MissingData(format!(
+                        "{}/{}",
+                        calendar,
+                        kind.path(false, length)
+                    )));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Substitutes `date` for `{1}` and `time` for `{0}` in `glue`.
+fn join_date_time_pattern(
+    glue: &[layout::PatternElement],
+    date: &[layout::PatternElement],
+    time: &[layout::PatternElement],
 ) -> Cow<'static, [layout::PatternElement]> {
-    let calendar_data = &resource
-        .borrow()
-        .get("pl")
-        .unwrap()
-        .dates
-        .calendars
-        .gregorian;
-    let mut pattern: Vec<data::layout::PatternElement> = pattern.to_parsed().to_vec();
+    let mut pattern: Vec<data::layout::PatternElement> = glue.to_vec();
 
     if let Some(idx) = pattern
         .iter()
         .position(|s| s == &data::layout::PatternElement::Token(data::layout::DateTimeToken::Sub1))
     {
-        pattern.splice(
-            idx..=idx,
-            calendar_data
-                .date_formats
-                .get(date_style.idx())
-                .unwrap()
-                .to_parsed(),
-        );
+        pattern.splice(idx..=idx, date.iter().cloned());
     }
     if let Some(idx) = pattern
         .iter()
         .position(|s| s == &data::layout::PatternElement::Token(data::layout::DateTimeToken::Sub0))
     {
-        pattern.splice(
-            idx..=idx,
-            calendar_data
-                .time_formats
-                .get(time_style.idx())
-                .unwrap()
-                .to_parsed(),
-        );
+        pattern.splice(idx..=idx, time.iter().cloned());
     }
     Cow::Owned(pattern)
 }
 
-impl<'l, R> DateTimeFormat<R> {
-    pub fn new(
-        _locale: &str,
+fn create_date_time_pattern(
+    pattern: &[layout::PatternElement],
+    date_style: DateStyle,
+    time_style: TimeStyle,
+    calendar: &str,
+    calendar_data: &CalendarData,
+) -> Result<Cow<'static, [layout::PatternElement]>, DataError> {
+    Ok(join_date_time_pattern(
+        pattern,
+        &get_format(
+            &calendar_data.date_formats,
+            calendar,
+            "dateFormats",
+            date_style.idx(),
+        )?,
+        &get_format(
+            &calendar_data.time_formats,
+            calendar,
+            "timeFormats",
+            time_style.idx(),
+        )?,
+    ))
+}
+
+/// Picks and parses the pattern of `calendar_data` for the styles, ensuring
+/// that the name lists it uses are present.
+fn select_pattern(
+    calendar: &str,
+    calendar_data: &CalendarData,
+    date_style: Option<DateStyle>,
+    time_style: Option<TimeStyle>,
+) -> Result<Cow<'static, [layout::PatternElement]>, DataError> {
+    let pattern = match (date_style, time_style) {
+        (Some(date_style), Some(time_style)) => {
+            let pattern = get_format(
+                &calendar_data.date_time_formats,
+                calendar,
+                "dateTimeFormats",
+                date_style.idx(),
+            )?;
+            create_date_time_pattern(&pattern, date_style, time_style, calendar, calendar_data)?
+        }
+        (Some(date_style), None) => get_format(
+            &calendar_data.date_formats,
+            calendar,
+            "dateFormats",
+            date_style.idx(),
+        )?
+        .into(),
+        (None, Some(time_style)) => get_format(
+            &calendar_data.time_formats,
+            calendar,
+            "timeFormats",
+            time_style.idx(),
+        )?
+        .into(),
+        (None, None) => return Err(DataError::NoStyle),
+    };
+    check_names(&pattern, calendar, calendar_data)?;
+    Ok(pattern)
+}
+
+/// The fields of the input that `token` is formatted from in `calendar`.
+fn token_fields(
+    token: &layout::DateTimeToken,
+    calendar: &dyn CalendarSystem,
+) -> &'static [DateTimeField] {
+    use layout::DateTimeToken as T;
+    const DATE: &[DateTimeField] = &[
+        DateTimeField::Year,
+        DateTimeField::Month,
+        DateTimeField::Day,
+    ];
+    match token {
+        T::Hour2digit | T::HourNumeric => &[DateTimeField::Hour],
+        T::Minute2digit | T::MinuteNumeric => &[DateTimeField::Minute],
+        T::Second2digit | T::SecondNumeric => &[DateTimeField::Second],
+        T::ZoneLong | T::ZoneShort => &[DateTimeField::Zone],
+        T::Sub0 | T::Sub1 => &[],
+        // Years and months of other calendars change within Gregorian
+        // months.
+        _ if !calendar.has_gregorian_months() => DATE,
+        T::DayNumeric | T::Day2digit => &[DateTimeField::Day],
+        T::MonthNumeric | T::Month2digit | T::MonthNameLong | T::MonthNameAbbreviated => {
+            &[DateTimeField::Month]
+        }
+        T::WeekDayWide => DATE,
+        _ => &[DateTimeField::Year],
+    }
+}
+
+impl<'l, R> DateTimeFormat<R>
+where
+    R: Borrow<CalendarData<'l>>,
+{
+    /// Creates a formatter for `locale`, showing Gregorian dates unless a
+    /// `-u-ca-` extension selects another calendar, as in
+    /// `"th-TH-u-ca-buddhist"`.
+    pub fn new<P>(
+        locale: &str,
         date_style: Option<DateStyle>,
         time_style: Option<TimeStyle>,
-        data: R,
-    ) -> Self
+        provider: P,
+    ) -> Result<Self, DataError>
     where
-        R: Borrow<Resource<'l>>,
+        P: DataProvider<'l, Calendar = R>,
     {
-        let calendar_data = &data.borrow().get("pl").unwrap().dates.calendars.gregorian;
-        let pattern = match (date_style, time_style) {
-            (Some(date_style), Some(time_style)) => {
-                let pattern = &calendar_data
-                    .date_time_formats
-                    .get(date_style.idx())
-                    .unwrap();
-                let pattern =
-                    create_date_time_pattern(pattern, date_style, time_style, data.borrow());
-                layout::DateTimePattern::Parsed(pattern)
-            }
-            (Some(date_style), None) => calendar_data
-                .date_formats
-                .get(date_style.idx())
-                .unwrap()
-                .clone(),
-            (None, Some(time_style)) => calendar_data
-                .time_formats
-                .get(time_style.idx())
-                .unwrap()
-                .clone(),
-            (None, None) => panic!(),
-        };
-        Self {
-            pattern,
-            calendar_data: data,
-        }
+        let (locale, calendar) = calendar::split_locale(locale);
+        let calendar = calendar.as_deref().unwrap_or("gregorian");
+        Self::new_with_calendar(&locale, calendar, date_style, time_style, provider)
     }
 
-    pub fn format(&self, value: &DateTime) -> String
+    /// Creates a formatter that shows dates in `calendar`, identified as in
+    /// CLDR (for example `"gregorian"`), with that calendar's data.
+    ///
+    /// Variants of a calendar share its data, so `"islamic-civil"` reads
+    /// `"islamic"` from `provider`.
+    pub fn new_with_calendar<P>(
+        locale: &str,
+        calendar: &str,
+        date_style: Option<DateStyle>,
+        time_style: Option<TimeStyle>,
+        provider: P,
+    ) -> Result<Self, DataError>
     where
-        R: Borrow<Resource<'l>>,
+        P: DataProvider<'l, Calendar = R>,
     {
+        let system = calendar::get(calendar)
+            .ok_or_else(|| DataError::MissingCalendar(calendar.to_string()))?;
+        let data = provider.get_calendar_data(locale, system.data_name())?;
+        let pattern = select_pattern(system.data_name(), data.borrow(), date_style, time_style)?;
+        Ok(Self {
+            pattern,
+            calendar_data: data,
+            calendar: system,
+        })
+    }
+
+    /// Formats `value` with the pattern. Inputs without some of the fields,
+    /// such as `Date`, are formatted with `for_input`.
+    ///
+    /// # Panics
+    ///
+    /// If `value` holds a month, day or time that does not exist, such as
+    /// month 13.
+    pub fn format<I: FullDateTimeInput + ?Sized>(&self, value: &I) -> String {
+        self.format_input(value)
+    }
+
+    fn format_input<I: DateTimeInput + ?Sized>(&self, value: &I) -> String {
+        if let Err(err) = check_fields(value) {
+            panic!("cannot format {}", err);
+        }
         let mut result = String::new();
         self.calendar_data
             .borrow()
-            .format_pattern(&mut result, self.pattern.borrow(), value)
+            .format_elements(&mut result, &self.pattern, value, self.calendar)
             .unwrap();
         result
     }
+
+    /// Makes the formatter format inputs of type `I`, such as `Date` or
+    /// `YearMonth`, after checking that they hold every field the pattern
+    /// writes.
+    pub fn for_input<I: DateTimeInput>(self) -> Result<TypedDateTimeFormat<R, I>, DateTimeError> {
+        for elem in self.pattern.iter() {
+            if let layout::PatternElement::Token(token) = elem {
+                for &field in token_fields(token, self.calendar) {
+                    if !I::provides(field) {
+                        return Err(DateTimeError::MissingField { field });
+                    }
+                }
+            }
+        }
+        Ok(TypedDateTimeFormat {
+            format: self,
+            input: PhantomData,
+        })
+    }
+}
+
+/// A `DateTimeFormat` for inputs of type `I`, made with
+/// `DateTimeFormat::for_input`.
+pub struct TypedDateTimeFormat<R, I> {
+    format: DateTimeFormat<R>,
+    input: PhantomData<fn(&I)>,
+}
+
+impl<'l, R, I> TypedDateTimeFormat<R, I>
+where
+    R: Borrow<CalendarData<'l>>,
+    I: DateTimeInput,
+{
+    /// Formats `value` with the pattern, panicking as
+    /// `DateTimeFormat::format`.
+    pub fn format(&self, value: &I) -> String {
+        self.format.format_input(value)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[cfg(not(feature = "no-static"))]
     #[test]
     fn it_works() {
         let dt = DateTime::new(2019, 10, 29, 10, 23, 5);
-        let dtf = DateTimeFormat::new_from_static("pl", Some(DateStyle::LONG), None);
+        let dtf = DateTimeFormat::new_from_static("pl", Some(DateStyle::LONG), None).unwrap();
         assert_eq!(dtf.format(&dt), "29 października 2019");
 
-        let dtf = DateTimeFormat::new_from_static("pl", Some(DateStyle::SHORT), None);
+        let dtf = DateTimeFormat::new_from_static("pl", Some(DateStyle::SHORT), None).unwrap();
         assert_eq!(dtf.format(&dt), "29.10.2019");
 
         let dtf =
-            DateTimeFormat::new_from_static("pl", Some(DateStyle::MEDIUM), Some(TimeStyle::MEDIUM));
+            DateTimeFormat::new_from_static("pl", Some(DateStyle::MEDIUM), Some(TimeStyle::MEDIUM))
+                .unwrap();
         assert_eq!(dtf.format(&dt), "29 paź 2019, 10:23:05");
     }
+
+    #[test]
+    fn test_token_fields() {
+        use layout::DateTimeToken as T;
+        let month = [DateTimeField::Month];
+        assert_eq!(token_fields(&T::MonthNameLong, &calendar::Gregorian), month);
+        assert_eq!(token_fields(&T::MonthNumeric, &calendar::Roc), month);
+        assert_eq!(
+            token_fields(&T::EraWide, &calendar::Buddhist),
+            [DateTimeField::Year]
+        );
+        // Japanese eras and Hebrew months start within Gregorian months.
+        let date = [
+            DateTimeField::Year,
+            DateTimeField::Month,
+            DateTimeField::Day,
+        ];
+        assert_eq!(token_fields(&T::EraWide, &calendar::Japanese), date);
+        assert_eq!(token_fields(&T::MonthNameLong, &calendar::Hebrew), date);
+        assert_eq!(
+            token_fields(&T::Hour2digit, &calendar::Hebrew),
+            [DateTimeField::Hour]
+        );
+    }
 }
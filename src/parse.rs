@@ -0,0 +1,646 @@
+//! Parsing of dates written with the same patterns they are formatted with.
+use crate::calendar::{
+    self, day_of_week, fixed_from_gregorian, gregorian_from_fixed, CalendarDate, CalendarSystem,
+};
+use crate::data::error::DataError;
+use crate::data::layout::{CalendarData, DateTimeToken, NamesLength, PatternElement};
+use crate::data::provider::DataProvider;
+use crate::{select_pattern, DateStyle, DateTime, TimeStyle};
+use std::borrow::{Borrow, Cow};
+use std::convert::TryFrom;
+use std::fmt;
+
+/// An error parsing a date, with the byte offset in the input at which the
+/// input stopped matching the pattern.
+#[derive(Debug, PartialEq)]
+pub enum ParseError {
+    /// The input does not continue with the literal text of the pattern.
+    Literal { expected: String, offset: usize },
+    /// The input has no number where the pattern has the numeric `field`.
+    Number { field: DateTimeToken, offset: usize },
+    /// The input has no known name where the pattern has the name `field`.
+    Name { field: DateTimeToken, offset: usize },
+    /// `field` holds a value that does not exist, such as month 13, or a
+    /// weekday that is not the weekday of the date.
+    OutOfRange { field: DateTimeToken, offset: usize },
+    /// `field`, such as a time zone, cannot be parsed.
+    UnsupportedField { field: DateTimeToken, offset: usize },
+    /// The input goes on after the end of the pattern.
+    TrailingInput { offset: usize },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Literal { expected, offset } => {
+                write!(f, "expected {:?} at {}", expected, offset)
+            }
+            Self::Number { field, offset } => {
+                write!(
+                    f,
+                    "expected a number for {} at {}",
+                    field.get_name(),
+                    offset
+                )
+            }
+            Self::Name { field, offset } => {
+                write!(f, "expected a name for {} at {}", field.get_name(), offset)
+            }
+            Self::OutOfRange { field, offset } => {
+                write!(f, "{} out of range at {}", field.get_name(), offset)
+            }
+            Self::UnsupportedField { field, offset } => {
+                write!(f, "cannot parse {} at {}", field.get_name(), offset)
+            }
+            Self::TrailingInput { offset } => write!(f, "unexpected input at {}", offset),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// The Gregorian year that two-digit years are placed around unless
+/// `DateTimeParser::two_digit_year_pivot` sets another.
+const TWO_DIGIT_YEAR_PIVOT: i64 = 2000;
+
+/// Parses dates written in a pattern of the CLDR data, such as the ones
+/// `DateTimeFormat` writes.
+///
+/// Names are matched regardless of case, in any of the widths and contexts
+/// of the data. Fields missing from the pattern are taken from January 1,
+/// 1970, 00:00:00, as in ICU. Leap months marked by a pattern, as in the
+/// Chinese calendar, are not recognized.
+///
+/// The input has to follow the pattern exactly, unless the parser is made
+/// lenient with `DateTimeParser::lenient`.
+pub struct DateTimeParser<R> {
+    pattern: Cow<'static, [PatternElement]>,
+    calendar_data: R,
+    calendar: &'static dyn CalendarSystem,
+    lenient: bool,
+    two_digit_year_pivot: i64,
+}
+
+#[cfg(not(feature = "no-static"))]
+impl DateTimeParser<&'static CalendarData<'static>> {
+    pub fn new_from_static(
+        locale: &str,
+        date_style: Option<DateStyle>,
+        time_style: Option<TimeStyle>,
+    ) -> Result<Self, DataError> {
+        Self::new(
+            locale,
+            date_style,
+            time_style,
+            crate::data::provider::StaticDataProvider,
+        )
+    }
+}
+
+/// A value read from the input, with the token it was read for and the
+/// offset it was read at.
+struct Field {
+    value: usize,
+    token: DateTimeToken,
+    offset: usize,
+}
+
+impl Field {
+    fn out_of_range(&self) -> ParseError {
+        ParseError::OutOfRange {
+            field: self.token.clone(),
+            offset: self.offset,
+        }
+    }
+}
+
+/// The values read from the input.
+#[derive(Default)]
+struct Fields {
+    era: Option<Field>,
+    year: Option<Field>,
+    /// Either the number written for the month, as in
+    /// `CalendarSystem::numeric_month`, or the index of its name.
+    month: Option<Field>,
+    day: Option<Field>,
+    weekday: Option<Field>,
+    hour: Option<Field>,
+    minute: Option<Field>,
+    second: Option<Field>,
+    /// Whether the year was written with two digits, leaving the century
+    /// to the pivot.
+    two_digit_year: bool,
+}
+
+impl<'l, R> DateTimeParser<R>
+where
+    R: Borrow<CalendarData<'l>>,
+{
+    /// Creates a parser for `locale`, reading Gregorian dates unless a
+    /// `-u-ca-` extension selects another calendar.
+    pub fn new<P>(
+        locale: &str,
+        date_style: Option<DateStyle>,
+        time_style: Option<TimeStyle>,
+        provider: P,
+    ) -> Result<Self, DataError>
+    where
+        P: DataProvider<'l, Calendar = R>,
+    {
+        let (locale, calendar) = calendar::split_locale(locale);
+        let calendar = calendar.as_deref().unwrap_or("gregorian");
+        Self::new_with_calendar(&locale, calendar, date_style, time_style, provider)
+    }
+
+    /// Creates a parser for dates of `calendar`, identified as in CLDR.
+    pub fn new_with_calendar<P>(
+        locale: &str,
+        calendar: &str,
+        date_style: Option<DateStyle>,
+        time_style: Option<TimeStyle>,
+        provider: P,
+    ) -> Result<Self, DataError>
+    where
+        P: DataProvider<'l, Calendar = R>,
+    {
+        let system = calendar::get(calendar)
+            .ok_or_else(|| DataError::MissingCalendar(calendar.to_string()))?;
+        let data = provider.get_calendar_data(locale, system.data_name())?;
+        let pattern = select_pattern(system.data_name(), data.borrow(), date_style, time_style)?;
+        Ok(Self {
+            pattern,
+            calendar_data: data,
+            calendar: system,
+            lenient: false,
+            two_digit_year_pivot: TWO_DIGIT_YEAR_PIVOT,
+        })
+    }
+
+    /// Makes the parser accept input that is not written exactly as the
+    /// pattern, as typed by people:
+    ///
+    /// * names match without diacritics of Latin letters, so `"29 paz 2019"`
+    ///   reads as `"29 paź 2019"`;
+    /// * any whitespace, such as U+202F NARROW NO-BREAK SPACE, matches any
+    ///   run of whitespace, which may also come before and after fields;
+    /// * a separator, such as `.` or `-`, matches any separator, or
+    ///   whitespace in its place;
+    /// * two-digit fields accept a single digit, two-digit years accept a
+    ///   full year, and numeric years written with two digits are placed
+    ///   around the pivot.
+    pub fn lenient(mut self, lenient: bool) -> Self {
+        self.lenient = lenient;
+        self
+    }
+
+    /// Places two-digit years in the 100 years starting 50 years before the
+    /// Gregorian year `pivot`, which is 2000 unless set.
+    pub fn two_digit_year_pivot(mut self, pivot: i64) -> Self {
+        self.two_digit_year_pivot = pivot;
+        self
+    }
+
+    /// Parses `input`, which has to match the whole pattern.
+    pub fn parse(&self, input: &str) -> Result<DateTime, ParseError> {
+        let data = self.calendar_data.borrow();
+        let pattern = &self.pattern;
+        let mut fields = Fields::default();
+        let mut offset = 0;
+        for (idx, elem) in pattern.iter().enumerate() {
+            let token = match elem {
+                PatternElement::Literal(literal) => {
+                    let rest = &input[offset..];
+                    let len = if self.lenient {
+                        match_literal_lenient(rest, literal)
+                    } else if rest.starts_with(literal.as_ref()) {
+                        Some(literal.len())
+                    } else {
+                        None
+                    };
+                    offset += len.ok_or_else(|| ParseError::Literal {
+                        expected: literal.to_string(),
+                        offset,
+                    })?;
+                    continue;
+                }
+                PatternElement::Token(token) => token,
+            };
+            if self.lenient {
+                offset += leading_whitespace(&input[offset..]);
+            }
+            let rest = &input[offset..];
+            let number = |min_digits, max_digits| {
+                parse_number(rest, min_digits, max_digits).ok_or_else(|| ParseError::Number {
+                    field: token.clone(),
+                    offset,
+                })
+            };
+            let name = |names: Vec<(&str, usize)>| {
+                match_names(rest, &names, self.lenient).ok_or_else(|| ParseError::Name {
+                    field: token.clone(),
+                    offset,
+                })
+            };
+            let two_digits = || number(if self.lenient { 1 } else { 2 }, 2);
+            let start = offset;
+            let (value, len) = match token {
+                DateTimeToken::DayNumeric => number(1, 9)?,
+                DateTimeToken::Day2digit => two_digits()?,
+                DateTimeToken::MonthNumeric => number(1, 9)?,
+                DateTimeToken::Month2digit => two_digits()?,
+                DateTimeToken::MonthNameLong | DateTimeToken::MonthNameAbbreviated => {
+                    name(month_names(data))?
+                }
+                DateTimeToken::WeekDayWide => name(day_names(data))?,
+                DateTimeToken::YearNumeric => {
+                    let suffix = match pattern.get(idx + 1) {
+                        Some(PatternElement::Literal(s)) => s.as_ref(),
+                        _ => "",
+                    };
+                    match self.calendar.year_name(1, suffix) {
+                        Some(year_name) if rest.starts_with(year_name) => (1, year_name.len()),
+                        _ => number(1, 9)?,
+                    }
+                }
+                DateTimeToken::Year2digit if self.lenient => number(1, 9)?,
+                DateTimeToken::Year2digit => number(2, 2)?,
+                DateTimeToken::EraAbbreviated
+                | DateTimeToken::EraWide
+                | DateTimeToken::EraNarrow => name(era_names(data))?,
+                DateTimeToken::Hour2digit
+                | DateTimeToken::Minute2digit
+                | DateTimeToken::Second2digit => two_digits()?,
+                DateTimeToken::HourNumeric
+                | DateTimeToken::MinuteNumeric
+                | DateTimeToken::SecondNumeric => number(1, 9)?,
+                _ => {
+                    return Err(ParseError::UnsupportedField {
+                        field: token.clone(),
+                        offset,
+                    })
+                }
+            };
+            offset += len;
+
+            let field = Some(Field {
+                value,
+                token: token.clone(),
+                offset: start,
+            });
+            match token {
+                DateTimeToken::DayNumeric | DateTimeToken::Day2digit => fields.day = field,
+                DateTimeToken::MonthNumeric
+                | DateTimeToken::Month2digit
+                | DateTimeToken::MonthNameLong
+                | DateTimeToken::MonthNameAbbreviated => fields.month = field,
+                DateTimeToken::WeekDayWide => fields.weekday = field,
+                DateTimeToken::YearNumeric | DateTimeToken::Year2digit => {
+                    fields.two_digit_year = match token {
+                        DateTimeToken::Year2digit => len <= 2,
+                        _ => self.lenient && len == 2,
+                    };
+                    fields.year = field;
+                }
+                DateTimeToken::Hour2digit | DateTimeToken::HourNumeric => fields.hour = field,
+                DateTimeToken::Minute2digit | DateTimeToken::MinuteNumeric => fields.minute = field,
+                DateTimeToken::Second2digit | DateTimeToken::SecondNumeric => fields.second = field,
+                _ => fields.era = field,
+            }
+        }
+        if self.lenient {
+            offset += leading_whitespace(&input[offset..]);
+        }
+        if offset < input.len() {
+            return Err(ParseError::TrailingInput { offset });
+        }
+        self.resolve(&fields)
+    }
+
+    /// Turns the fields read into a Gregorian date and time.
+    fn resolve(&self, fields: &Fields) -> Result<DateTime, ParseError> {
+        let calendar = self.calendar;
+        let default = calendar.date_from_fixed(fixed_from_gregorian(1970, 1, 1));
+        let era = fields.era.as_ref().map_or(default.era, |era| era.value);
+        let year = match &fields.year {
+            Some(year) if fields.two_digit_year => {
+                let pivot =
+                    calendar.date_from_fixed(fixed_from_gregorian(self.two_digit_year_pivot, 1, 1));
+                let first = pivot.year - 50;
+                first + (year.value as i32 - first).rem_euclid(100)
+            }
+            Some(year) => year.value as i32,
+            None => default.year,
+        };
+
+        // Find the month among those of the year, since calendars with
+        // leap months number them in different ways.
+        let mut start = calendar.fixed_from_date(&CalendarDate::new(era, year, 1, 1));
+        let mut ordinal_month = 1;
+        if let Some(month) = &fields.month {
+            loop {
+                if ordinal_month > calendar.months_in_year(era, year) {
+                    return Err(month.out_of_range());
+                }
+                let date = calendar.date_from_fixed(start);
+                let found = if month.token.names().is_some() {
+                    date.month == month.value + 1
+                } else {
+                    calendar.numeric_month(&date) == month.value
+                };
+                if found && !date.leap_month {
+                    break;
+                }
+                start += calendar.days_in_month(era, year, ordinal_month) as i64;
+                ordinal_month += 1;
+            }
+        }
+
+        let day = match &fields.day {
+            Some(day) => {
+                if day.value == 0 || day.value > calendar.days_in_month(era, year, ordinal_month) {
+                    return Err(day.out_of_range());
+                }
+                day.value
+            }
+            None => 1,
+        };
+        let fixed = start + day as i64 - 1;
+        let year_out_of_range = || match &fields.year {
+            Some(year) => year.out_of_range(),
+            None => ParseError::OutOfRange {
+                field: DateTimeToken::YearNumeric,
+                offset: 0,
+            },
+        };
+        // Years outside of the era, such as year 0 AD, land in another one.
+        let date = calendar.date_from_fixed(fixed);
+        if (date.era, date.year) != (era, year) {
+            return Err(year_out_of_range());
+        }
+        if let Some(weekday) = &fields.weekday {
+            if weekday.value != day_of_week(fixed) {
+                return Err(weekday.out_of_range());
+            }
+        }
+
+        let time_field = |field: &Option<Field>, max| match field {
+            Some(field) if field.value > max => Err(field.out_of_range()),
+            Some(field) => Ok(field.value),
+            None => Ok(0),
+        };
+        let hour = time_field(&fields.hour, 23)?;
+        let minute = time_field(&fields.minute, 59)?;
+        let second = time_field(&fields.second, 59)?;
+
+        // The other fields were checked above, leaving only the year out of
+        // range of `DateTime`.
+        let (year, month, day) = gregorian_from_fixed(fixed);
+        let year = usize::try_from(year).map_err(|_| year_out_of_range())?;
+        DateTime::try_new(year, month, day, hour, minute, second).map_err(|_| year_out_of_range())
+    }
+}
+
+/// Reads a number of `min_digits` to `max_digits` ASCII digits from the
+/// start of `input`, returning it with its length.
+fn parse_number(input: &str, min_digits: usize, max_digits: usize) -> Option<(usize, usize)> {
+    let len = input
+        .bytes()
+        .take(max_digits)
+        .take_while(u8::is_ascii_digit)
+        .count();
+    if len < min_digits {
+        return None;
+    }
+    Some((input[..len].parse().ok()?, len))
+}
+
+/// The length of the leading whitespace of `input`.
+fn leading_whitespace(input: &str) -> usize {
+    input.len() - input.trim_start().len()
+}
+
+fn is_separator(c: char) -> bool {
+    c.is_ascii_punctuation() || ('\u{2010}'..='\u{2015}').contains(&c)
+}
+
+fn is_combining_mark(c: char) -> bool {
+    ('\u{300}'..='\u{36f}').contains(&c)
+}
+
+/// The base letter of a lowercase Latin letter with diacritics.
+fn strip_diacritics(c: char) -> char {
+    match c {
+        'à'..='å' | 'ā' | 'ă' | 'ą' => 'a',
+        'ç' | 'ć' | 'ĉ' | 'ċ' | 'č' => 'c',
+        'ď' | 'đ' => 'd',
+        'è'..='ë' | 'ē' | 'ĕ' | 'ė' | 'ę' | 'ě' => 'e',
+        'ĝ' | 'ğ' | 'ġ' | 'ģ' => 'g',
+        'ĥ' | 'ħ' => 'h',
+        'ì'..='ï' | 'ĩ' | 'ī' | 'ĭ' | 'į' | 'ı' => 'i',
+        'ĵ' => 'j',
+        'ķ' => 'k',
+        'ĺ' | 'ļ' | 'ľ' | 'ŀ' | 'ł' => 'l',
+        'ñ' | 'ń' | 'ņ' | 'ň' => 'n',
+        'ò'..='ö' | 'ø' | 'ō' | 'ŏ' | 'ő' => 'o',
+        'ŕ' | 'ŗ' | 'ř' => 'r',
+        'ś' | 'ŝ' | 'ş' | 'š' | 'ș' => 's',
+        'ţ' | 'ť' | 'ŧ' | 'ț' => 't',
+        'ù'..='ü' | 'ũ' | 'ū' | 'ŭ' | 'ů' | 'ű' | 'ų' => 'u',
+        'ŵ' => 'w',
+        'ý' | 'ÿ' | 'ŷ' => 'y',
+        'ź' | 'ż' | 'ž' => 'z',
+        c => c,
+    }
+}
+
+/// The lowercase characters of `s`, with the offset each one ends at.
+/// Folding drops diacritics as well.
+fn lowercase_chars(s: &str, fold: bool) -> impl Iterator<Item = (char, usize)> + '_ {
+    s.char_indices().flat_map(move |(idx, c)| {
+        let end = idx + c.len_utf8();
+        c.to_lowercase()
+            .filter(move |&c| !(fold && is_combining_mark(c)))
+            .map(move |c| (if fold { strip_diacritics(c) } else { c }, end))
+    })
+}
+
+/// The length of the prefix of `input` that is `name` regardless of case,
+/// and of diacritics if `fold` is set.
+fn match_name(input: &str, name: &str, fold: bool) -> Option<usize> {
+    if name.is_empty() {
+        return None;
+    }
+    let mut chars = lowercase_chars(input, fold);
+    let mut end = 0;
+    for (expected, _) in lowercase_chars(name, fold) {
+        let (c, c_end) = chars.next()?;
+        if c != expected {
+            return None;
+        }
+        end = c_end;
+    }
+    if fold {
+        // Marks combining with the last letter belong to the name.
+        end += input[end..]
+            .chars()
+            .take_while(|&c| is_combining_mark(c))
+            .map(char::len_utf8)
+            .sum::<usize>();
+    }
+    Some(end)
+}
+
+/// The length of the prefix of `input` that leniently matches `literal`,
+/// as described for `DateTimeParser::lenient`.
+fn match_literal_lenient(input: &str, literal: &str) -> Option<usize> {
+    let mut offset = 0;
+    let mut spaced = false;
+    for expected in literal.chars() {
+        let spaces = leading_whitespace(&input[offset..]);
+        offset += spaces;
+        spaced |= spaces > 0;
+        if expected.is_whitespace() {
+            continue;
+        }
+        let rest = &input[offset..];
+        if is_separator(expected) {
+            match rest.chars().next() {
+                Some(c) if is_separator(c) => offset += c.len_utf8(),
+                _ if spaced => {}
+                _ => return None,
+            }
+        } else {
+            offset += match_name(rest, expected.encode_utf8(&mut [0; 4]), true)?;
+        }
+        spaced = false;
+    }
+    Some(offset + leading_whitespace(&input[offset..]))
+}
+
+/// Finds the longest of `names` at the start of `input`, returning its
+/// value and length. Names of different values that match equally far,
+/// such as narrow names, are ambiguous and match nothing.
+fn match_names(input: &str, names: &[(&str, usize)], fold: bool) -> Option<(usize, usize)> {
+    let mut best: Option<(usize, usize)> = None;
+    let mut ambiguous = false;
+    for &(name, value) in names {
+        if let Some(len) = match_name(input, name, fold) {
+            match best {
+                Some((best_value, best_len)) if len == best_len => {
+                    ambiguous |= value != best_value;
+                }
+                Some((_, best_len)) if len < best_len => {}
+                _ => {
+                    best = Some((value, len));
+                    ambiguous = false;
+                }
+            }
+        }
+    }
+    if ambiguous {
+        None
+    } else {
+        best
+    }
+}
+
+/// Month names of every width and context, with the index of the month.
+fn month_names<'a>(data: &'a CalendarData) -> Vec<(&'a str, usize)> {
+    let mut names = vec![];
+    for &stand_alone in &[false, true] {
+        for &length in &NamesLength::ALL {
+            if let Some(list) = data.months.get_list(stand_alone, length) {
+                names.extend(
+                    list.names
+                        .iter()
+                        .enumerate()
+                        .map(|(idx, name)| (&name[..], idx)),
+                );
+                names.extend(list.leap_names.iter().map(|(idx, name)| (&name[..], *idx)));
+            }
+        }
+    }
+    names
+}
+
+/// Weekday names of every width and context, with 0 for Sunday.
+fn day_names<'a>(data: &'a CalendarData) -> Vec<(&'a str, usize)> {
+    let mut names = vec![];
+    for &stand_alone in &[false, true] {
+        for &length in &NamesLength::ALL {
+            if let Some(list) = data.days.get_list(stand_alone, length) {
+                names.extend(
+                    list.0
+                        .iter()
+                        .enumerate()
+                        .map(|(idx, name)| (&name[..], idx)),
+                );
+            }
+        }
+    }
+    names
+}
+
+/// Era names of every width, with the index of the era.
+fn era_names<'a>(data: &'a CalendarData) -> Vec<(&'a str, usize)> {
+    let mut names = vec![];
+    if let Some(eras) = &data.eras {
+        for &length in &NamesLength::ALL {
+            if let Some(list) = eras.get_list(length) {
+                names.extend(
+                    list.0
+                        .iter()
+                        .enumerate()
+                        .map(|(idx, name)| (&name[..], idx)),
+                );
+            }
+        }
+    }
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_match_names() {
+        let names = [
+            ("lipiec", 6),
+            ("lip", 6),
+            ("l", 6),
+            ("listopad", 10),
+            ("l", 10),
+        ];
+        assert_eq!(match_names("Lipca", &names, false), Some((6, 3)));
+        assert_eq!(match_names("LISTOPAD 2019", &names, false), Some((10, 8)));
+        assert_eq!(match_names("l 2019", &names, false), None);
+        assert_eq!(match_names("maj", &names, false), None);
+        assert_eq!(match_name("ÉTÉ", "été", false), Some("ÉTÉ".len()));
+        assert_eq!(match_name("paz", "paź", false), None);
+        assert_eq!(match_name("PAZ 2019", "paź", true), Some(3));
+        assert_eq!(match_name("paz\u{301} 2019", "paź", true), Some(5));
+        assert_eq!(match_name("Łódź", "lodz", true), Some("Łódź".len()));
+    }
+
+    #[test]
+    fn test_match_literal_lenient() {
+        assert_eq!(match_literal_lenient("\u{202f}PM", " "), Some(3));
+        assert_eq!(match_literal_lenient("  \t10", " "), Some(3));
+        assert_eq!(match_literal_lenient("10", " "), Some(0));
+        assert_eq!(match_literal_lenient(" .10", "."), Some(2));
+        assert_eq!(match_literal_lenient("-10", "."), Some(1));
+        assert_eq!(match_literal_lenient(" 10", "."), Some(1));
+        assert_eq!(match_literal_lenient("10", "."), None);
+        assert_eq!(match_literal_lenient("  R. 10", " r."), Some(5));
+        assert_eq!(match_literal_lenient("de 10", " r."), None);
+    }
+
+    #[test]
+    fn test_parse_number() {
+        assert_eq!(parse_number("2019-10", 1, 9), Some((2019, 4)));
+        assert_eq!(parse_number("7.", 2, 2), None);
+        assert_eq!(parse_number("071", 2, 2), Some((7, 2)));
+        assert_eq!(parse_number("x", 1, 9), None);
+    }
+}
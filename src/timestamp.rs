@@ -0,0 +1,83 @@
+//! Conversions between `DateTime` and Unix time.
+//!
+//! Offsets are in minutes east of UTC, as in `IsoDateTime`: a `DateTime`
+//! is the local time at that offset.
+use crate::{DateTime, DateTimeError, YEAR_OUT_OF_RANGE};
+use std::convert::TryFrom;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// The fixed day number of January 1, 1970.
+const UNIX_EPOCH_DAY: i64 = 719_163;
+
+impl DateTime {
+    /// The local time at `offset` of `seconds` since 1970-01-01T00:00:00Z.
+    pub fn from_unix_seconds(seconds: i64, offset: i32) -> Result<Self, DateTimeError> {
+        let seconds = seconds
+            .checked_add(offset as i64 * 60)
+            .ok_or(YEAR_OUT_OF_RANGE)?;
+        let time = seconds.rem_euclid(86_400) as usize;
+        DateTime::new(1970, 1, 1, time / 3600, time / 60 % 60, time % 60)
+            .with_fixed(UNIX_EPOCH_DAY + seconds.div_euclid(86_400))
+    }
+
+    /// As `from_unix_seconds`, dropping the fraction of the second.
+    pub fn from_unix_millis(millis: i64, offset: i32) -> Result<Self, DateTimeError> {
+        Self::from_unix_seconds(millis.div_euclid(1_000), offset)
+    }
+
+    /// As `from_unix_seconds`, dropping the fraction of the second.
+    pub fn from_unix_nanos(nanos: i128, offset: i32) -> Result<Self, DateTimeError> {
+        let seconds =
+            i64::try_from(nanos.div_euclid(1_000_000_000)).map_err(|_| YEAR_OUT_OF_RANGE)?;
+        Self::from_unix_seconds(seconds, offset)
+    }
+
+    /// The local time at `offset` of `time`, dropping the fraction of the
+    /// second.
+    pub fn from_system_time(time: SystemTime, offset: i32) -> Result<Self, DateTimeError> {
+        let nanos = match time.duration_since(UNIX_EPOCH) {
+            Ok(duration) => duration.as_nanos() as i128,
+            Err(err) => -(err.duration().as_nanos() as i128),
+        };
+        Self::from_unix_nanos(nanos, offset)
+    }
+
+    /// The current local time at `offset`.
+    pub fn now(offset: i32) -> Self {
+        Self::from_system_time(SystemTime::now(), offset)
+            .expect("the system clock is set to a supported year")
+    }
+
+    /// The seconds since 1970-01-01T00:00:00Z of `self` as a local time
+    /// at `offset`.
+    pub fn to_unix_seconds(&self, offset: i32) -> i64 {
+        (self.fixed() - UNIX_EPOCH_DAY) * 86_400 + self.seconds_of_day() - offset as i64 * 60
+    }
+
+    pub fn to_unix_millis(&self, offset: i32) -> i64 {
+        self.to_unix_seconds(offset) * 1_000
+    }
+
+    pub fn to_unix_nanos(&self, offset: i32) -> i128 {
+        self.to_unix_seconds(offset) as i128 * 1_000_000_000
+    }
+
+    pub fn to_system_time(&self, offset: i32) -> SystemTime {
+        let seconds = self.to_unix_seconds(offset);
+        if seconds < 0 {
+            UNIX_EPOCH - Duration::from_secs(seconds.unsigned_abs())
+        } else {
+            UNIX_EPOCH + Duration::from_secs(seconds as u64)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::calendar::fixed_from_gregorian;
+
+    #[test]
+    fn test_unix_epoch_day() {
+        assert_eq!(super::UNIX_EPOCH_DAY, fixed_from_gregorian(1970, 1, 1));
+    }
+}
@@ -0,0 +1,135 @@
+#![cfg(feature = "bincode")]
+
+use std::borrow::Cow;
+use std::io::Cursor;
+use unic_datetime::data::bundle::{write_bundle, Bundle, BundleReader};
+use unic_datetime::data::error::DataError;
+use unic_datetime::data::header::{DataHeader, BUNDLE_MAGIC};
+use unic_datetime::data::load_bin::get_calendar_data_from_bytes;
+use unic_datetime::*;
+
+static PL_DATA: &[u8] = include_bytes!("../res/pl.dat");
+
+fn create_bundle() -> Vec<u8> {
+    let mut res = get_calendar_data_from_bytes(PL_DATA).unwrap();
+    let pl = get_calendar_data_from_bytes(PL_DATA)
+        .unwrap()
+        .main
+        .remove("pl")
+        .unwrap();
+    res.main.insert(Cow::Borrowed("pl-PL"), pl);
+
+    let mut buffer = vec![];
+    write_bundle(&mut buffer, "36.0.0", &res).unwrap();
+    buffer
+}
+
+#[test]
+fn test_bundle_from_bytes() {
+    let buffer = create_bundle();
+    let bundle = Bundle::from_bytes(&buffer).unwrap();
+    assert_eq!(bundle.cldr_version(), "36.0.0");
+    assert_eq!(bundle.locales().collect::<Vec<_>>(), ["pl", "pl-PL"]);
+
+    let data = bundle.get("pl-PL").unwrap();
+    let months = &data.get_calendar("gregorian").unwrap().months;
+    let list = months.format.wide.as_ref().unwrap();
+    assert!(matches!(list.get(9), Cow::Borrowed("października")));
+
+    let dt = DateTime::new(2019, 10, 29, 10, 23, 5);
+    let dtf = DateTimeFormat::new("pl", Some(DateStyle::LONG), None, &bundle).unwrap();
+    assert_eq!(dtf.format(&dt), "29 października 2019");
+
+    assert!(matches!(bundle.get("de"), Err(DataError::MissingLocale(_))));
+}
+
+#[test]
+fn test_bundle_reader() {
+    let reader = BundleReader::new(Cursor::new(create_bundle())).unwrap();
+    assert_eq!(reader.cldr_version(), "36.0.0");
+
+    let dt = DateTime::new(2019, 10, 29, 10, 23, 5);
+    let dtf = DateTimeFormat::new("pl-PL", Some(DateStyle::SHORT), None, &reader).unwrap();
+    assert_eq!(dtf.format(&dt), "29.10.2019");
+}
+
+#[test]
+fn test_bundle_errors() {
+    let mut buffer = create_bundle();
+    assert!(matches!(
+        Bundle::from_bytes(&buffer[..8]),
+        Err(DataError::Format(_))
+    ));
+
+    let len = buffer.len();
+    assert!(matches!(
+        Bundle::from_bytes(&buffer[..len - 10])
+            .unwrap()
+            .get("pl-PL"),
+        Err(DataError::Format(_))
+    ));
+
+    buffer[0] = 99;
+    assert!(matches!(
+        Bundle::from_bytes(&buffer),
+        Err(DataError::Format(_))
+    ));
+    assert!(matches!(
+        BundleReader::new(Cursor::new(buffer)),
+        Err(DataError::Format(_))
+    ));
+}
+
+/// Writes a bundle with the given index length and entries, which need not
+/// match the payload.
+fn corrupt_bundle(index_len: Option<u64>, entries: &[(&str, u64, u64)], payload: &[u8]) -> Vec<u8> {
+    let entries: Vec<_> = entries
+        .iter()
+        .map(|&(locale, offset, length)| (locale.to_string(), offset, length))
+        .collect();
+    let index = bincode::serialize(&entries).unwrap();
+    let mut buffer = vec![];
+    DataHeader::new("36.0.0")
+        .write(&mut buffer, BUNDLE_MAGIC)
+        .unwrap();
+    buffer.extend_from_slice(&index_len.unwrap_or(index.len() as u64).to_le_bytes());
+    buffer.extend_from_slice(&index);
+    buffer.extend_from_slice(payload);
+    buffer
+}
+
+#[test]
+fn test_corrupt_bundle() {
+    let is_format_error = |buffer: &[u8]| {
+        let from_bytes = Bundle::from_bytes(buffer).and_then(|bundle| bundle.get("pl").map(|_| ()));
+        let reader =
+            BundleReader::new(Cursor::new(buffer)).and_then(|reader| reader.get("pl").map(|_| ()));
+        matches!(from_bytes, Err(DataError::Format(_)))
+            && matches!(reader, Err(DataError::Format(_)))
+    };
+
+    // Lengths past the end of the file are rejected before allocating.
+    assert!(is_format_error(&corrupt_bundle(Some(u64::MAX), &[], &[])));
+    assert!(is_format_error(&corrupt_bundle(Some(1 << 40), &[], &[])));
+    assert!(is_format_error(&corrupt_bundle(
+        None,
+        &[("pl", 0, u64::MAX)],
+        &[0; 16]
+    )));
+    assert!(is_format_error(&corrupt_bundle(
+        None,
+        &[("pl", u64::MAX, 2)],
+        &[0; 16]
+    )));
+    assert!(is_format_error(&corrupt_bundle(
+        None,
+        &[("pl", 10, 7)],
+        &[0; 16]
+    )));
+
+    // So are header strings longer than any header.
+    let mut buffer = corrupt_bundle(None, &[], &[]);
+    let version_len = 4 + 4 + 8;
+    buffer[version_len..version_len + 8].copy_from_slice(&u64::MAX.to_le_bytes());
+    assert!(is_format_error(&buffer));
+}
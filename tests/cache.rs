@@ -0,0 +1,101 @@
+#![cfg(feature = "bincode")]
+
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+use unic_datetime::data::cache::CachingDataProvider;
+use unic_datetime::data::error::DataError;
+use unic_datetime::data::layout::CalendarData;
+use unic_datetime::data::load_bin::get_calendar_data_from_bytes;
+use unic_datetime::data::provider::DataProvider;
+use unic_datetime::*;
+
+static PL_DATA: &[u8] = include_bytes!("../res/pl.dat");
+
+/// Serves the Polish data under any locale and counts the loads.
+#[derive(Default)]
+struct CountingProvider {
+    loads: AtomicUsize,
+}
+
+impl DataProvider<'static> for CountingProvider {
+    type Calendar = CalendarData<'static>;
+
+    fn get_calendar_data(&self, locale: &str, calendar: &str) -> Result<Self::Calendar, DataError> {
+        if locale == "xx" {
+            return Err(DataError::MissingLocale(locale.to_string()));
+        }
+        self.loads.fetch_add(1, Ordering::SeqCst);
+        let mut res = get_calendar_data_from_bytes(PL_DATA)?.into_owned();
+        res.main
+            .remove("pl")
+            .and_then(|data| data.into_calendar(calendar))
+            .ok_or_else(|| DataError::MissingCalendar(calendar.to_string()))
+    }
+}
+
+fn assert_send_sync<T: Send + Sync>(_: &T) {}
+
+#[test]
+fn test_loads_once() {
+    let provider =
+        CachingDataProvider::new(CountingProvider::default(), NonZeroUsize::new(2).unwrap());
+    let first = provider.get_calendar_data("pl", "gregorian").unwrap();
+    let second = provider.get_calendar_data("pl", "gregorian").unwrap();
+    assert!(Arc::ptr_eq(&first, &second));
+    assert_eq!(provider.len(), 1);
+    assert_eq!(provider.inner().loads.load(Ordering::SeqCst), 1);
+
+    assert!(matches!(
+        provider.get_calendar_data("xx", "gregorian"),
+        Err(DataError::MissingLocale(_))
+    ));
+    assert_eq!(provider.len(), 1);
+}
+
+#[test]
+fn test_evicts_least_recently_used() {
+    let provider =
+        CachingDataProvider::new(CountingProvider::default(), NonZeroUsize::new(2).unwrap());
+    let a = provider.get_calendar_data("a", "gregorian").unwrap();
+    provider.get_calendar_data("b", "gregorian").unwrap();
+    provider.get_calendar_data("a", "gregorian").unwrap();
+    provider.get_calendar_data("c", "gregorian").unwrap();
+    assert_eq!(provider.len(), 2);
+
+    let again = provider.get_calendar_data("a", "gregorian").unwrap();
+    assert!(Arc::ptr_eq(&a, &again));
+    assert_eq!(provider.inner().loads.load(Ordering::SeqCst), 3);
+
+    // "b" was the least recently used entry, so it has to be reloaded.
+    provider.get_calendar_data("b", "gregorian").unwrap();
+    assert_eq!(provider.inner().loads.load(Ordering::SeqCst), 4);
+}
+
+#[test]
+fn test_shared_across_threads() {
+    let provider = Arc::new(CachingDataProvider::new(
+        CountingProvider::default(),
+        NonZeroUsize::new(8).unwrap(),
+    ));
+    let dt = DateTime::new(2019, 10, 29, 10, 23, 5);
+
+    let dtf = DateTimeFormat::new("pl", Some(DateStyle::SHORT), None, &*provider).unwrap();
+    assert_send_sync(&dtf);
+
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let provider = provider.clone();
+            thread::spawn(move || {
+                let dtf =
+                    DateTimeFormat::new("pl", Some(DateStyle::LONG), None, &*provider).unwrap();
+                dtf.format(&DateTime::new(2019, 10, 29, 10, 23, 5))
+            })
+        })
+        .collect();
+    for handle in handles {
+        assert_eq!(handle.join().unwrap(), "29 października 2019");
+    }
+    assert_eq!(dtf.format(&dt), "29.10.2019");
+}
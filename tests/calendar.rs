@@ -0,0 +1,368 @@
+use unic_datetime::calendar;
+#[cfg(not(feature = "no-static"))]
+use unic_datetime::calendar::{CalendarSystem, Gregorian};
+use unic_datetime::data::error::DataError;
+#[cfg(not(feature = "no-static"))]
+use unic_datetime::data::layout::DateTimePattern;
+#[cfg(feature = "serde_json")]
+use unic_datetime::data::load_json::get_calendar_data_from_str;
+#[cfg(not(feature = "no-static"))]
+use unic_datetime::data::patterns::parse_pattern;
+#[cfg(not(feature = "no-static"))]
+use unic_datetime::data::provider::{DataProvider, StaticDataProvider};
+use unic_datetime::*;
+
+#[cfg(feature = "serde_json")]
+mod common;
+
+#[cfg(not(feature = "no-static"))]
+fn format(pattern: &str, date: &DateTime, system: &dyn CalendarSystem) -> String {
+    let calendar = StaticDataProvider
+        .get_calendar_data("pl", system.name())
+        .unwrap();
+    let pattern = DateTimePattern::Parsed(parse_pattern(pattern).unwrap().into());
+    let mut result = String::new();
+    calendar
+        .format_pattern(&mut result, &pattern, date, system)
+        .unwrap();
+    result
+}
+
+#[cfg(not(feature = "no-static"))]
+#[test]
+fn test_gregorian_eras() {
+    let dt = DateTime::new(2019, 10, 29, 10, 23, 5);
+    assert_eq!(
+        format("d MMMM y GGGG", &dt, &Gregorian),
+        "29 października 2019 naszej ery"
+    );
+    assert_eq!(format("dd.MM.yy G", &dt, &Gregorian), "29.10.19 n.e.");
+
+    // Year 0 is 1 BC.
+    let dt = DateTime::new(0, 2, 29, 0, 0, 0);
+    assert_eq!(
+        format("EEEE d.MM.y G", &dt, &Gregorian),
+        "wtorek 29.02.1 p.n.e."
+    );
+}
+
+#[cfg(not(feature = "no-static"))]
+#[test]
+fn test_new_with_calendar() {
+    let dt = DateTime::new(2019, 10, 29, 10, 23, 5);
+    let dtf = DateTimeFormat::new_with_calendar(
+        "pl",
+        "gregorian",
+        Some(DateStyle::LONG),
+        None,
+        StaticDataProvider,
+    )
+    .unwrap();
+    assert_eq!(dtf.format(&dt), "29 października 2019");
+
+    assert!(calendar::get("klingon").is_none());
+    assert!(matches!(
+        DateTimeFormat::new_with_calendar(
+            "pl",
+            "klingon",
+            Some(DateStyle::LONG),
+            None,
+            StaticDataProvider
+        ),
+        Err(DataError::MissingCalendar(ref name)) if name == "klingon"
+    ));
+}
+
+#[cfg(feature = "serde_json")]
+#[test]
+fn test_japanese() {
+    let json = common::all_calendars();
+    let res = get_calendar_data_from_str(&json).unwrap();
+    let format = |style, dt: &DateTime| common::format(&res, "ja", "japanese", style, dt);
+
+    let dt = DateTime::new(2021, 10, 18, 0, 0, 0);
+    assert_eq!(format(DateStyle::LONG, &dt), "令和3年10月18日");
+    assert_eq!(format(DateStyle::FULL, &dt), "令和3年10月18日月曜日");
+    assert_eq!(format(DateStyle::SHORT, &dt), "R3/10/18");
+
+    // The first year of an era is 元年 when followed by 年.
+    let dt = DateTime::new(2019, 5, 1, 0, 0, 0);
+    assert_eq!(format(DateStyle::LONG, &dt), "令和元年5月1日");
+    assert_eq!(format(DateStyle::SHORT, &dt), "R1/5/1");
+    let dt = DateTime::new(2019, 4, 30, 0, 0, 0);
+    assert_eq!(format(DateStyle::LONG, &dt), "平成31年4月30日");
+    let dt = DateTime::new(1989, 1, 7, 0, 0, 0);
+    assert_eq!(format(DateStyle::MEDIUM, &dt), "昭和64年1月7日");
+    // Dates before Meiji fall in the earlier eras.
+    let dt = DateTime::new(1800, 1, 1, 0, 0, 0);
+    assert_eq!(format(DateStyle::LONG, &dt), "寛政12年1月1日");
+
+    let parse = |style, input: &str| common::parse(&res, "ja", "japanese", style, input);
+    assert_eq!(
+        parse(DateStyle::LONG, "令和元年5月1日"),
+        Ok(DateTime::new(2019, 5, 1, 0, 0, 0))
+    );
+    assert_eq!(
+        parse(DateStyle::FULL, "令和3年10月18日月曜日"),
+        Ok(DateTime::new(2021, 10, 18, 0, 0, 0))
+    );
+    assert_eq!(
+        parse(DateStyle::SHORT, "H31/4/30"),
+        Ok(DateTime::new(2019, 4, 30, 0, 0, 0))
+    );
+    assert_eq!(
+        parse(DateStyle::LONG, "寛政12年1月1日"),
+        Ok(DateTime::new(1800, 1, 1, 0, 0, 0))
+    );
+
+    assert!(matches!(
+        DateTimeFormat::new_with_calendar("ja", "gregorian", Some(DateStyle::LONG), None, &res),
+        Err(DataError::MissingCalendar(_))
+    ));
+
+    // Data from before Reiwa writes its era number instead of a name.
+    let japanese = common::japanese(common::JAPANESE_ERAS - 1);
+    let json = common::document(&[("ja", &[("japanese", &japanese)])]);
+    let res = get_calendar_data_from_str(&json).unwrap();
+    let format = |style, dt: &DateTime| common::format(&res, "ja", "japanese", style, dt);
+    let dt = DateTime::new(2021, 10, 18, 0, 0, 0);
+    assert_eq!(format(DateStyle::LONG, &dt), "2363年10月18日");
+    assert_eq!(format(DateStyle::SHORT, &dt), "2363/10/18");
+}
+
+#[cfg(feature = "serde_json")]
+#[test]
+fn test_islamic() {
+    let json = common::all_calendars();
+    let res = get_calendar_data_from_str(&json).unwrap();
+    let format = |calendar, style, dt: &DateTime| common::format(&res, "en", calendar, style, dt);
+
+    let dt = DateTime::new(2021, 10, 18, 0, 0, 0);
+    assert_eq!(
+        format("islamic-civil", DateStyle::LONG, &dt),
+        "Rabiʻ I 11, 1443 AH"
+    );
+    assert_eq!(
+        format("islamic-tbla", DateStyle::LONG, &dt),
+        "Rabiʻ I 12, 1443 AH"
+    );
+    assert_eq!(
+        format("islamic-umalqura", DateStyle::LONG, &dt),
+        "Rabiʻ I 12, 1443 AH"
+    );
+
+    let dt = DateTime::new(2077, 11, 16, 0, 0, 0);
+    assert_eq!(
+        format("islamic-civil", DateStyle::SHORT, &dt),
+        "12/29/1500 AH"
+    );
+    assert_eq!(format("islamic-tbla", DateStyle::SHORT, &dt), "1/1/1501 AH");
+    assert_eq!(
+        format("islamic-umalqura", DateStyle::SHORT, &dt),
+        "12/30/1500 AH"
+    );
+
+    // The observational calendar is approximated by Umm al-Qura.
+    assert_eq!(format("islamic", DateStyle::SHORT, &dt), "12/30/1500 AH");
+    let dtf = DateTimeFormat::new("en-u-ca-islamic", Some(DateStyle::SHORT), None, &res).unwrap();
+    assert_eq!(dtf.format(&dt), "12/30/1500 AH");
+}
+
+#[cfg(feature = "serde_json")]
+#[test]
+fn test_hebrew() {
+    let json = common::all_calendars();
+    let res = get_calendar_data_from_str(&json).unwrap();
+    let format = |year, month, day| {
+        let dt = DateTime::new(year, month, day, 0, 0, 0);
+        let format = |style| common::format(&res, "en", "hebrew", style, &dt);
+        (format(DateStyle::LONG), format(DateStyle::SHORT))
+    };
+
+    // 5784 is a leap year, 5783 is not. Numeric months count the months
+    // present in the year, as in ICU.
+    assert_eq!(
+        format(2024, 3, 1),
+        ("21 Adar I 5784 AM".to_string(), "21/6/5784".to_string())
+    );
+    assert_eq!(
+        format(2024, 3, 20),
+        ("10 Adar II 5784 AM".to_string(), "10/7/5784".to_string())
+    );
+    assert_eq!(
+        format(2024, 4, 20),
+        ("12 Nisan 5784 AM".to_string(), "12/8/5784".to_string())
+    );
+    assert_eq!(
+        format(2023, 3, 1),
+        ("8 Adar 5783 AM".to_string(), "8/6/5783".to_string())
+    );
+    assert_eq!(
+        format(2023, 9, 16),
+        ("1 Tishri 5784 AM".to_string(), "1/1/5784".to_string())
+    );
+
+    // Parsing finds the month by its name or its position in the year.
+    let parse = |style, input: &str| common::parse(&res, "en", "hebrew", style, input);
+    for &(year, month, day) in &[(2024, 3, 1), (2024, 3, 20), (2023, 3, 1), (2023, 9, 16)] {
+        let dt = DateTime::new(year, month, day, 0, 0, 0);
+        let (long_text, short_text) = format(year, month, day);
+        assert_eq!(parse(DateStyle::LONG, &long_text), Ok(dt));
+        assert_eq!(parse(DateStyle::SHORT, &short_text), Ok(dt));
+    }
+    assert!(parse(DateStyle::LONG, "21 Adar I 5783 AM").is_err());
+}
+
+#[cfg(feature = "serde_json")]
+#[test]
+fn test_chinese() {
+    let json = common::all_calendars();
+    let res = get_calendar_data_from_str(&json).unwrap();
+    for &calendar in &["chinese", "dangi"] {
+        let format = |year, month, day| {
+            let dt = DateTime::new(year, month, day, 0, 0, 0);
+            let format = |style| common::format(&res, "en", calendar, style, &dt);
+            (format(DateStyle::LONG), format(DateStyle::SHORT))
+        };
+
+        // Reference values from ICU. The related year is the Gregorian year
+        // in which the Chinese year starts.
+        assert_eq!(
+            format(2021, 10, 18),
+            ("Month9 13, 2021(c38)".to_string(), "9/13/2021".to_string())
+        );
+        assert_eq!(
+            format(2022, 1, 31),
+            (
+                "Month12 29, 2021(c38)".to_string(),
+                "12/29/2021".to_string()
+            )
+        );
+        assert_eq!(
+            format(2020, 5, 23),
+            (
+                "Month4bis 1, 2020(c37)".to_string(),
+                "4bis/1/2020".to_string()
+            )
+        );
+    }
+
+    // The two calendars place the leap month of 2017 differently.
+    let dt = DateTime::new(2017, 7, 23, 0, 0, 0);
+    let format = |calendar| common::format(&res, "en", calendar, DateStyle::LONG, &dt);
+    assert_eq!(format("chinese"), "Month6bis 1, 2017(c34)");
+    assert_eq!(format("dangi"), "Month6 1, 2017(c34)");
+
+    // Without `monthPatterns`, leap months are written like the others.
+    let chinese = common::chinese(false);
+    let json = common::document(&[("en", &[("chinese", &chinese)])]);
+    let res = get_calendar_data_from_str(&json).unwrap();
+    let dt = DateTime::new(2020, 5, 23, 0, 0, 0);
+    let format = |style| common::format(&res, "en", "chinese", style, &dt);
+    assert_eq!(format(DateStyle::LONG), "Month4 1, 2020(c37)");
+    assert_eq!(format(DateStyle::SHORT), "4/1/2020");
+}
+
+#[cfg(feature = "serde_json")]
+#[test]
+fn test_solar_calendars() {
+    let json = common::all_calendars();
+    let res = get_calendar_data_from_str(&json).unwrap();
+    let format = |calendar, style, dt: &DateTime| common::format(&res, "en", calendar, style, dt);
+
+    // Reference values from ICU.
+    let dt = DateTime::new(2021, 10, 18, 0, 0, 0);
+    assert_eq!(format("persian", DateStyle::LONG, &dt), "Mehr 26, 1400 AP");
+    assert_eq!(
+        format("indian", DateStyle::LONG, &dt),
+        "Asvina 26, 1943 Saka"
+    );
+    assert_eq!(
+        format("ethiopic", DateStyle::LONG, &dt),
+        "Tekemt 8, 2014 ERA1"
+    );
+    assert_eq!(format("coptic", DateStyle::LONG, &dt), "Baba 8, 1738 ERA1");
+    assert_eq!(
+        format("ethiopic-amete-alem", DateStyle::LONG, &dt),
+        "Tekemt 8, 7514 ERA0"
+    );
+    let dtf = DateTimeFormat::new("en-u-ca-ethioaa", Some(DateStyle::SHORT), None, &res).unwrap();
+    assert_eq!(dtf.format(&dt), "2/8/7514 ERA0");
+
+    // Esfand has 30 days in leap years.
+    let dt = DateTime::new(2025, 3, 20, 0, 0, 0);
+    assert_eq!(format("persian", DateStyle::SHORT, &dt), "12/30/1403 AP");
+    assert_eq!(format("indian", DateStyle::SHORT, &dt), "12/29/1946 Saka");
+
+    // The 13th month.
+    let dt = DateTime::new(2023, 9, 11, 0, 0, 0);
+    assert_eq!(
+        format("ethiopic", DateStyle::LONG, &dt),
+        "Pagumen 6, 2015 ERA1"
+    );
+    assert_eq!(format("coptic", DateStyle::LONG, &dt), "Nasie 6, 1739 ERA1");
+    assert_eq!(format("coptic", DateStyle::SHORT, &dt), "13/6/1739 ERA1");
+}
+
+#[test]
+fn test_split_locale() {
+    use calendar::split_locale;
+
+    assert_eq!(split_locale("pl"), ("pl".into(), None));
+    assert_eq!(
+        split_locale("th-TH-u-ca-buddhist"),
+        ("th-TH".into(), Some("buddhist".to_string()))
+    );
+    assert_eq!(
+        split_locale("ar-SA-u-nu-latn-ca-islamic-umalqura"),
+        ("ar-SA".into(), Some("islamic-umalqura".to_string()))
+    );
+    assert_eq!(
+        split_locale("en-u-ca-gregory-x-private"),
+        ("en-x-private".into(), Some("gregorian".to_string()))
+    );
+    assert_eq!(split_locale("en-US-u-nu-thai"), ("en-US".into(), None));
+}
+
+#[cfg(feature = "serde_json")]
+#[test]
+fn test_year_offsets() {
+    let json = common::all_calendars();
+    let res = get_calendar_data_from_str(&json).unwrap();
+    let format = |locale, style, dt: &DateTime| {
+        DateTimeFormat::new(locale, Some(style), None, &res)
+            .unwrap()
+            .format(dt)
+    };
+
+    // Reference values from ICU.
+    let dt = DateTime::new(2021, 10, 18, 0, 0, 0);
+    assert_eq!(
+        format("th-u-ca-buddhist", DateStyle::LONG, &dt),
+        "18 ตุลาคม พ.ศ. 2564"
+    );
+    assert_eq!(
+        format("th-u-ca-buddhist", DateStyle::SHORT, &dt),
+        "18/10/64"
+    );
+    assert_eq!(
+        format("zh-Hant-u-ca-roc", DateStyle::LONG, &dt),
+        "民國110年10月18日"
+    );
+    assert_eq!(
+        format("zh-Hant-u-ca-roc", DateStyle::SHORT, &dt),
+        "民國110/10/18"
+    );
+
+    let dt = DateTime::new(1911, 12, 31, 0, 0, 0);
+    assert_eq!(
+        format("zh-Hant-u-ca-roc", DateStyle::SHORT, &dt),
+        "民國前1/12/31"
+    );
+
+    // Without the extension, the locale's Gregorian data is used.
+    assert!(matches!(
+        DateTimeFormat::new("th", Some(DateStyle::LONG), None, &res),
+        Err(DataError::MissingCalendar(ref name)) if name == "gregorian"
+    ));
+}
@@ -0,0 +1,263 @@
+//! Calendar data shaped like CLDR's `ca-*.json` files, with only the names
+//! and patterns the calendar tests use.
+use unic_datetime::data::layout::Resource;
+use unic_datetime::*;
+
+/// The number of eras CLDR lists for the Japanese calendar.
+pub const JAPANESE_ERAS: usize = 237;
+
+/// Japanese data for `ja` with the first `eras` eras. Only the modern ones
+/// get real names, and of the eras before Meiji only Kansei (1789-1801).
+pub fn japanese(eras: usize) -> String {
+    let names = |modern: [&str; 5]| {
+        let entries: Vec<String> = (0..eras)
+            .map(|idx| match idx {
+                220 => format!(r#""{}": "寛政""#, idx),
+                232..=236 => format!(r#""{}": "{}""#, idx, modern[idx - 232]),
+                _ => format!(r#""{}": "era{}""#, idx, idx),
+            })
+            .collect();
+        format!("{{{}}}", entries.join(", "))
+    };
+    let wide = names(["明治", "大正", "昭和", "平成", "令和"]);
+    let months: Vec<String> = (1..=12).map(|m| format!(r#""{}": "{}月""#, m, m)).collect();
+    format!(
+        r#"{{
+            "months": {{"format": {{"wide": {{{months}}}}}, "stand-alone": {{}}}},
+            "days": {{"format": {{"wide": {{"sun": "日曜日", "mon": "月曜日", "tue": "火曜日",
+                "wed": "水曜日", "thu": "木曜日", "fri": "金曜日", "sat": "土曜日"}}}},
+                "stand-alone": {{}}}},
+            "eras": {{"eraNames": {wide}, "eraAbbr": {wide}, "eraNarrow": {narrow}}},
+            "dateFormats": {{"full": "Gy年M月d日EEEE", "long": "Gy年M月d日",
+                "medium": "Gy年M月d日", "short": "GGGGGy/M/d"}},
+            "timeFormats": {{"medium": "H:mm:ss"}},
+            "dateTimeFormats": {{"medium": "{{1}} {{0}}"}}
+        }}"#,
+        months = months.join(", "),
+        wide = wide,
+        narrow = names(["M", "T", "S", "H", "R"]),
+    )
+}
+
+pub const ISLAMIC: &str = r#"{
+    "months": {
+        "format": {"wide": {
+            "1": "Muharram", "2": "Safar", "3": "Rabiʻ I", "4": "Rabiʻ II",
+            "5": "Jumada I", "6": "Jumada II", "7": "Rajab", "8": "Shaʻban",
+            "9": "Ramadan", "10": "Shawwal", "11": "Dhuʻl-Qiʻdah", "12": "Dhuʻl-Hijjah"
+        }},
+        "stand-alone": {}
+    },
+    "days": {"format": {}, "stand-alone": {}},
+    "eras": {"eraAbbr": {"0": "AH"}},
+    "dateFormats": {"long": "MMMM d, y G", "short": "M/d/y G"},
+    "timeFormats": {},
+    "dateTimeFormats": {}
+}"#;
+
+pub const HEBREW: &str = r#"{
+    "months": {
+        "format": {"wide": {
+            "1": "Tishri", "2": "Heshvan", "3": "Kislev", "4": "Tevet", "5": "Shevat",
+            "6": "Adar I", "7": "Adar", "7-yeartype-leap": "Adar II", "8": "Nisan",
+            "9": "Iyar", "10": "Sivan", "11": "Tamuz", "12": "Av", "13": "Elul"
+        }},
+        "stand-alone": {}
+    },
+    "days": {"format": {}, "stand-alone": {}},
+    "eras": {"eraAbbr": {"0": "AM"}},
+    "dateFormats": {"long": "d MMMM y G", "short": "d/M/y"},
+    "timeFormats": {},
+    "dateTimeFormats": {}
+}"#;
+
+/// Chinese or Dangi data, with the cyclic years named by their number to
+/// keep it short, and leap months marked with `bis` if `month_patterns`.
+pub fn chinese(month_patterns: bool) -> String {
+    let months: Vec<String> = (1..=12)
+        .map(|m| format!(r#""{}": "Month{}""#, m, m))
+        .collect();
+    let years: Vec<String> = (1..=60).map(|y| format!(r#""{}": "c{}""#, y, y)).collect();
+    let month_patterns = if month_patterns {
+        r#""monthPatterns": {
+            "format": {"wide": {"leap": "{0}bis"}},
+            "numeric": {"all": {"leap": "{0}bis"}}
+        },"#
+    } else {
+        ""
+    };
+    format!(
+        r#"{{
+            "months": {{"format": {{"wide": {{{months}}}}}, "stand-alone": {{}}}},
+            "days": {{"format": {{}}, "stand-alone": {{}}}},
+            "cyclicNameSets": {{"years": {{"format": {{"abbreviated": {{{years}}}}}}}}},
+            {month_patterns}
+            "dateFormats": {{"long": "MMMM d, r(U)", "short": "M/d/r"}},
+            "timeFormats": {{}},
+            "dateTimeFormats": {{}}
+        }}"#,
+        months = months.join(", "),
+        years = years.join(", "),
+        month_patterns = month_patterns,
+    )
+}
+
+pub const PERSIAN: &str = r#"{
+    "months": {
+        "format": {"wide": {
+            "1": "Farvardin", "2": "Ordibehesht", "3": "Khordad", "4": "Tir",
+            "5": "Mordad", "6": "Shahrivar", "7": "Mehr", "8": "Aban", "9": "Azar",
+            "10": "Dey", "11": "Bahman", "12": "Esfand"
+        }},
+        "stand-alone": {}
+    },
+    "days": {"format": {}, "stand-alone": {}},
+    "eras": {"eraAbbr": {"0": "AP"}},
+    "dateFormats": {"long": "MMMM d, y G", "short": "M/d/y G"},
+    "timeFormats": {},
+    "dateTimeFormats": {}
+}"#;
+
+pub const INDIAN: &str = r#"{
+    "months": {
+        "format": {"wide": {
+            "1": "Chaitra", "2": "Vaisakha", "3": "Jyaistha", "4": "Asadha",
+            "5": "Sravana", "6": "Bhadra", "7": "Asvina", "8": "Kartika",
+            "9": "Agrahayana", "10": "Pausa", "11": "Magha", "12": "Phalguna"
+        }},
+        "stand-alone": {}
+    },
+    "days": {"format": {}, "stand-alone": {}},
+    "eras": {"eraAbbr": {"0": "Saka"}},
+    "dateFormats": {"long": "MMMM d, y G", "short": "M/d/y G"},
+    "timeFormats": {},
+    "dateTimeFormats": {}
+}"#;
+
+pub const ETHIOPIC: &str = r#"{
+    "months": {
+        "format": {"wide": {
+            "1": "Meskerem", "2": "Tekemt", "3": "Hedar", "4": "Tahsas", "5": "Ter",
+            "6": "Yekatit", "7": "Megabit", "8": "Miazia", "9": "Genbot", "10": "Sene",
+            "11": "Hamle", "12": "Nehasse", "13": "Pagumen"
+        }},
+        "stand-alone": {}
+    },
+    "days": {"format": {}, "stand-alone": {}},
+    "eras": {"eraAbbr": {"0": "ERA0", "1": "ERA1"}},
+    "dateFormats": {"long": "MMMM d, y G", "short": "M/d/y G"},
+    "timeFormats": {},
+    "dateTimeFormats": {}
+}"#;
+
+pub const COPTIC: &str = r#"{
+    "months": {
+        "format": {"wide": {
+            "1": "Tout", "2": "Baba", "3": "Hator", "4": "Kiahk", "5": "Toba",
+            "6": "Amshir", "7": "Baramhat", "8": "Baramouda", "9": "Bashans",
+            "10": "Paona", "11": "Epep", "12": "Mesra", "13": "Nasie"
+        }},
+        "stand-alone": {}
+    },
+    "days": {"format": {}, "stand-alone": {}},
+    "eras": {"eraAbbr": {"0": "ERA0", "1": "ERA1"}},
+    "dateFormats": {"long": "MMMM d, y G", "short": "M/d/y G"},
+    "timeFormats": {},
+    "dateTimeFormats": {}
+}"#;
+
+pub const BUDDHIST: &str = r#"{
+    "months": {
+        "format": {"wide": {
+            "1": "มกราคม", "2": "กุมภาพันธ์", "3": "มีนาคม", "4": "เมษายน",
+            "5": "พฤษภาคม", "6": "มิถุนายน", "7": "กรกฎาคม", "8": "สิงหาคม",
+            "9": "กันยายน", "10": "ตุลาคม", "11": "พฤศจิกายน", "12": "ธันวาคม"
+        }},
+        "stand-alone": {}
+    },
+    "days": {"format": {}, "stand-alone": {}},
+    "eras": {"eraAbbr": {"0": "พ.ศ."}},
+    "dateFormats": {"long": "d MMMM G y", "short": "d/M/yy"},
+    "timeFormats": {},
+    "dateTimeFormats": {}
+}"#;
+
+pub const ROC: &str = r#"{
+    "months": {"format": {}, "stand-alone": {}},
+    "days": {"format": {}, "stand-alone": {}},
+    "eras": {"eraAbbr": {"0": "民國前", "1": "民國"}},
+    "dateFormats": {"long": "Gy年M月d日", "short": "Gy/M/d"},
+    "timeFormats": {},
+    "dateTimeFormats": {}
+}"#;
+
+/// A CLDR document holding, for each locale, pairs of a calendar name and
+/// its data.
+pub fn document(locales: &[(&str, &[(&str, &str)])]) -> String {
+    let locales: Vec<String> = locales
+        .iter()
+        .map(|(locale, calendars)| {
+            let calendars: Vec<String> = calendars
+                .iter()
+                .map(|(name, data)| format!(r#""{}": {}"#, name, data))
+                .collect();
+            format!(
+                r#""{}": {{"dates": {{"calendars": {{{}}}}}}}"#,
+                locale,
+                calendars.join(", ")
+            )
+        })
+        .collect();
+    format!(r#"{{"main": {{{}}}}}"#, locales.join(", "))
+}
+
+/// Every calendar of the tests: Japanese for `ja`, Buddhist for `th`, ROC
+/// for `zh-Hant` and the others for `en`.
+pub fn all_calendars() -> String {
+    let japanese = japanese(JAPANESE_ERAS);
+    let chinese = chinese(true);
+    document(&[
+        ("ja", &[("japanese", &japanese)]),
+        (
+            "en",
+            &[
+                ("islamic", ISLAMIC),
+                ("hebrew", HEBREW),
+                ("chinese", &chinese),
+                ("dangi", &chinese),
+                ("persian", PERSIAN),
+                ("indian", INDIAN),
+                ("ethiopic", ETHIOPIC),
+                ("coptic", COPTIC),
+            ],
+        ),
+        ("th", &[("buddhist", BUDDHIST)]),
+        ("zh-Hant", &[("roc", ROC)]),
+    ])
+}
+
+/// Formats `dt` with the `style` date pattern of `calendar` in `res`.
+pub fn format(
+    res: &Resource,
+    locale: &str,
+    calendar: &str,
+    style: DateStyle,
+    dt: &DateTime,
+) -> String {
+    DateTimeFormat::new_with_calendar(locale, calendar, Some(style), None, res)
+        .unwrap()
+        .format(dt)
+}
+
+/// Parses `input` with the `style` date pattern of `calendar` in `res`.
+pub fn parse(
+    res: &Resource,
+    locale: &str,
+    calendar: &str,
+    style: DateStyle,
+    input: &str,
+) -> Result<DateTime, ParseError> {
+    DateTimeParser::new_with_calendar(locale, calendar, Some(style), None, res)
+        .unwrap()
+        .parse(input)
+}
@@ -0,0 +1,128 @@
+use unic_datetime::*;
+
+fn date(year: usize, month: usize, day: usize) -> DateTime {
+    DateTime::new(year, month, day, 10, 23, 5)
+}
+
+#[test]
+fn test_try_new() {
+    assert_eq!(
+        DateTime::try_new(2019, 10, 29, 10, 23, 5),
+        Ok(date(2019, 10, 29))
+    );
+    assert!(DateTime::try_new(2024, 2, 29, 0, 0, 0).is_ok());
+    assert!(DateTime::try_new(0, 2, 29, 0, 0, 0).is_ok());
+    let out_of_range = |field| Err(DateTimeError::OutOfRange { field });
+    assert_eq!(
+        DateTime::try_new(2019, 13, 40, 99, 0, 0),
+        out_of_range(DateTimeField::Month)
+    );
+    assert_eq!(
+        DateTime::try_new(2019, 0, 1, 0, 0, 0),
+        out_of_range(DateTimeField::Month)
+    );
+    assert_eq!(
+        DateTime::try_new(2019, 2, 29, 0, 0, 0),
+        out_of_range(DateTimeField::Day)
+    );
+    assert_eq!(
+        DateTime::try_new(1900, 2, 29, 0, 0, 0),
+        out_of_range(DateTimeField::Day)
+    );
+    assert_eq!(
+        DateTime::try_new(2019, 4, 0, 0, 0, 0),
+        out_of_range(DateTimeField::Day)
+    );
+    assert_eq!(
+        DateTime::try_new(2019, 4, 30, 24, 0, 0),
+        out_of_range(DateTimeField::Hour)
+    );
+    assert_eq!(
+        DateTime::try_new(2019, 4, 30, 23, 60, 0),
+        out_of_range(DateTimeField::Minute)
+    );
+    assert_eq!(
+        DateTime::try_new(2019, 4, 30, 23, 59, 60),
+        out_of_range(DateTimeField::Second)
+    );
+    assert_eq!(
+        DateTime::try_new(2019, 13, 1, 0, 0, 0)
+            .unwrap_err()
+            .to_string(),
+        "Month out of range"
+    );
+}
+
+#[cfg(not(feature = "no-static"))]
+#[test]
+#[should_panic(expected = "cannot format Month out of range")]
+fn test_format_out_of_range() {
+    let dtf = DateTimeFormat::new_from_static("pl", Some(DateStyle::LONG), None).unwrap();
+    dtf.format(&DateTime::new(2019, 13, 40, 0, 0, 0));
+}
+
+#[test]
+fn test_accessors() {
+    let dt = date(2019, 10, 29);
+    assert_eq!(dt.day_of_week(), 2);
+    assert_eq!(dt.day_of_year(), 302);
+    assert_eq!(date(2024, 12, 31).day_of_year(), 366);
+    // January 1 of year 0 (1 BC) is a Saturday.
+    assert_eq!(date(0, 1, 1).day_of_week(), 6);
+    assert_eq!(date(0, 1, 1).day_of_year(), 1);
+}
+
+#[test]
+fn test_add() {
+    let dt = date(2019, 10, 29);
+    assert_eq!(dt.add_days(1000), Ok(date(2022, 7, 25)));
+    assert_eq!(dt.add_days(-10_000), Ok(date(1992, 6, 12)));
+    assert_eq!(dt.add_days(3), Ok(date(2019, 11, 1)));
+    assert_eq!(dt.add_months(3), Ok(date(2020, 1, 29)));
+    assert_eq!(dt.add_months(-10), Ok(date(2018, 12, 29)));
+    assert_eq!(dt.add_years(-2019), Ok(date(0, 10, 29)));
+
+    // Days past the end of the month are clamped.
+    assert_eq!(date(2019, 1, 31).add_months(1), Ok(date(2019, 2, 28)));
+    assert_eq!(date(2020, 1, 31).add_months(1), Ok(date(2020, 2, 29)));
+    assert_eq!(date(2019, 5, 31).add_months(-1), Ok(date(2019, 4, 30)));
+    assert_eq!(date(2024, 2, 29).add_years(1), Ok(date(2025, 2, 28)));
+    assert_eq!(date(2024, 2, 29).add_years(-4), Ok(date(2020, 2, 29)));
+
+    let out_of_range = Err(DateTimeError::OutOfRange {
+        field: DateTimeField::Year,
+    });
+    assert_eq!(date(0, 1, 1).add_days(-1), out_of_range);
+    assert_eq!(date(0, 1, 1).add_months(-1), out_of_range);
+    assert_eq!(dt.add_years(-2020), out_of_range);
+    assert_eq!(dt.add_days(i64::MAX), out_of_range);
+    assert_eq!(dt.add_years(i64::MAX), out_of_range);
+}
+
+#[test]
+fn test_differences() {
+    let a = date(2019, 10, 29);
+    let b = DateTime::new(2024, 2, 29, 8, 0, 0);
+    assert_eq!(a.days_until(&b), 1584);
+    assert_eq!(b.days_until(&a), -1584);
+    assert_eq!(a.seconds_until(&b), 136_849_015);
+    assert_eq!(b.seconds_until(&a), -136_849_015);
+    assert_eq!(a.months_until(&a), 0);
+
+    // Months are counted as `add_months` adds them.
+    let months = |from: DateTime, to: DateTime| from.months_until(&to);
+    assert_eq!(months(date(2019, 1, 31), date(2019, 2, 28)), 1);
+    assert_eq!(months(date(2019, 1, 15), date(2019, 2, 15)), 1);
+    assert_eq!(
+        months(date(2019, 1, 15), DateTime::new(2019, 2, 15, 9, 0, 0)),
+        0
+    );
+    assert_eq!(months(date(2019, 3, 31), date(2019, 2, 28)), -1);
+    assert_eq!(
+        months(date(2019, 3, 15), DateTime::new(2019, 2, 15, 11, 0, 0)),
+        0
+    );
+    assert_eq!(a.months_until(&b), 51);
+    assert_eq!(b.months_until(&a), -51);
+    assert!(a < b);
+}
@@ -0,0 +1,286 @@
+#![cfg(not(feature = "no-static"))]
+
+use unic_datetime::{
+    Date, DateStyle, DateTime, DateTimeError, DateTimeField, DateTimeFormat, DateTimeInput,
+    FullDateTimeInput, MonthDay, Time, TimeStyle, YearMonth,
+};
+
+fn format(
+    date_style: Option<DateStyle>,
+    time_style: Option<TimeStyle>,
+    input: &dyn FullDateTimeInput,
+) -> String {
+    DateTimeFormat::new_from_static("pl", date_style, time_style)
+        .unwrap()
+        .format(input)
+}
+
+/// A date and time in a named zone.
+struct ZonedDateTime {
+    date_time: DateTime,
+    zone: &'static str,
+}
+
+impl DateTimeInput for ZonedDateTime {
+    fn year(&self) -> i64 {
+        self.date_time.year()
+    }
+
+    fn month(&self) -> usize {
+        self.date_time.month
+    }
+
+    fn day(&self) -> usize {
+        self.date_time.day
+    }
+
+    fn hour(&self) -> usize {
+        self.date_time.hour
+    }
+
+    fn minute(&self) -> usize {
+        self.date_time.minute
+    }
+
+    fn second(&self) -> usize {
+        self.date_time.second
+    }
+
+    fn offset(&self) -> Option<i32> {
+        Some(60)
+    }
+
+    fn zone(&self) -> Option<&str> {
+        Some(self.zone)
+    }
+
+    fn provides(_field: DateTimeField) -> bool {
+        true
+    }
+}
+
+impl FullDateTimeInput for ZonedDateTime {}
+
+#[test]
+fn test_date_time_input() {
+    let dt = DateTime::new(2019, 10, 29, 10, 23, 5);
+    assert_eq!(dt.weekday(), 2);
+    assert_eq!(dt.nanosecond(), 0);
+    assert_eq!(dt.offset(), None);
+    assert_eq!(
+        format(Some(DateStyle::FULL), None, &dt),
+        "wtorek, 29 października 2019"
+    );
+
+    let zoned = ZonedDateTime {
+        date_time: dt,
+        zone: "CET",
+    };
+    assert_eq!(
+        format(Some(DateStyle::FULL), None, &zoned),
+        "wtorek, 29 października 2019"
+    );
+    assert_eq!(format(None, Some(TimeStyle::LONG), &zoned), "10:23:05 CET");
+    assert_eq!(format(None, Some(TimeStyle::FULL), &zoned), "10:23:05 CET");
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_chrono() {
+    use chrono::{FixedOffset, NaiveDate, TimeZone, Utc};
+
+    let naive = NaiveDate::from_ymd_opt(2019, 10, 29)
+        .unwrap()
+        .and_hms_nano_opt(10, 23, 5, 250)
+        .unwrap();
+    assert_eq!(naive.nanosecond(), 250);
+    assert_eq!(naive.offset(), None);
+    assert_eq!(
+        format(Some(DateStyle::FULL), Some(TimeStyle::MEDIUM), &naive),
+        "wtorek, 29 października 2019 10:23:05"
+    );
+
+    let offset = FixedOffset::east_opt(2 * 3600).unwrap();
+    let dt = offset.from_local_datetime(&naive).unwrap();
+    assert_eq!(DateTimeInput::offset(&dt), Some(120));
+    assert_eq!(
+        format(Some(DateStyle::SHORT), Some(TimeStyle::LONG), &dt),
+        "29.10.2019, 10:23:05 GMT+2"
+    );
+    assert_eq!(
+        format(None, Some(TimeStyle::FULL), &dt),
+        "10:23:05 GMT+02:00"
+    );
+
+    let dt = Utc.from_utc_datetime(&naive);
+    assert_eq!(format(None, Some(TimeStyle::LONG), &dt), "10:23:05 GMT");
+
+    let full = || DateTimeFormat::new_from_static("pl", None, Some(TimeStyle::FULL)).unwrap();
+    assert!(full().for_input::<chrono::DateTime<Utc>>().is_ok());
+    assert_eq!(
+        full().for_input::<chrono::NaiveDateTime>().err(),
+        Some(DateTimeError::MissingField {
+            field: DateTimeField::Zone
+        })
+    );
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn test_time() {
+    use time::{Date, Month, UtcOffset};
+
+    let primitive = Date::from_calendar_date(2019, Month::October, 29)
+        .unwrap()
+        .with_hms_nano(10, 23, 5, 250)
+        .unwrap();
+    assert_eq!(DateTimeInput::nanosecond(&primitive), 250);
+    assert_eq!(DateTimeInput::weekday(&primitive), 2);
+    assert_eq!(
+        format(Some(DateStyle::LONG), Some(TimeStyle::SHORT), &primitive),
+        "29 października 2019 10:23"
+    );
+
+    let offset = UtcOffset::from_hms(-5, -30, 0).unwrap();
+    let dt = primitive.assume_offset(offset);
+    assert_eq!(DateTimeInput::offset(&dt), Some(-330));
+    assert_eq!(
+        format(None, Some(TimeStyle::LONG), &dt),
+        "10:23:05 GMT-5:30"
+    );
+    assert_eq!(
+        format(None, Some(TimeStyle::FULL), &dt),
+        "10:23:05 GMT-05:30"
+    );
+
+    let full = || DateTimeFormat::new_from_static("pl", None, Some(TimeStyle::FULL)).unwrap();
+    assert!(full().for_input::<time::OffsetDateTime>().is_ok());
+    assert_eq!(
+        full().for_input::<time::PrimitiveDateTime>().err(),
+        Some(DateTimeError::MissingField {
+            field: DateTimeField::Zone
+        })
+    );
+}
+
+#[test]
+fn test_partial_inputs() {
+    let typed = |date_style, time_style| {
+        DateTimeFormat::new_from_static("pl", date_style, time_style).unwrap()
+    };
+    let missing = |field| DateTimeError::MissingField { field };
+
+    let date = Date::new(2019, 10, 29);
+    let dtf = typed(Some(DateStyle::FULL), None)
+        .for_input::<Date>()
+        .unwrap();
+    assert_eq!(dtf.format(&date), "wtorek, 29 października 2019");
+    assert_eq!(Date::from(DateTime::new(2019, 10, 29, 10, 23, 5)), date);
+    assert_eq!(
+        typed(Some(DateStyle::SHORT), Some(TimeStyle::SHORT))
+            .for_input::<Date>()
+            .err(),
+        Some(missing(DateTimeField::Hour))
+    );
+
+    let time = Time::new(10, 23, 5);
+    let dtf = typed(None, Some(TimeStyle::MEDIUM))
+        .for_input::<Time>()
+        .unwrap();
+    assert_eq!(dtf.format(&time), "10:23:05");
+    assert_eq!(
+        typed(Some(DateStyle::SHORT), None)
+            .for_input::<Time>()
+            .err(),
+        Some(missing(DateTimeField::Day))
+    );
+    // Times have no zone to write.
+    let err = typed(None, Some(TimeStyle::LONG))
+        .for_input::<Time>()
+        .err()
+        .unwrap();
+    assert_eq!(err, missing(DateTimeField::Zone));
+    assert_eq!(err.to_string(), "the input has no Zone");
+
+    // No standard style of pl leaves out the day.
+    assert_eq!(
+        typed(Some(DateStyle::LONG), None)
+            .for_input::<YearMonth>()
+            .err(),
+        Some(missing(DateTimeField::Day))
+    );
+    assert_eq!(
+        typed(Some(DateStyle::LONG), None)
+            .for_input::<MonthDay>()
+            .err(),
+        Some(missing(DateTimeField::Year))
+    );
+
+    // Full inputs hold every field but the zone, unless they have one.
+    assert!(typed(Some(DateStyle::FULL), Some(TimeStyle::MEDIUM))
+        .for_input::<DateTime>()
+        .is_ok());
+    assert_eq!(
+        typed(Some(DateStyle::FULL), Some(TimeStyle::FULL))
+            .for_input::<DateTime>()
+            .err(),
+        Some(missing(DateTimeField::Zone))
+    );
+    assert!(typed(Some(DateStyle::FULL), Some(TimeStyle::FULL))
+        .for_input::<ZonedDateTime>()
+        .is_ok());
+}
+
+#[test]
+fn test_partial_patterns() {
+    use unic_datetime::data::layout::DateTimePattern;
+    use unic_datetime::data::overlay::{CalendarOverrides, OverlayProvider, Overrides};
+    use unic_datetime::data::patterns::parse_pattern;
+    use unic_datetime::data::provider::StaticDataProvider;
+
+    let pattern = |pattern| {
+        Some(DateTimePattern::Parsed(
+            parse_pattern(pattern).unwrap().into(),
+        ))
+    };
+    let mut pl = CalendarOverrides::default();
+    pl.date_formats.long = pattern("MMMM y");
+    pl.date_formats.medium = pattern("d MMMM");
+    let mut overrides = Overrides::new();
+    overrides.insert("pl", "gregorian", pl);
+    let provider = OverlayProvider::new(StaticDataProvider, overrides);
+    let typed = |style| DateTimeFormat::new("pl", Some(style), None, &provider).unwrap();
+
+    let dtf = typed(DateStyle::LONG).for_input::<YearMonth>().unwrap();
+    assert_eq!(dtf.format(&YearMonth::new(2019, 10)), "października 2019");
+    let dtf = typed(DateStyle::MEDIUM).for_input::<MonthDay>().unwrap();
+    assert_eq!(dtf.format(&MonthDay::new(2, 29)), "29 lutego");
+    assert_eq!(
+        typed(DateStyle::MEDIUM).for_input::<YearMonth>().err(),
+        Some(DateTimeError::MissingField {
+            field: DateTimeField::Day
+        })
+    );
+}
+
+#[test]
+fn test_partial_try_new() {
+    let out_of_range = |field| Some(DateTimeError::OutOfRange { field });
+    assert_eq!(
+        Date::try_new(2019, 2, 29).err(),
+        out_of_range(DateTimeField::Day)
+    );
+    assert_eq!(
+        Time::try_new(24, 0, 0).err(),
+        out_of_range(DateTimeField::Hour)
+    );
+    assert_eq!(
+        YearMonth::try_new(2019, 13).err(),
+        out_of_range(DateTimeField::Month)
+    );
+    assert_eq!(MonthDay::try_new(2, 29), Ok(MonthDay::new(2, 29)));
+    assert_eq!(
+        MonthDay::try_new(4, 31).err(),
+        out_of_range(DateTimeField::Day)
+    );
+}
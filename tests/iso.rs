@@ -0,0 +1,202 @@
+use unic_datetime::*;
+
+fn iso(
+    date: (usize, usize, usize),
+    time: (usize, usize, usize),
+    nanosecond: u32,
+    offset: Option<i32>,
+) -> IsoDateTime {
+    let dt = DateTime::new(date.0, date.1, date.2, time.0, time.1, time.2);
+    IsoDateTime::new(dt, nanosecond, offset)
+}
+
+#[test]
+fn test_parse_date_times() {
+    let dt = iso((2019, 10, 29), (10, 23, 5), 0, Some(120));
+    assert_eq!(IsoDateTime::parse("2019-10-29T10:23:05+02:00"), Ok(dt));
+    assert_eq!(IsoDateTime::parse("20191029T102305+0200"), Ok(dt));
+    assert_eq!(IsoDateTime::parse("2019-10-29 10:23:05+02"), Ok(dt));
+    assert_eq!(
+        IsoDateTime::parse("2019-10-29t08:23:05.123z"),
+        Ok(iso((2019, 10, 29), (8, 23, 5), 123_000_000, Some(0)))
+    );
+    assert_eq!(
+        IsoDateTime::parse("2019-10-29T10:23:05,000001234-05:30"),
+        Ok(iso((2019, 10, 29), (10, 23, 5), 1_234, Some(-330)))
+    );
+    // Digits past nanoseconds are dropped.
+    assert_eq!(
+        IsoDateTime::parse("2019-10-29T10:23:05.1234567899"),
+        Ok(iso((2019, 10, 29), (10, 23, 5), 123_456_789, None))
+    );
+    assert_eq!(
+        IsoDateTime::parse("2019-10-29T10:23"),
+        Ok(iso((2019, 10, 29), (10, 23, 0), 0, None))
+    );
+    assert_eq!(
+        IsoDateTime::parse("2019-10-29T10"),
+        Ok(iso((2019, 10, 29), (10, 0, 0), 0, None))
+    );
+}
+
+#[test]
+fn test_parse_dates_and_times() {
+    let date = iso((2019, 10, 29), (0, 0, 0), 0, None);
+    assert_eq!(IsoDateTime::parse("2019-10-29"), Ok(date));
+    assert_eq!(IsoDateTime::parse("20191029"), Ok(date));
+    assert_eq!(IsoDateTime::parse("2019-302"), Ok(date));
+    assert_eq!(IsoDateTime::parse("2019302"), Ok(date));
+    assert_eq!(IsoDateTime::parse("2019-W44-2"), Ok(date));
+    assert_eq!(IsoDateTime::parse("2019W442"), Ok(date));
+    assert_eq!(
+        IsoDateTime::parse("2021-W36-3"),
+        Ok(iso((2021, 9, 8), (0, 0, 0), 0, None))
+    );
+    // Week 53 of 2020 ends in 2021.
+    assert_eq!(
+        IsoDateTime::parse("2020-W53-5"),
+        Ok(iso((2021, 1, 1), (0, 0, 0), 0, None))
+    );
+    assert_eq!(
+        IsoDateTime::parse("2020-366"),
+        Ok(iso((2020, 12, 31), (0, 0, 0), 0, None))
+    );
+
+    let time = iso((1970, 1, 1), (10, 23, 5), 500_000_000, Some(0));
+    assert_eq!(IsoDateTime::parse("10:23:05.5Z"), Ok(time));
+    assert_eq!(IsoDateTime::parse("T10:23:05.5Z"), Ok(time));
+    assert_eq!(IsoDateTime::parse("T102305.5Z"), Ok(time));
+}
+
+#[test]
+fn test_parse_errors() {
+    let syntax = |offset| Err(IsoError::Syntax { offset });
+    let out_of_range = |field, offset| Err(IsoError::OutOfRange { field, offset });
+    assert_eq!(IsoDateTime::parse(""), syntax(0));
+    assert_eq!(IsoDateTime::parse("19-10-29"), syntax(0));
+    assert_eq!(IsoDateTime::parse("2019-1-29"), syntax(5));
+    assert_eq!(IsoDateTime::parse("2019-10-29T"), syntax(11));
+    assert_eq!(IsoDateTime::parse("2019-10-29T10:23:05."), syntax(20));
+    assert_eq!(IsoDateTime::parse("2019-10-29T10:23:05 PDT"), syntax(19));
+    assert_eq!(IsoDateTime::parse("2019-10-29Z"), syntax(10));
+
+    assert_eq!(
+        IsoDateTime::parse("2019-13-01"),
+        out_of_range(IsoField::Month, 5)
+    );
+    assert_eq!(
+        IsoDateTime::parse("2019-02-29"),
+        out_of_range(IsoField::Day, 8)
+    );
+    assert_eq!(
+        IsoDateTime::parse("2019-366"),
+        out_of_range(IsoField::DayOfYear, 5)
+    );
+    assert_eq!(
+        IsoDateTime::parse("2021-W53-1"),
+        out_of_range(IsoField::Week, 6)
+    );
+    assert_eq!(
+        IsoDateTime::parse("2021-W00-1"),
+        out_of_range(IsoField::Week, 6)
+    );
+    assert_eq!(
+        IsoDateTime::parse("2021-W10-8"),
+        out_of_range(IsoField::WeekDay, 9)
+    );
+    assert_eq!(
+        IsoDateTime::parse("24:00:00"),
+        out_of_range(IsoField::Hour, 0)
+    );
+    assert_eq!(
+        IsoDateTime::parse("23:59:60"),
+        out_of_range(IsoField::Second, 6)
+    );
+    assert_eq!(
+        IsoDateTime::parse("10:00+24:00"),
+        out_of_range(IsoField::Offset, 6)
+    );
+    assert_eq!(
+        IsoDateTime::parse("0000-W01-1"),
+        Ok(iso((0, 1, 3), (0, 0, 0), 0, None))
+    );
+
+    assert_eq!(IsoDateTime::parse("+2019-10-29"), syntax(1));
+    assert_eq!(
+        IsoDateTime::parse("+1000001-01-01"),
+        out_of_range(IsoField::Year, 1)
+    );
+    assert_eq!(
+        IsoDateTime::parse("+99999999-01-01"),
+        out_of_range(IsoField::Year, 1)
+    );
+
+    let err = IsoDateTime::parse("2019-02-29").unwrap_err();
+    assert_eq!(err.to_string(), "Day out of range at 8");
+}
+
+#[test]
+fn test_format() {
+    let dt = iso((2019, 10, 29), (10, 23, 5), 0, Some(120));
+    assert_eq!(dt.format(IsoFormat::Extended), "2019-10-29T10:23:05+02:00");
+    assert_eq!(dt.format(IsoFormat::Basic), "20191029T102305+0200");
+    assert_eq!(dt.format_date(IsoFormat::Extended), "2019-10-29");
+    assert_eq!(dt.format_week_date(IsoFormat::Extended), "2019-W44-2");
+    assert_eq!(dt.format_week_date(IsoFormat::Basic), "2019W442");
+    assert_eq!(dt.format_ordinal_date(IsoFormat::Extended), "2019-302");
+    assert_eq!(dt.format_ordinal_date(IsoFormat::Basic), "2019302");
+
+    let time = |nanosecond, offset| {
+        iso((2019, 1, 1), (8, 0, 0), nanosecond, offset).format_time(IsoFormat::Extended)
+    };
+    assert_eq!(time(0, None), "08:00:00");
+    assert_eq!(time(0, Some(0)), "08:00:00Z");
+    assert_eq!(time(250_000_000, Some(-330)), "08:00:00.250-05:30");
+    assert_eq!(time(1_000, None), "08:00:00.000001");
+    assert_eq!(time(7, None), "08:00:00.000000007");
+
+    // Week years differ from calendar years around January 1.
+    let week_date = |year, month, day| {
+        iso((year, month, day), (0, 0, 0), 0, None).format_week_date(IsoFormat::Extended)
+    };
+    assert_eq!(week_date(2019, 12, 30), "2020-W01-1");
+    assert_eq!(week_date(2027, 1, 1), "2026-W53-5");
+    assert_eq!(week_date(2021, 9, 8), "2021-W36-3");
+
+    // Years past 9999 take the expanded form.
+    let dt = iso((123_456, 10, 29), (0, 0, 0), 0, None);
+    assert_eq!(dt.format_date(IsoFormat::Extended), "+123456-10-29");
+    assert_eq!(dt.format_date(IsoFormat::Basic), "+1234561029");
+    assert_eq!(week_date(10_000, 1, 1), "9999-W52-6");
+    assert_eq!(week_date(10_000, 1, 3), "+010000-W01-1");
+}
+
+#[test]
+fn test_round_trip() {
+    let dt = iso((2024, 2, 29), (23, 59, 59), 999_999_999, Some(-45));
+    for &format in &[IsoFormat::Extended, IsoFormat::Basic] {
+        assert_eq!(IsoDateTime::parse(&dt.format(format)), Ok(dt));
+        let date = iso((2024, 2, 29), (0, 0, 0), 0, None);
+        assert_eq!(IsoDateTime::parse(&dt.format_week_date(format)), Ok(date));
+        assert_eq!(
+            IsoDateTime::parse(&dt.format_ordinal_date(format)),
+            Ok(date)
+        );
+    }
+
+    for &year in &[10_000, 123_456, 999_999] {
+        let dt = iso((year, 12, 31), (12, 0, 0), 0, Some(0));
+        for &format in &[IsoFormat::Extended, IsoFormat::Basic] {
+            assert_eq!(IsoDateTime::parse(&dt.format(format)), Ok(dt));
+            let date = iso((year, 12, 31), (0, 0, 0), 0, None);
+            assert_eq!(IsoDateTime::parse(&dt.format_week_date(format)), Ok(date));
+            assert_eq!(
+                IsoDateTime::parse(&dt.format_ordinal_date(format)),
+                Ok(date)
+            );
+        }
+    }
+    // Only the extended form separates the seven digits of the last year.
+    let dt = iso((1_000_000, 1, 1), (0, 0, 0), 0, None);
+    assert_eq!(IsoDateTime::parse(&dt.format(IsoFormat::Extended)), Ok(dt));
+}
@@ -0,0 +1,77 @@
+#![cfg(feature = "bincode")]
+
+use std::borrow::Cow;
+use unic_datetime::data::error::DataError;
+use unic_datetime::data::header::SCHEMA_HASH;
+use unic_datetime::data::load_bin::{get_calendar_data, get_calendar_data_from_bytes, get_header};
+
+static PL_DATA: &[u8] = include_bytes!("../res/pl.dat");
+
+#[test]
+fn test_borrowed_names() {
+    let res = get_calendar_data_from_bytes(PL_DATA).unwrap();
+    let months = &res
+        .get("pl")
+        .unwrap()
+        .get_calendar("gregorian")
+        .unwrap()
+        .months;
+    let list = months.format.wide.as_ref().unwrap();
+
+    assert_eq!(list.get(9), "października");
+    assert!(matches!(list.get(9), Cow::Borrowed(_)));
+}
+
+#[test]
+fn test_into_owned() {
+    let res = get_calendar_data_from_bytes(PL_DATA).unwrap().into_owned();
+    let days = &res
+        .get("pl")
+        .unwrap()
+        .get_calendar("gregorian")
+        .unwrap()
+        .days;
+    let list = days.format.wide.as_ref().unwrap();
+
+    assert_eq!(list.get(0), "niedziela");
+    assert!(matches!(list.get(0), Cow::Owned(_)));
+}
+
+#[test]
+fn test_errors() {
+    assert!(matches!(
+        get_calendar_data_from_bytes(&PL_DATA[..PL_DATA.len() / 2]),
+        Err(DataError::Format(_))
+    ));
+
+    assert!(matches!(
+        get_calendar_data("./res", "xx"),
+        Err(DataError::Io(_))
+    ));
+}
+
+#[test]
+fn test_header() {
+    let header = get_header(PL_DATA).unwrap();
+    assert_eq!(header.cldr_version, "36.0.0");
+    assert_eq!(header.schema_hash, SCHEMA_HASH);
+
+    let mut buffer = PL_DATA.to_vec();
+    buffer[4] = 2;
+    assert!(matches!(
+        get_calendar_data_from_bytes(&buffer),
+        Err(DataError::UnsupportedVersion(2))
+    ));
+
+    let mut buffer = PL_DATA.to_vec();
+    buffer[8] ^= 0xff;
+    assert!(matches!(
+        get_calendar_data_from_bytes(&buffer),
+        Err(DataError::SchemaMismatch { .. })
+    ));
+
+    assert!(matches!(
+        get_calendar_data_from_bytes(&PL_DATA[4..]),
+        Err(DataError::Format(_))
+    ));
+}
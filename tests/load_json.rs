@@ -0,0 +1,174 @@
+#![cfg(feature = "serde_json")]
+
+use unic_datetime::data::error::DataError;
+use unic_datetime::data::layout::{DateTimePattern, MonthList};
+use unic_datetime::data::load_json::{get_calendar_data_from_reader, get_calendar_data_from_str};
+use unic_datetime::data::patterns::ParserError;
+use unic_datetime::{DateStyle, DateTimeFormat, DateTimeParser, TimeStyle};
+
+const MINIMAL: &str = r#"{
+  "main": {
+    "pl": {
+      "dates": {
+        "calendars": {
+          "gregorian": {
+            "months": { "format": {}, "stand-alone": {} },
+            "days": { "format": {}, "stand-alone": {} },
+            "dateFormats": { "short": "dd.MM.y" },
+            "timeFormats": {},
+            "dateTimeFormats": {}
+          }
+        }
+      }
+    }
+  }
+}"#;
+
+#[test]
+fn test_load_from_str() {
+    let res = get_calendar_data_from_str(MINIMAL).unwrap();
+    let formats = &res
+        .get("pl")
+        .unwrap()
+        .get_calendar("gregorian")
+        .unwrap()
+        .date_formats;
+    assert_eq!(formats.short, Some(DateTimePattern::Raw("dd.MM.y".into())));
+    assert_eq!(formats.full, None);
+}
+
+#[test]
+fn test_load_eras() {
+    let input = MINIMAL.replace(
+        r#""dateFormats""#,
+        r#""eras": {
+            "eraAbbr": {"0": "p.n.e.", "0-alt-variant": "BCE", "1": "n.e."}
+        },
+        "dateFormats""#,
+    );
+    let res = get_calendar_data_from_str(&input).unwrap();
+    let eras = res
+        .get("pl")
+        .unwrap()
+        .get_calendar("gregorian")
+        .unwrap()
+        .eras
+        .as_ref();
+    let abbreviated = eras.unwrap().abbreviated.as_ref().unwrap();
+    assert_eq!(abbreviated.0.len(), 2);
+    assert_eq!(abbreviated.get(1), "n.e.");
+    assert_eq!(eras.unwrap().wide, None);
+
+    let input = MINIMAL.replace(
+        r#""dateFormats""#,
+        r#""eras": {"eraAbbr": {"0": "p.n.e.", "2": "n.e."}}, "dateFormats""#,
+    );
+    assert!(matches!(
+        get_calendar_data_from_str(&input),
+        Err(DataError::Schema(_))
+    ));
+}
+
+#[test]
+fn test_load_leap_month_names() {
+    let input = MINIMAL.replace(
+        r#""months": { "format": {}"#,
+        r#""months": { "format": {"wide": {
+            "5": "Shevat", "6": "Adar I", "7": "Adar", "7-yeartype-leap": "Adar II",
+            "1": "Tishri", "2": "Heshvan", "3": "Kislev", "4": "Tevet"
+        }}"#,
+    );
+    let res = get_calendar_data_from_str(&input).unwrap();
+    let calendar = res.get("pl").unwrap().get_calendar("gregorian").unwrap();
+    let wide = calendar.months.format.wide.as_ref().unwrap();
+    assert_eq!(wide.names.len(), 7);
+    assert_eq!(wide.get_in_year(6, false).unwrap(), "Adar");
+    assert_eq!(wide.get_in_year(6, true).unwrap(), "Adar II");
+    assert_eq!(wide.get_in_year(5, true).unwrap(), "Adar I");
+
+    #[cfg(feature = "bincode")]
+    {
+        let buffer = bincode::serialize(wide).unwrap();
+        assert_eq!(&bincode::deserialize::<MonthList>(&buffer).unwrap(), wide);
+    }
+
+    // A leap year name needs the name it replaces.
+    let input = MINIMAL.replace(
+        r#""months": { "format": {}"#,
+        r#""months": { "format": {"wide": {"1": "Tishri", "2-yeartype-leap": "Adar II"}}"#,
+    );
+    assert!(matches!(
+        get_calendar_data_from_str(&input),
+        Err(DataError::Schema(_))
+    ));
+}
+
+#[test]
+fn test_load_from_reader() {
+    let res = get_calendar_data_from_reader(MINIMAL.as_bytes()).unwrap();
+    assert!(res.get("pl").is_some());
+}
+
+#[test]
+fn test_unsupported_pattern() {
+    let input = MINIMAL.replace(
+        r#""timeFormats": {}"#,
+        r#""timeFormats": {"short": "h:mm a"}"#,
+    );
+    let res = get_calendar_data_from_str(&input).unwrap();
+    let is_invalid = |result: Result<(), DataError>| match result {
+        Err(DataError::InvalidPattern { path, error }) => {
+            path == "gregorian/timeFormats/short"
+                && matches!(error, ParserError::UnsupportedField { symbol: 'h', .. })
+        }
+        _ => false,
+    };
+    assert!(is_invalid(
+        DateTimeFormat::new("pl", None, Some(TimeStyle::SHORT), &res).map(|_| ())
+    ));
+    assert!(is_invalid(
+        DateTimeParser::new("pl", None, Some(TimeStyle::SHORT), &res).map(|_| ())
+    ));
+    assert!(DateTimeFormat::new("pl", Some(DateStyle::SHORT), None, &res).is_ok());
+}
+
+#[test]
+fn test_errors() {
+    assert!(matches!(
+        get_calendar_data_from_str(r#"{"main": "#),
+        Err(DataError::Format(_))
+    ));
+
+    assert!(matches!(
+        get_calendar_data_from_str(r#"{"main": {"pl": {}}}"#),
+        Err(DataError::Schema(_))
+    ));
+}
+
+#[cfg(feature = "bincode")]
+#[test]
+fn test_load_alongside_bincode() {
+    use unic_datetime::data::layout::PatternElement;
+    use unic_datetime::data::patterns::parse_pattern;
+
+    let parsed: Vec<PatternElement> = parse_pattern("dd.MM.y").unwrap();
+    let buffer = bincode::serialize(&DateTimePattern::Parsed(parsed.into())).unwrap();
+    let handles: Vec<_> = (0..8)
+        .map(|i| {
+            let buffer = buffer.clone();
+            std::thread::spawn(move || {
+                for _ in 0..100 {
+                    if i % 2 == 0 {
+                        assert!(get_calendar_data_from_str(MINIMAL).is_ok());
+                    } else {
+                        let pattern: DateTimePattern = bincode::deserialize(&buffer).unwrap();
+                        assert!(matches!(pattern, DateTimePattern::Parsed(_)));
+                    }
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+}
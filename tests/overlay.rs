@@ -0,0 +1,105 @@
+use std::collections::HashMap;
+use unic_datetime::data::error::DataError;
+use unic_datetime::data::layout::DateTimePattern;
+use unic_datetime::data::overlay::{CalendarOverrides, OverlayProvider, Overrides};
+#[cfg(not(feature = "no-static"))]
+use unic_datetime::data::provider::{DataProvider, StaticDataProvider};
+use unic_datetime::*;
+
+fn get_overrides() -> Overrides {
+    let mut pl = CalendarOverrides::default();
+    pl.date_formats.short = Some(DateTimePattern::Parsed(
+        unic_datetime::data::patterns::parse_pattern("d/MM/y")
+            .unwrap()
+            .into(),
+    ));
+    pl.months.format.abbreviated = HashMap::from([("9".to_string(), "wrz.".to_string())]);
+
+    let mut overrides = Overrides::new();
+    overrides.insert("pl", "gregorian", pl);
+    overrides
+}
+
+#[cfg(not(feature = "no-static"))]
+#[test]
+fn test_static_overlay() {
+    let provider = OverlayProvider::new(StaticDataProvider, get_overrides());
+
+    let dt = DateTime::new(2019, 10, 29, 10, 23, 5);
+    let dtf = DateTimeFormat::new("pl", Some(DateStyle::SHORT), None, &provider).unwrap();
+    assert_eq!(dtf.format(&dt), "29/10/2019");
+
+    // Everything not overridden falls back to the base data.
+    let dt = DateTime::new(2019, 9, 3, 10, 23, 5);
+    let dtf = DateTimeFormat::new("pl", Some(DateStyle::MEDIUM), None, &provider).unwrap();
+    assert_eq!(dtf.format(&dt), "3 wrz. 2019");
+    let dtf = DateTimeFormat::new("pl", Some(DateStyle::LONG), None, &provider).unwrap();
+    assert_eq!(dtf.format(&dt), "3 września 2019");
+
+    let calendar = provider.get_calendar_data("pl", "gregorian").unwrap();
+    let list = calendar.months.stand_alone.abbreviated.as_ref().unwrap();
+    assert_eq!(list.get(8), "wrz");
+
+    assert!(matches!(
+        provider.get_calendar_data("de", "gregorian"),
+        Err(DataError::MissingLocale(_))
+    ));
+}
+
+#[cfg(not(feature = "no-static"))]
+#[test]
+fn test_missing_list() {
+    let mut pl = CalendarOverrides::default();
+    pl.months.format.short = HashMap::from([("1".to_string(), "st".to_string())]);
+    let mut overrides = Overrides::new();
+    overrides.insert("pl", "gregorian", pl);
+
+    let provider = OverlayProvider::new(StaticDataProvider, overrides);
+    assert!(matches!(
+        provider.get_calendar_data("pl", "gregorian"),
+        Err(DataError::MissingData(ref path)) if path == "gregorian/months/format/short"
+    ));
+}
+
+#[cfg(feature = "serde_json")]
+#[test]
+fn test_from_json() {
+    let overrides = Overrides::from_json(
+        r#"{"main": {"pl": {"dates": {"calendars": {"gregorian": {
+            "dateFormats": {"short": "d/MM/y"},
+            "months": {"format": {"abbreviated": {"9": "wrz."}}}
+        }}}}}}"#,
+    )
+    .unwrap();
+    assert_eq!(overrides, get_overrides());
+
+    assert!(matches!(
+        Overrides::from_json(
+            r#"{"main": {"pl": {"dates": {"calendars": {"gregorian": {
+                "days": {"format": {"wide": {"mo": "pn"}}}
+            }}}}}}"#
+        ),
+        Err(DataError::Schema(_))
+    ));
+    assert!(Overrides::from_json(
+        r#"{"main": {"pl": {"dates": {"calendars": {"gregorian": {
+            "dateFormats": {"short": "d/MM/y 'ok"}
+        }}}}}}"#
+    )
+    .is_err());
+}
+
+#[cfg(feature = "bincode")]
+#[test]
+fn test_cached_dynamic_overlay() {
+    use std::num::NonZeroUsize;
+    use unic_datetime::data::cache::CachingDataProvider;
+    use unic_datetime::data::load_bin::BincodeDataProvider;
+
+    let overlay = OverlayProvider::new(BincodeDataProvider::new("./res"), get_overrides());
+    let provider = CachingDataProvider::new(overlay, NonZeroUsize::new(4).unwrap());
+
+    let dt = DateTime::new(2019, 10, 29, 10, 23, 5);
+    let dtf = DateTimeFormat::new("pl", Some(DateStyle::SHORT), None, &provider).unwrap();
+    assert_eq!(dtf.format(&dt), "29/10/2019");
+}
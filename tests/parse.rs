@@ -0,0 +1,230 @@
+#![cfg(not(feature = "no-static"))]
+
+use unic_datetime::data::layout::DateTimeToken;
+use unic_datetime::*;
+
+fn parser(
+    date_style: Option<DateStyle>,
+    time_style: Option<TimeStyle>,
+) -> impl Fn(&str) -> Result<DateTime, ParseError> {
+    let parser = DateTimeParser::new_from_static("pl", date_style, time_style).unwrap();
+    move |input| parser.parse(input)
+}
+
+#[test]
+fn test_parse_styles() {
+    let dt = DateTime::new(2019, 10, 29, 0, 0, 0);
+    assert_eq!(parser(Some(DateStyle::SHORT), None)("29.10.2019"), Ok(dt));
+    assert_eq!(
+        parser(Some(DateStyle::LONG), None)("29 października 2019"),
+        Ok(dt)
+    );
+    assert_eq!(parser(Some(DateStyle::MEDIUM), None)("29 paź 2019"), Ok(dt));
+    assert_eq!(
+        parser(Some(DateStyle::FULL), None)("wtorek, 29 października 2019"),
+        Ok(dt)
+    );
+    assert_eq!(
+        parser(Some(DateStyle::SHORT), Some(TimeStyle::MEDIUM))("29.10.2019, 10:23:05"),
+        Ok(DateTime::new(2019, 10, 29, 10, 23, 5))
+    );
+    assert_eq!(
+        parser(None, Some(TimeStyle::SHORT))("10:23"),
+        Ok(DateTime::new(1970, 1, 1, 10, 23, 0))
+    );
+}
+
+#[test]
+fn test_parse_names() {
+    let long = parser(Some(DateStyle::LONG), None);
+    let dt = DateTime::new(2019, 10, 29, 0, 0, 0);
+    // Names of any case, width and context.
+    assert_eq!(long("29 PAŹDZIERNIKA 2019"), Ok(dt));
+    assert_eq!(long("29 październik 2019"), Ok(dt));
+    assert_eq!(long("29 paź 2019"), Ok(dt));
+    assert_eq!(long("1 lipca 2020"), Ok(DateTime::new(2020, 7, 1, 0, 0, 0)));
+    assert_eq!(
+        long("1 listopada 2020"),
+        Ok(DateTime::new(2020, 11, 1, 0, 0, 0))
+    );
+
+    let full = parser(Some(DateStyle::FULL), None);
+    assert_eq!(full("WTOREK, 29 października 2019"), Ok(dt));
+    assert_eq!(
+        full("środa, 29 października 2019"),
+        Err(ParseError::OutOfRange {
+            field: DateTimeToken::WeekDayWide,
+            offset: 0
+        })
+    );
+}
+
+#[test]
+fn test_parse_errors() {
+    let short = parser(Some(DateStyle::SHORT), None);
+    assert_eq!(
+        short("29-10-2019"),
+        Err(ParseError::Literal {
+            expected: ".".to_string(),
+            offset: 2
+        })
+    );
+    assert_eq!(
+        short("29.1.2019"),
+        Err(ParseError::Number {
+            field: DateTimeToken::Month2digit,
+            offset: 3
+        })
+    );
+    assert_eq!(
+        short("29.13.2019"),
+        Err(ParseError::OutOfRange {
+            field: DateTimeToken::Month2digit,
+            offset: 3
+        })
+    );
+    assert_eq!(
+        short("29.02.2019"),
+        Err(ParseError::OutOfRange {
+            field: DateTimeToken::Day2digit,
+            offset: 0
+        })
+    );
+    assert_eq!(
+        short("29.10.999999999"),
+        Err(ParseError::OutOfRange {
+            field: DateTimeToken::YearNumeric,
+            offset: 6
+        })
+    );
+    assert_eq!(
+        short("29.10.2019 r."),
+        Err(ParseError::TrailingInput { offset: 10 })
+    );
+    assert_eq!(
+        short("29.10."),
+        Err(ParseError::Number {
+            field: DateTimeToken::YearNumeric,
+            offset: 6
+        })
+    );
+
+    let long = parser(Some(DateStyle::LONG), None);
+    let err = long("29 brumaire 2019").unwrap_err();
+    assert_eq!(
+        err,
+        ParseError::Name {
+            field: DateTimeToken::MonthNameLong,
+            offset: 3
+        }
+    );
+    assert_eq!(err.to_string(), "expected a name for MonthNameLong at 3");
+
+    let time = parser(None, Some(TimeStyle::MEDIUM));
+    assert_eq!(
+        time("24:00:00"),
+        Err(ParseError::OutOfRange {
+            field: DateTimeToken::Hour2digit,
+            offset: 0
+        })
+    );
+    // Time zones are written but not read.
+    assert_eq!(
+        parser(None, Some(TimeStyle::LONG))("10:23:05 PDT"),
+        Err(ParseError::UnsupportedField {
+            field: DateTimeToken::ZoneShort,
+            offset: 9
+        })
+    );
+}
+
+#[test]
+fn test_round_trip() {
+    for &style in &DateStyle::ALL {
+        let format = DateTimeFormat::new_from_static("pl", Some(style), None).unwrap();
+        let parse = parser(Some(style), None);
+        for &(year, month, day) in &[(2019, 10, 29), (1, 1, 1), (2024, 2, 29), (1999, 12, 31)] {
+            let dt = DateTime::new(year, month, day, 0, 0, 0);
+            assert_eq!(parse(&format.format(&dt)), Ok(dt));
+        }
+    }
+}
+
+#[test]
+fn test_parse_lenient() {
+    let lenient = |style| {
+        let parser = DateTimeParser::new_from_static("pl", Some(style), None)
+            .unwrap()
+            .lenient(true);
+        move |input: &str| parser.parse(input)
+    };
+    let dt = DateTime::new(2019, 10, 29, 0, 0, 0);
+
+    let short = lenient(DateStyle::SHORT);
+    assert_eq!(short("29.10.2019"), Ok(dt));
+    assert_eq!(short("29-10-2019"), Ok(dt));
+    assert_eq!(short("29 .10. 2019"), Ok(dt));
+    assert_eq!(short(" 29/10 2019 "), Ok(dt));
+    assert_eq!(short("9.1.2019"), Ok(DateTime::new(2019, 1, 9, 0, 0, 0)));
+    assert_eq!(short("29.10.19"), Ok(dt));
+    assert_eq!(short("29.10.69"), Ok(DateTime::new(1969, 10, 29, 0, 0, 0)));
+    assert_eq!(
+        short("2910.2019"),
+        Err(ParseError::Literal {
+            expected: ".".to_string(),
+            offset: 2
+        })
+    );
+
+    let medium = lenient(DateStyle::MEDIUM);
+    assert_eq!(medium("29 paz 2019"), Ok(dt));
+    assert_eq!(medium("29\u{202f}PAZ\u{a0}2019"), Ok(dt));
+    assert_eq!(medium("29paź2019"), Ok(dt));
+    assert_eq!(
+        lenient(DateStyle::FULL)("Sroda 30 pazdziernika 2019"),
+        Ok(DateTime::new(2019, 10, 30, 0, 0, 0))
+    );
+
+    let time = DateTimeParser::new_from_static("pl", None, Some(TimeStyle::MEDIUM))
+        .unwrap()
+        .lenient(true);
+    assert_eq!(time.parse("9:5:07"), Ok(DateTime::new(1970, 1, 1, 9, 5, 7)));
+
+    // Strict parsing reads the same input as written: only the narrow
+    // name "p" matches there.
+    let strict = parser(Some(DateStyle::MEDIUM), None);
+    assert_eq!(
+        strict("29 paz 2019"),
+        Err(ParseError::Literal {
+            expected: " ".to_string(),
+            offset: 4
+        })
+    );
+    assert_eq!(
+        strict("29\u{202f}paź 2019"),
+        Err(ParseError::Literal {
+            expected: " ".to_string(),
+            offset: 2
+        })
+    );
+}
+
+#[test]
+fn test_two_digit_year_pivot() {
+    let parse = |pivot, input| {
+        DateTimeParser::new_from_static("pl", Some(DateStyle::SHORT), None)
+            .unwrap()
+            .lenient(true)
+            .two_digit_year_pivot(pivot)
+            .parse(input)
+            .map(|dt| dt.year)
+    };
+    assert_eq!(parse(2000, "1.1.49"), Ok(2049));
+    assert_eq!(parse(2000, "1.1.50"), Ok(1950));
+    assert_eq!(parse(2020, "1.1.69"), Ok(2069));
+    assert_eq!(parse(2020, "1.1.70"), Ok(1970));
+    assert_eq!(parse(1900, "1.1.99"), Ok(1899));
+    // Years of other lengths are read as written.
+    assert_eq!(parse(2020, "1.1.070"), Ok(70));
+    assert_eq!(parse(2020, "1.1.1970"), Ok(1970));
+}
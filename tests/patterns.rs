@@ -1,6 +1,6 @@
 use std::borrow::Cow;
 use unic_datetime::data::layout::{DateTimeToken, PatternElement};
-use unic_datetime::data::patterns::parse_pattern;
+use unic_datetime::data::patterns::{parse_pattern, ParserError};
 
 #[test]
 fn test_literal_patterns() {
@@ -31,6 +31,17 @@ fn test_literal_patterns() {
             PatternElement::Literal(Cow::Owned("Smith".to_string())),
         ]
     );
+    assert_eq!(
+        parse_pattern("y年M月d日").unwrap().as_ref(),
+        [
+            PatternElement::Token(DateTimeToken::YearNumeric),
+            PatternElement::Literal(Cow::Owned("年".to_string())),
+            PatternElement::Token(DateTimeToken::MonthNumeric),
+            PatternElement::Literal(Cow::Owned("月".to_string())),
+            PatternElement::Token(DateTimeToken::DayNumeric),
+            PatternElement::Literal(Cow::Owned("日".to_string())),
+        ]
+    );
 }
 
 #[test]
@@ -70,6 +81,31 @@ fn test_tokens() {
             PatternElement::Token(DateTimeToken::YearNumeric),
         ]
     );
+
+    assert_eq!(
+        parse_pattern("y GGGG G GGGGG").unwrap().as_ref(),
+        [
+            PatternElement::Token(DateTimeToken::YearNumeric),
+            PatternElement::Literal(Cow::Owned(" ".to_string())),
+            PatternElement::Token(DateTimeToken::EraWide),
+            PatternElement::Literal(Cow::Owned(" ".to_string())),
+            PatternElement::Token(DateTimeToken::EraAbbreviated),
+            PatternElement::Literal(Cow::Owned(" ".to_string())),
+            PatternElement::Token(DateTimeToken::EraNarrow),
+        ]
+    );
+
+    assert_eq!(
+        parse_pattern("rU年 UUUU UUUUU").unwrap().as_ref(),
+        [
+            PatternElement::Token(DateTimeToken::RelatedYear),
+            PatternElement::Token(DateTimeToken::CyclicYearAbbreviated),
+            PatternElement::Literal(Cow::Owned("年 ".to_string())),
+            PatternElement::Token(DateTimeToken::CyclicYearWide),
+            PatternElement::Literal(Cow::Owned(" ".to_string())),
+            PatternElement::Token(DateTimeToken::CyclicYearNarrow),
+        ]
+    );
 }
 #[test]
 fn test_replace() {
@@ -82,3 +118,47 @@ fn test_replace() {
         ]
     );
 }
+
+#[test]
+fn test_errors() {
+    assert_eq!(
+        parse_pattern("'John").unwrap_err(),
+        ParserError::UnterminatedLiteral
+    );
+
+    assert_eq!(
+        parse_pattern("d MMMMM").unwrap_err(),
+        ParserError::UnsupportedField {
+            symbol: 'M',
+            length: 5,
+            offset: 2
+        }
+    );
+
+    assert_eq!(
+        parse_pattern("h:mm a").unwrap_err(),
+        ParserError::UnsupportedField {
+            symbol: 'h',
+            length: 1,
+            offset: 0
+        }
+    );
+
+    assert_eq!(
+        parse_pattern("rr/M/d").unwrap_err(),
+        ParserError::UnsupportedField {
+            symbol: 'r',
+            length: 2,
+            offset: 0
+        }
+    );
+
+    assert_eq!(
+        parse_pattern("{1} {2}").unwrap_err(),
+        ParserError::InvalidPlaceholder { offset: 4 }
+    );
+    assert_eq!(
+        parse_pattern("{1").unwrap_err(),
+        ParserError::InvalidPlaceholder { offset: 0 }
+    );
+}
@@ -0,0 +1,73 @@
+#![cfg(not(feature = "no-static"))]
+
+use unic_datetime::data::error::DataError;
+use unic_datetime::data::layout::CalendarData;
+use unic_datetime::data::provider::{DataProvider, StaticDataProvider};
+use unic_datetime::*;
+
+use std::panic::{RefUnwindSafe, UnwindSafe};
+
+/// Resolves regional locales to the language-only static data.
+struct FallbackProvider;
+
+impl DataProvider<'static> for FallbackProvider {
+    type Calendar = &'static CalendarData<'static>;
+
+    fn get_calendar_data(&self, locale: &str, calendar: &str) -> Result<Self::Calendar, DataError> {
+        let language = locale.split('-').next().unwrap();
+        StaticDataProvider.get_calendar_data(language, calendar)
+    }
+}
+
+#[test]
+fn test_static_provider() {
+    let dt = DateTime::new(2019, 10, 29, 10, 23, 5);
+    let dtf = DateTimeFormat::new("pl", Some(DateStyle::LONG), None, StaticDataProvider).unwrap();
+    assert_eq!(dtf.format(&dt), "29 października 2019");
+
+    assert!(matches!(
+        DateTimeFormat::new_from_static("de", Some(DateStyle::LONG), None),
+        Err(DataError::MissingLocale(_))
+    ));
+    assert!(matches!(
+        DateTimeFormat::new_from_static("pl", None, None),
+        Err(DataError::NoStyle)
+    ));
+    assert!(matches!(
+        DateTimeParser::new("pl", None, None, StaticDataProvider),
+        Err(DataError::NoStyle)
+    ));
+}
+
+fn assert_unwind_safe<T: UnwindSafe + RefUnwindSafe>(_: &T) {}
+
+#[test]
+fn test_unwind_safe() {
+    let dtf = DateTimeFormat::new_from_static("pl", Some(DateStyle::SHORT), None).unwrap();
+    assert_unwind_safe(&dtf);
+    let parser = DateTimeParser::new_from_static("pl", Some(DateStyle::SHORT), None).unwrap();
+    assert_unwind_safe(&parser);
+}
+
+#[test]
+fn test_custom_provider() {
+    let dt = DateTime::new(2019, 10, 29, 10, 23, 5);
+    let dtf = DateTimeFormat::new("pl-PL", Some(DateStyle::SHORT), None, FallbackProvider).unwrap();
+    assert_eq!(dtf.format(&dt), "29.10.2019");
+
+    assert!(matches!(
+        FallbackProvider.get_calendar_data("pl", "hebrew"),
+        Err(DataError::MissingCalendar(_))
+    ));
+}
+
+#[cfg(feature = "bincode")]
+#[test]
+fn test_bincode_provider() {
+    use unic_datetime::data::load_bin::BincodeDataProvider;
+
+    let dt = DateTime::new(2019, 10, 29, 10, 23, 5);
+    let provider = BincodeDataProvider::new("./res");
+    let dtf = DateTimeFormat::new("pl", Some(DateStyle::MEDIUM), None, &provider).unwrap();
+    assert_eq!(dtf.format(&dt), "29 paź 2019");
+}
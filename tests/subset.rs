@@ -0,0 +1,89 @@
+#![cfg(feature = "bincode")]
+
+use unic_datetime::data::error::DataError;
+use unic_datetime::data::layout::{DateTimeToken, NamesKind, NamesLength, Resource};
+use unic_datetime::data::load_bin;
+use unic_datetime::data::subset::UsageManifest;
+use unic_datetime::*;
+
+fn get_subset(manifest: &UsageManifest) -> Resource<'static> {
+    let mut res = load_bin::get_calendar_data("./res", "pl").unwrap();
+    for locale_res in res.main.values_mut() {
+        manifest.subset(locale_res.dates.calendars.gregorian.as_mut().unwrap());
+    }
+    res
+}
+
+#[test]
+fn test_subset_styles() {
+    let dt = DateTime::new(2019, 10, 29, 10, 23, 5);
+    let res = get_subset(&UsageManifest {
+        date_styles: vec![DateStyle::SHORT],
+        ..Default::default()
+    });
+
+    let dtf = DateTimeFormat::new("pl", Some(DateStyle::SHORT), None, &res).unwrap();
+    assert_eq!(dtf.format(&dt), "29.10.2019");
+
+    let calendar = res.get("pl").unwrap().get_calendar("gregorian").unwrap();
+    for &length in &NamesLength::ALL {
+        assert!(!calendar.has_names(NamesKind::Months, false, length));
+        assert!(!calendar.has_names(NamesKind::Months, true, length));
+    }
+
+    assert!(matches!(
+        DateTimeFormat::new("pl", Some(DateStyle::LONG), None, &res),
+        Err(DataError::MissingData(ref path)) if path == "gregorian/dateFormats/long"
+    ));
+    assert!(matches!(
+        DateTimeFormat::new("pl", Some(DateStyle::SHORT), Some(TimeStyle::SHORT), &res),
+        Err(DataError::MissingData(ref path)) if path == "gregorian/dateTimeFormats/short"
+    ));
+}
+
+#[test]
+fn test_subset_names() {
+    let dt = DateTime::new(2019, 10, 29, 10, 23, 5);
+    let res = get_subset(&UsageManifest {
+        date_styles: vec![DateStyle::MEDIUM],
+        time_styles: vec![TimeStyle::SHORT],
+        ..Default::default()
+    });
+    let dtf =
+        DateTimeFormat::new("pl", Some(DateStyle::MEDIUM), Some(TimeStyle::SHORT), &res).unwrap();
+    assert_eq!(dtf.format(&dt), "29 paź 2019, 10:23");
+
+    let calendar = res.get("pl").unwrap().get_calendar("gregorian").unwrap();
+    assert!(calendar.has_names(NamesKind::Months, false, NamesLength::ABBREVIATED));
+    assert!(!calendar.has_names(NamesKind::Months, false, NamesLength::WIDE));
+    assert!(!calendar.has_names(NamesKind::Days, false, NamesLength::WIDE));
+
+    let res = get_subset(&UsageManifest {
+        tokens: vec![DateTimeToken::MonthNameLong],
+        ..Default::default()
+    });
+    let calendar = res.get("pl").unwrap().get_calendar("gregorian").unwrap();
+    assert!(calendar.has_names(NamesKind::Months, false, NamesLength::WIDE));
+    assert!(!calendar.has_names(NamesKind::Months, false, NamesLength::ABBREVIATED));
+}
+
+#[test]
+fn test_manifest_from_json() {
+    let manifest = UsageManifest::from_json(
+        r#"{"dateStyles": ["short", "long"], "timeStyles": ["medium"], "tokens": ["d MMMM"]}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        manifest,
+        UsageManifest {
+            date_styles: vec![DateStyle::SHORT, DateStyle::LONG],
+            time_styles: vec![TimeStyle::MEDIUM],
+            tokens: vec![DateTimeToken::DayNumeric, DateTimeToken::MonthNameLong],
+        }
+    );
+
+    assert!(matches!(
+        UsageManifest::from_json(r#"{"dateStyles": ["tiny"]}"#),
+        Err(DataError::Schema(_))
+    ));
+}
@@ -0,0 +1,79 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use unic_datetime::*;
+
+#[test]
+fn test_from_unix() {
+    let dt = DateTime::new(2019, 10, 29, 10, 23, 5);
+    assert_eq!(DateTime::from_unix_seconds(1_572_344_585, 0), Ok(dt));
+    assert_eq!(DateTime::from_unix_millis(1_572_344_585_999, 0), Ok(dt));
+    assert_eq!(
+        DateTime::from_unix_nanos(1_572_344_585_000_000_001, 0),
+        Ok(dt)
+    );
+    // Offsets are in minutes east of UTC.
+    assert_eq!(
+        DateTime::from_unix_seconds(1_572_344_585, 120),
+        Ok(DateTime::new(2019, 10, 29, 12, 23, 5))
+    );
+    assert_eq!(
+        DateTime::from_unix_seconds(1_572_344_585, -630),
+        Ok(DateTime::new(2019, 10, 28, 23, 53, 5))
+    );
+
+    // Times before 1970 round down to the second.
+    let before = DateTime::new(1969, 12, 31, 23, 59, 59);
+    assert_eq!(DateTime::from_unix_seconds(-1, 0), Ok(before));
+    assert_eq!(DateTime::from_unix_millis(-1, 0), Ok(before));
+    assert_eq!(DateTime::from_unix_nanos(-1, 0), Ok(before));
+    assert_eq!(
+        DateTime::from_unix_seconds(-62_135_596_800, 0),
+        Ok(DateTime::new(1, 1, 1, 0, 0, 0))
+    );
+
+    let out_of_range = Err(DateTimeError::OutOfRange {
+        field: DateTimeField::Year,
+    });
+    assert_eq!(DateTime::from_unix_seconds(i64::MAX, 0), out_of_range);
+    assert_eq!(DateTime::from_unix_seconds(i64::MIN, 0), out_of_range);
+    assert_eq!(DateTime::from_unix_nanos(i128::MAX, 0), out_of_range);
+}
+
+#[test]
+fn test_to_unix() {
+    let dt = DateTime::new(2019, 10, 29, 10, 23, 5);
+    assert_eq!(dt.to_unix_seconds(0), 1_572_344_585);
+    assert_eq!(dt.to_unix_seconds(120), 1_572_344_585 - 7_200);
+    assert_eq!(dt.to_unix_millis(0), 1_572_344_585_000);
+    assert_eq!(dt.to_unix_nanos(0), 1_572_344_585_000_000_000);
+    assert_eq!(
+        DateTime::new(1, 1, 1, 0, 0, 0).to_unix_seconds(0),
+        -62_135_596_800
+    );
+    for &seconds in &[0, -1, 951_782_400, -62_135_596_800, 253_402_300_799] {
+        for &offset in &[0, 60, -480, 345] {
+            let dt = DateTime::from_unix_seconds(seconds, offset).unwrap();
+            assert_eq!(dt.to_unix_seconds(offset), seconds);
+        }
+    }
+}
+
+#[test]
+fn test_system_time() {
+    let time = UNIX_EPOCH + Duration::new(1_572_344_585, 500_000_000);
+    let dt = DateTime::new(2019, 10, 29, 10, 23, 5);
+    assert_eq!(DateTime::from_system_time(time, 0), Ok(dt));
+    assert_eq!(
+        dt.to_system_time(0),
+        UNIX_EPOCH + Duration::from_secs(1_572_344_585)
+    );
+
+    let time = UNIX_EPOCH - Duration::from_millis(1_500);
+    let dt = DateTime::new(1969, 12, 31, 23, 59, 58);
+    assert_eq!(DateTime::from_system_time(time, 0), Ok(dt));
+    assert_eq!(dt.to_system_time(0), UNIX_EPOCH - Duration::from_secs(2));
+
+    let now = SystemTime::now();
+    let dt = DateTime::now(60);
+    let seconds = now.duration_since(UNIX_EPOCH).unwrap().as_secs() as i64;
+    assert!((dt.to_unix_seconds(60) - seconds).abs() <= 1);
+}
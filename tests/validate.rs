@@ -0,0 +1,65 @@
+use unic_datetime::calendar::Gregorian;
+#[cfg(not(feature = "no-static"))]
+use unic_datetime::data::provider::{DataProvider, StaticDataProvider};
+use unic_datetime::data::validate::validate_calendar;
+
+#[cfg(not(feature = "no-static"))]
+#[test]
+fn test_valid_data() {
+    let calendar = StaticDataProvider
+        .get_calendar_data("pl", "gregorian")
+        .unwrap();
+    assert_eq!(validate_calendar(calendar, &Gregorian), []);
+}
+
+#[cfg(feature = "bincode")]
+#[test]
+fn test_issues() {
+    use std::borrow::Cow;
+    use unic_datetime::data::layout::DateTimePattern;
+    use unic_datetime::data::load_bin::get_calendar_data;
+    use unic_datetime::data::patterns::ParserError;
+    use unic_datetime::data::validate::Issue;
+
+    let mut res = get_calendar_data("./res", "pl").unwrap();
+    let calendars = &mut res.main.get_mut("pl").unwrap().dates.calendars;
+    let calendar = calendars.gregorian.as_mut().unwrap();
+    calendar.months.format.wide = None;
+    calendar.time_formats.short = Some(DateTimePattern::Raw(Cow::Borrowed("h:mm a")));
+    calendar.date_formats.short = None;
+
+    assert_eq!(
+        validate_calendar(calendar, &Gregorian),
+        [
+            Issue::MissingNames {
+                path: "gregorian/dateFormats/full".to_string(),
+                names: "months/format/wide".to_string(),
+            },
+            Issue::MissingNames {
+                path: "gregorian/dateFormats/long".to_string(),
+                names: "months/format/wide".to_string(),
+            },
+            Issue::MissingPattern {
+                path: "gregorian/dateFormats/short".to_string(),
+            },
+            Issue::Parse {
+                path: "gregorian/timeFormats/short".to_string(),
+                pattern: "h:mm a".to_string(),
+                error: ParserError::UnsupportedField {
+                    symbol: 'h',
+                    length: 1,
+                    offset: 0,
+                },
+            },
+            Issue::Parse {
+                path: "gregorian/timeFormats/short".to_string(),
+                pattern: "h:mm a".to_string(),
+                error: ParserError::UnsupportedField {
+                    symbol: 'a',
+                    length: 1,
+                    offset: 5,
+                },
+            },
+        ]
+    );
+}